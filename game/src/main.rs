@@ -23,6 +23,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         .window("DIIRDOOM", options.width, options.height)
         .position_centered()
         .opengl()
+        .resizable()
         .hidden()
         .build()?;
     let _gl_ctx = window.gl_create_context()?;
@@ -0,0 +1,44 @@
+use criterion::*;
+
+use gamelib::{DrawList, Rasteriser};
+use sdl2::pixels::Color;
+
+const WIDTH: usize = 1920;
+const HEIGHT: usize = 1080;
+
+/// A full screen of walls drawn over each other a few times and some fuzz,
+/// much like a busy view at a high resolution
+fn draw_list() -> DrawList {
+    let mut list = DrawList::default();
+    for layer in 0..8 {
+        for x in 0..WIDTH as i32 {
+            let top = (x * 7 + layer * 31) % (HEIGHT as i32 / 2);
+            let bottom = HEIGHT as i32 - 1 - (x * 3 + layer * 17) % 200;
+            let colour = Color::RGBA(x as u8, layer as u8 * 30, 100, 255);
+            list.push_wall(x, top, bottom, colour);
+        }
+    }
+    // A spectre in the middle
+    for x in WIDTH as i32 / 3..WIDTH as i32 * 2 / 3 {
+        list.push_fuzz(x, HEIGHT as i32 / 4, HEIGHT as i32 - 1);
+    }
+    list
+}
+
+fn bench_rasterise(c: &mut Criterion, name: &str, threads: usize) {
+    let list = draw_list();
+    let rasteriser = Rasteriser::new(threads).expect("rayon pool");
+    let mut buf = vec![0u8; WIDTH * HEIGHT * 4];
+    let name = format!("{} ({} threads)", name, rasteriser.threads());
+    c.bench_function(&name, |b| {
+        b.iter(|| rasteriser.draw(&list, &mut buf, WIDTH, HEIGHT, WIDTH * 4))
+    });
+}
+
+fn bench(c: &mut Criterion) {
+    bench_rasterise(c, "Rasterise single threaded", 1);
+    bench_rasterise(c, "Rasterise all cores", 0);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
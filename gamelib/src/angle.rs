@@ -95,6 +95,17 @@ impl Angle {
     }
 
     //pub fn tan(&self) -> f32 { self.0.tan() }
+
+    /// Interpolate the shortest way round from `self` to `to`
+    pub fn lerp(self, to: Angle, frac: f32) -> Angle {
+        let mut delta = to.0 - self.0;
+        if delta > PI {
+            delta -= 2.0 * PI;
+        } else if delta < -PI {
+            delta += 2.0 * PI;
+        }
+        Angle::new(self.0 + delta * frac)
+    }
 }
 
 impl Add for Angle {
@@ -0,0 +1,99 @@
+//! Items: key cards, artifacts, weapon, ammunition.
+
+use crate::doom_def::{AmmoType, WeaponType};
+use crate::info::StateNum;
+
+/// Weapon info: sprite frames, ammunition use.
+pub(crate) struct WeaponInfo {
+    pub ammo:       AmmoType,
+    pub upstate:    StateNum,
+    pub downstate:  StateNum,
+    pub readystate: StateNum,
+    pub atkstate:   StateNum,
+    pub flashstate: StateNum,
+}
+
+/// The `weaponinfo[]` table from d_items.c, indexed by `WeaponType`
+pub(crate) const WEAPON_INFO: [WeaponInfo; WeaponType::NUMWEAPONS as usize] = [
+    // fist
+    WeaponInfo {
+        ammo:       AmmoType::am_noammo,
+        upstate:    StateNum::S_PUNCHUP,
+        downstate:  StateNum::S_PUNCHDOWN,
+        readystate: StateNum::S_PUNCH,
+        atkstate:   StateNum::S_PUNCH1,
+        flashstate: StateNum::S_NULL,
+    },
+    // pistol
+    WeaponInfo {
+        ammo:       AmmoType::am_clip,
+        upstate:    StateNum::S_PISTOLUP,
+        downstate:  StateNum::S_PISTOLDOWN,
+        readystate: StateNum::S_PISTOL,
+        atkstate:   StateNum::S_PISTOL1,
+        flashstate: StateNum::S_PISTOLFLASH,
+    },
+    // shotgun
+    WeaponInfo {
+        ammo:       AmmoType::am_shell,
+        upstate:    StateNum::S_SGUNUP,
+        downstate:  StateNum::S_SGUNDOWN,
+        readystate: StateNum::S_SGUN,
+        atkstate:   StateNum::S_SGUN1,
+        flashstate: StateNum::S_SGUNFLASH1,
+    },
+    // chaingun
+    WeaponInfo {
+        ammo:       AmmoType::am_clip,
+        upstate:    StateNum::S_CHAINUP,
+        downstate:  StateNum::S_CHAINDOWN,
+        readystate: StateNum::S_CHAIN,
+        atkstate:   StateNum::S_CHAIN1,
+        flashstate: StateNum::S_CHAINFLASH1,
+    },
+    // missile launcher
+    WeaponInfo {
+        ammo:       AmmoType::am_misl,
+        upstate:    StateNum::S_MISSILEUP,
+        downstate:  StateNum::S_MISSILEDOWN,
+        readystate: StateNum::S_MISSILE,
+        atkstate:   StateNum::S_MISSILE1,
+        flashstate: StateNum::S_MISSILEFLASH1,
+    },
+    // plasma rifle
+    WeaponInfo {
+        ammo:       AmmoType::am_cell,
+        upstate:    StateNum::S_PLASMAUP,
+        downstate:  StateNum::S_PLASMADOWN,
+        readystate: StateNum::S_PLASMA,
+        atkstate:   StateNum::S_PLASMA1,
+        flashstate: StateNum::S_PLASMAFLASH1,
+    },
+    // bfg 9000
+    WeaponInfo {
+        ammo:       AmmoType::am_cell,
+        upstate:    StateNum::S_BFGUP,
+        downstate:  StateNum::S_BFGDOWN,
+        readystate: StateNum::S_BFG,
+        atkstate:   StateNum::S_BFG1,
+        flashstate: StateNum::S_BFGFLASH1,
+    },
+    // chainsaw
+    WeaponInfo {
+        ammo:       AmmoType::am_noammo,
+        upstate:    StateNum::S_SAWUP,
+        downstate:  StateNum::S_SAWDOWN,
+        readystate: StateNum::S_SAW,
+        atkstate:   StateNum::S_SAW1,
+        flashstate: StateNum::S_NULL,
+    },
+    // super shotgun
+    WeaponInfo {
+        ammo:       AmmoType::am_shell,
+        upstate:    StateNum::S_DSGUNUP,
+        downstate:  StateNum::S_DSGUNDOWN,
+        readystate: StateNum::S_DSGUN,
+        atkstate:   StateNum::S_DSGUN1,
+        flashstate: StateNum::S_DSGUNFLASH1,
+    },
+];
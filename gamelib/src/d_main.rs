@@ -1,27 +1,37 @@
-use std::{error::Error, fmt, str::FromStr};
+use std::{error::Error, fmt, str::FromStr, time::Instant};
 
 use golem::Context;
 
 use gumdrop::Options;
 use sdl2::{
-    keyboard::Scancode, pixels::Color, pixels::PixelFormatEnum, rect::Rect,
-    render::Canvas, surface::Surface, video::Window,
+    pixels::Color, pixels::PixelFormatEnum, rect::Rect, render::BlendMode,
+    render::Canvas, surface::Surface, video::FullscreenType, video::Window,
 };
 
 use crate::{
     doom_def::GameMission,
     doom_def::GameMode,
+    doom_def::GameState,
+    f_wipe::Wipe,
     game::Game,
+    hu_stats::StatsHud,
     input::Input,
+    m_menu::Menu,
+    m_misc::{screen_shot, FrameRecorder},
     shaders::{
-        basic::Basic, cgwg_crt::CGWGCRT, lottes_crt::LottesCRT, Renderer,
+        basic::Basic, cgwg_crt::CGWGCRT, chain::Chain, lottes_crt::LottesCRT,
+        read_window_pixels, Renderer, Renderers,
     },
     timestep::TimeStep,
+    v_video::{apply_gamma, draw_patch, fill_flat, gamma_tables},
 };
 
 #[derive(Debug)]
 pub enum DoomArgError {
     InvalidSkill(String),
+    InvalidWipe(String),
+    InvalidShader(String),
+    InvalidScale(String),
 }
 
 impl Error for DoomArgError {}
@@ -30,6 +40,9 @@ impl fmt::Display for DoomArgError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             DoomArgError::InvalidSkill(m) => write!(f, "{}", m),
+            DoomArgError::InvalidWipe(m) => write!(f, "{}", m),
+            DoomArgError::InvalidShader(m) => write!(f, "{}", m),
+            DoomArgError::InvalidScale(m) => write!(f, "{}", m),
         }
     }
 }
@@ -63,18 +76,196 @@ impl FromStr for Skill {
     }
 }
 
+/// The screen wipe used when the game state changes
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum WipeKind {
+    /// The vanilla column melt
+    Melt,
+    /// Fade from the old screen to the new
+    Crossfade,
+}
+
+impl Default for WipeKind {
+    fn default() -> Self { WipeKind::Melt }
+}
+
+impl FromStr for WipeKind {
+    type Err = DoomArgError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "melt" => Ok(WipeKind::Melt),
+            "fade" => Ok(WipeKind::Crossfade),
+            _ => Err(DoomArgError::InvalidWipe("Invalid arg".to_owned())),
+        }
+    }
+}
+
+/// The shader used to draw Doom's screen to the window
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ShaderKind {
+    /// Scaled with no effects
+    Basic,
+    /// Timothy Lottes' CRT
+    Lottes,
+    /// cgwg's CRT-Geom
+    Cgwg,
+    /// The chain of passes given by `shader_chain` in the config
+    Chain,
+}
+
+impl Default for ShaderKind {
+    fn default() -> Self { ShaderKind::Lottes }
+}
+
+impl FromStr for ShaderKind {
+    type Err = DoomArgError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "basic" => Ok(ShaderKind::Basic),
+            "lottes" => Ok(ShaderKind::Lottes),
+            "cgwg" => Ok(ShaderKind::Cgwg),
+            "chain" => Ok(ShaderKind::Chain),
+            _ => Err(DoomArgError::InvalidShader("Invalid arg".to_owned())),
+        }
+    }
+}
+
+/// How Doom's screen is fitted to the window
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ScaleMode {
+    /// As large as fits at 4:3, the shape it was shown at on a CRT
+    Aspect,
+    /// The largest whole multiple of 320x200 that fits, so every pixel is
+    /// the same size
+    Integer,
+    /// Fill the window
+    Stretch,
+}
+
+impl ScaleMode {
+    fn next(self) -> Self {
+        match self {
+            ScaleMode::Aspect => ScaleMode::Integer,
+            ScaleMode::Integer => ScaleMode::Stretch,
+            ScaleMode::Stretch => ScaleMode::Aspect,
+        }
+    }
+
+    /// The part of a window of `size` to draw to, centred
+    fn viewport(self, size: (u32, u32)) -> Rect {
+        let (width, height) = (size.0.max(1), size.1.max(1));
+        let (w, h) = match self {
+            ScaleMode::Aspect => {
+                if width * 3 > height * 4 {
+                    (height * 4 / 3, height)
+                } else {
+                    (width, width * 3 / 4)
+                }
+            }
+            ScaleMode::Integer => {
+                let scale = (width / 320).min(height / 200).max(1);
+                (320 * scale, 200 * scale)
+            }
+            ScaleMode::Stretch => (width, height),
+        };
+        Rect::new(
+            (width as i32 - w as i32) / 2,
+            (height as i32 - h as i32) / 2,
+            w,
+            h,
+        )
+    }
+}
+
+impl Default for ScaleMode {
+    fn default() -> Self { ScaleMode::Aspect }
+}
+
+impl FromStr for ScaleMode {
+    type Err = DoomArgError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "aspect" => Ok(ScaleMode::Aspect),
+            "integer" => Ok(ScaleMode::Integer),
+            "stretch" => Ok(ScaleMode::Stretch),
+            _ => Err(DoomArgError::InvalidScale("Invalid arg".to_owned())),
+        }
+    }
+}
+
 #[derive(Debug, Options)]
 pub struct GameOptions {
     #[options(no_short, help = "path to game WAD", default = "./doom1.wad")]
-    pub iwad:       String,
+    pub iwad:           String,
     #[options(no_short, help = "path to patch WAD")]
-    pub pwad:       Option<String>,
+    pub pwad:           Option<String>,
     #[options(help = "resolution width in pixels", default = "640")]
-    pub width:      u32,
+    pub width:          u32,
     #[options(help = "resolution height in pixels", default = "480")]
-    pub height:     u32,
+    pub height:         u32,
     #[options(help = "fullscreen?")]
-    pub fullscreen: bool,
+    pub fullscreen:     bool,
+    #[options(
+        no_short,
+        help = "how the screen fits the window, aspect (4:3), integer or stretch. Overrides the config"
+    )]
+    pub scale:          Option<ScaleMode>,
+    #[options(
+        no_short,
+        help = "screen wipe between game states, melt or fade",
+        default = "melt"
+    )]
+    pub wipe:           WipeKind,
+    #[options(
+        no_short,
+        help = "shader to draw the screen with, basic, lottes, cgwg or chain. Overrides the config"
+    )]
+    pub shader:         Option<ShaderKind>,
+    #[options(
+        no_short,
+        help = "path to the config file",
+        default = "./diirdoom.cfg"
+    )]
+    pub config:         String,
+    #[options(
+        no_short,
+        help = "screenshots and recordings are of the window after the shader rather than the 320x200 screen"
+    )]
+    pub capture_window: bool,
+    #[options(
+        no_short,
+        help = "save every frame drawn as numbered PNGs in this directory"
+    )]
+    pub record:         Option<String>,
+    #[options(
+        no_short,
+        help = "with --record, save once per game tic for a steady 35 frames a second"
+    )]
+    pub record_tics:    bool,
+    #[options(
+        no_short,
+        help = "draw only when a game tic has run, as vanilla, with no interpolation between tics"
+    )]
+    pub capped:         bool,
+    #[options(
+        no_short,
+        help = "mouse look up and down. The view is sheared rather than rotated so is not true 3D"
+    )]
+    pub free_look:      bool,
+    #[options(
+        no_short,
+        help = "threads to draw the view with, 0 for one per core",
+        default = "1"
+    )]
+    pub render_threads: usize,
+    #[options(
+        no_short,
+        help = "show the frame rate, a frame time graph and render times"
+    )]
+    pub stats:          bool,
 
     #[options(help = "Disable monsters")]
     pub no_monsters:  bool,
@@ -141,7 +332,7 @@ pub fn identify_version(wad: &wad::WadData) -> (GameMode, GameMission, String) {
 pub fn d_doom_loop(
     mut game: Game,
     mut input: Input,
-    gl: Window,
+    mut gl: Window,
     ctx: Context,
 ) -> Result<(), Box<dyn Error>> {
     let mut timestep = TimeStep::new();
@@ -149,27 +340,43 @@ pub fn d_doom_loop(
     let mut render_buffer =
         Surface::new(320, 200, PixelFormatEnum::RGBA32)?.into_canvas()?;
 
-    // TODO: sort this block of stuff out
-    let wsize = gl.drawable_size();
-    let ratio = wsize.1 as f32 / 3.0;
-    let xw = ratio * 4.0;
-    let xp = (wsize.0 as f32 - xw) / 2.0;
-    game.crop_rect = Rect::new(xp as i32, 0, xw as u32, wsize.1);
+    let mut presentation = Presentation {
+        scale:      game
+            .game_options
+            .scale
+            .or_else(|| game.defaults.get("scale"))
+            .unwrap_or_default(),
+        fullscreen: game.game_options.fullscreen,
+        changed:    false,
+    };
+    set_viewport(&mut game, &gl, &ctx, presentation.scale);
 
-    ctx.set_viewport(
-        game.crop_rect.x() as u32,
-        game.crop_rect.y() as u32,
-        game.crop_rect.width(),
-        game.crop_rect.height(),
-    );
+    let mut renderers = init_renderers(&game, &ctx);
+    input.config.load_defaults(&game.defaults);
+    input.config.set_free_look(game.game_options.free_look);
 
-    //let mut rend = Basic::new(&ctx);
-    let mut rend = LottesCRT::new(&ctx);
-    //let mut rend = CGWGCRT::new(&ctx, game.crop_rect.width(), game.crop_rect.height());
-    rend.set_tex_filter().unwrap();
+    let mut menu = Menu::new(&game);
 
     let buf_width = render_buffer.surface().width();
     let buf_height = render_buffer.surface().height();
+    // The wipe in progress, and the last frame drawn to start the next from
+    let mut wipe = None;
+    let mut last_frame = Vec::new();
+    let mut recorder = match game.game_options.record {
+        Some(ref dir) => {
+            Some(FrameRecorder::new(dir, game.game_options.record_tics)?)
+        }
+        None => None,
+    };
+    let mut stats_hud = if game.game_options.stats {
+        Some(StatsHud::new(&game))
+    } else {
+        None
+    };
+    let mut last_shown = Instant::now();
+    let gamma_tables = gamma_tables();
+    // The frame with gamma correction, as shown
+    let mut shown = Vec::new();
     loop {
         if !game.running() {
             break;
@@ -179,10 +386,48 @@ pub fn d_doom_loop(
         render_buffer.clear();
 
         // Update the game state
-        try_run_tics(&mut game, &mut input, &mut timestep);
+        let tics = try_run_tics(
+            &mut game,
+            &mut input,
+            &mut menu,
+            &mut wipe,
+            &mut renderers,
+            &mut presentation,
+            &mut timestep,
+        );
+        if input.take_resized() || presentation.changed {
+            presentation.changed = false;
+            let is_fullscreen = gl.fullscreen_state() != FullscreenType::Off;
+            if presentation.fullscreen != is_fullscreen {
+                let mode = if presentation.fullscreen {
+                    FullscreenType::Desktop
+                } else {
+                    FullscreenType::Off
+                };
+                gl.set_fullscreen(mode)?;
+                gl.set_bordered(!presentation.fullscreen);
+            }
+            set_viewport(&mut game, &gl, &ctx, presentation.scale);
+            renderers.resize(game.crop_rect);
+        }
+        if game.game_options.capped && tics == 0 {
+            // Wait for the next tic as vanilla does
+            std::thread::sleep(std::time::Duration::from_millis(1));
+            continue;
+        }
         // TODO: S_UpdateSounds(players[consoleplayer].mo); // move positional sounds
         // Draw everything to the buffer
-        d_display(&mut game, &mut render_buffer);
+        d_display(
+            &mut game,
+            &menu,
+            &mut wipe,
+            &mut last_frame,
+            timestep.tic_frac(),
+            &mut render_buffer,
+        );
+        if let Some(ref hud) = stats_hud {
+            hud.drawer(game.render_stats(), &mut render_buffer);
+        }
 
         let pix = render_buffer
             .read_pixels(
@@ -191,56 +436,296 @@ pub fn d_doom_loop(
             )
             .unwrap();
 
+        let rend = renderers.current();
         rend.clear();
-        rend.set_image_data(&pix, (buf_width, buf_height));
+        apply_gamma(&gamma_tables[game.usegamma], &pix, &mut shown);
+        rend.set_image_data(&shown, (buf_width, buf_height));
         rend.draw().unwrap();
 
-        gl.gl_swap_window();
+        // M_ScreenShot, done here rather than in G_Ticker as the game
+        // doesn't have the screen
+        let screenshot = game.take_screen_shot();
+        if screenshot || recorder.is_some() {
+            let (width, height, frame) = if game.game_options.capture_window {
+                let rect = game.crop_rect;
+                (rect.width(), rect.height(), read_window_pixels(&gl, rect)?)
+            } else {
+                // As shown, with the gamma correction
+                (buf_width, buf_height, shown.clone())
+            };
+            if screenshot {
+                let message = match screen_shot(width, height, &frame) {
+                    Ok(path) => {
+                        println!("Screenshot saved to {}", path.display());
+                        "screen shot"
+                    }
+                    Err(e) => {
+                        println!("{}", e);
+                        "screen shot failed"
+                    }
+                };
+                game.players[game.consoleplayer].message =
+                    Some(message.to_owned());
+            }
+            if let Some(ref mut recorder) = recorder {
+                recorder.record(tics, width, height, &frame)?;
+            }
+        }
+        last_frame = pix;
 
-        if let Some(fps) = timestep.frame_rate() {
-            println!("{:?}", fps);
+        gl.gl_swap_window();
+        if let Some(ref mut hud) = stats_hud {
+            let now = Instant::now();
+            hud.ticker(now - last_shown);
+            last_shown = now;
         }
     }
+    // I_Quit
+    game.save_defaults();
     Ok(())
 }
 
+/// How the screen is shown in the window, these can be changed while running
+struct Presentation {
+    scale:      ScaleMode,
+    fullscreen: bool,
+    /// Set when the window or viewport needs updating
+    changed:    bool,
+}
+
+/// Work out the part of the window to draw to for the window's size
+fn set_viewport(game: &mut Game, gl: &Window, ctx: &Context, scale: ScaleMode) {
+    game.crop_rect = scale.viewport(gl.drawable_size());
+    ctx.set_viewport(
+        game.crop_rect.x().max(0) as u32,
+        game.crop_rect.y().max(0) as u32,
+        game.crop_rect.width(),
+        game.crop_rect.height(),
+    );
+}
+
+/// Set up each of the shaders that can be switched between, and pick the
+/// one to start with from the options or the config
+fn init_renderers<'c>(game: &Game, ctx: &'c Context) -> Renderers<'c> {
+    let crop = game.crop_rect;
+    let mut renderers: Vec<(ShaderKind, Box<dyn Renderer + 'c>)> = vec![
+        (ShaderKind::Basic, Box::new(Basic::new(ctx))),
+        (ShaderKind::Lottes, Box::new(LottesCRT::new(ctx))),
+        (
+            ShaderKind::Cgwg,
+            Box::new(CGWGCRT::new(ctx, crop.width(), crop.height())),
+        ),
+    ];
+
+    if let Some(chain) = game.defaults.get_str("shader_chain") {
+        let paths: Vec<&str> = chain.split(',').map(|p| p.trim()).collect();
+        let viewport = (
+            crop.x().max(0) as u32,
+            crop.y().max(0) as u32,
+            crop.width(),
+            crop.height(),
+        );
+        match Chain::new(ctx, &paths, viewport) {
+            Ok(chain) => renderers.push((ShaderKind::Chain, Box::new(chain))),
+            Err(e) => println!("Shader chain not loaded: {}", e),
+        }
+    }
+
+    for (_, rend) in renderers.iter_mut() {
+        rend.set_tex_filter().unwrap();
+        rend.load_params(&game.defaults);
+    }
+
+    let kind = game
+        .game_options
+        .shader
+        .or_else(|| game.defaults.get("shader"))
+        .unwrap_or_default();
+    let current = renderers
+        .iter()
+        .position(|(k, _)| *k == kind)
+        .unwrap_or_else(|| {
+            println!("Shader {:?} is not available", kind);
+            0
+        });
+
+    Renderers::new(renderers.into_iter().map(|(_, r)| r).collect(), current)
+}
+
 /// D_Display
 /// Does a bunch of stuff in Doom...
-fn d_display(game: &mut Game, mut canvas: &mut Canvas<Surface>) {
-    //if (gamestate == GS_LEVEL && !automapactive && gametic)
-    game.render_player_view(&mut canvas);
+fn d_display(
+    game: &mut Game,
+    menu: &Menu,
+    wipe: &mut Option<Wipe>,
+    last_frame: &mut Vec<u8>,
+    frac: f32,
+    mut canvas: &mut Canvas<Surface>,
+) {
+    // save the current screen if about to wipe
+    if game.game_state() != game.wipe_game_state && !last_frame.is_empty() {
+        *wipe = Some(Wipe::start(
+            game.game_options.wipe,
+            std::mem::take(last_frame),
+            canvas.surface().width() as usize,
+            canvas.surface().height() as usize,
+        ));
+    }
+
+    match game.game_state() {
+        GameState::GS_LEVEL => {
+            //if (gamestate == GS_LEVEL && !automapactive && gametic)
+            game.render_player_view(frac, &mut canvas);
+            draw_view_window(game, canvas).unwrap();
+        }
+        GameState::GS_INTERMISSION => {
+            if let Some(ref mut wi) = game.intermission {
+                wi.drawer(canvas);
+            }
+        }
+        GameState::GS_FINALE => game.finale_drawer(canvas),
+        GameState::GS_DEMOSCREEN => page_drawer(game, canvas),
+        _ => {}
+    }
     //canvas.present();
 
-    // // menus go directly to the screen
-    // TODO: M_Drawer();	 // menu is drawn even on top of everything
+    game.wipe_game_state = game.game_state();
+
+    // draw what is left of the old screen over the new
+    if let Some(ref wipe) = wipe {
+        wipe.drawer(canvas);
+    }
+
+    // menus go directly to the screen
+    // menu is drawn even on top of everything
+    menu.drawer(game, canvas);
+
+    // the palette is for the whole screen, menu included
+    game.palette_drawer(canvas);
+
     // net update does i/o and buildcmds...
     // TODO: NetUpdate(); // send out any new accumulation
 }
 
-fn try_run_tics(game: &mut Game, input: &mut Input, timestep: &mut TimeStep) {
+/// D_PageDrawer
+// TODO: the demo loop cycling pages
+fn page_drawer(game: &Game, canvas: &mut Canvas<Surface>) {
+    let page = game.wad_data().get_patch("TITLEPIC");
+    draw_patch(&page, 0, 0, &game.palettes[0], canvas);
+}
+
+/// R_SetViewSize, R_FillBackScreen, R_DrawViewBorder
+///
+/// The view is always rendered at full size, so low detail and the smaller
+/// screen sizes are done here on the finished view.
+// TODO: render in to the view window once the renderer has one
+fn draw_view_window(
+    game: &Game,
+    canvas: &mut Canvas<Surface>,
+) -> Result<(), String> {
+    if game.detail_low {
+        // Each pixel is doubled in width
+        let surface = canvas.surface_mut();
+        let width = surface.width() as usize;
+        let pitch = surface.pitch() as usize;
+        surface.with_lock_mut(|buf| {
+            for row in buf.chunks_exact_mut(pitch) {
+                for x in (0..width - 1).step_by(2) {
+                    row.copy_within(x * 4..x * 4 + 4, x * 4 + 4);
+                }
+            }
+        });
+    }
+
+    if game.screen_blocks >= 10 {
+        return Ok(());
+    }
+    let width = game.screen_blocks as u32 * 32;
+    let height = (game.screen_blocks as u32 * 200 / 10) & !7;
+    let view_rect = Rect::new(
+        ((320 - width) / 2) as i32,
+        ((200 - height) / 2) as i32,
+        width,
+        height,
+    );
+
+    let mut view =
+        canvas.surface().convert(&canvas.surface().pixel_format())?;
+    view.set_blend_mode(BlendMode::None)?;
+
+    let flat = if game.game_mode() == GameMode::Commercial {
+        "GRNROCK"
+    } else {
+        "FLOOR7_2"
+    };
+    let flat = game.wad_data().get_flat(flat);
+    let screen = canvas.surface().rect();
+    fill_flat(&flat, screen, &game.palettes[0], canvas);
+
+    view.blit_scaled(None, canvas.surface_mut(), view_rect)?;
+    Ok(())
+}
+
+/// Returns the number of game tics run
+fn try_run_tics(
+    game: &mut Game,
+    input: &mut Input,
+    menu: &mut Menu,
+    wipe: &mut Option<Wipe>,
+    renderers: &mut Renderers,
+    presentation: &mut Presentation,
+    timestep: &mut TimeStep,
+) -> u32 {
     // TODO: net.c starts here
     input.update(); // D_ProcessEvents
+    for key in input.take_key_presses() {
+        if key == input.config.key_shader() {
+            renderers.cycle();
+            continue;
+        }
+        if key == input.config.key_fullscreen() {
+            presentation.fullscreen = !presentation.fullscreen;
+            presentation.changed = true;
+            continue;
+        }
+        if key == input.config.key_scale() {
+            presentation.scale = presentation.scale.next();
+            presentation.changed = true;
+            println!("Scale mode: {:?}", presentation.scale);
+            continue;
+        }
+        // The menu gets the first look at events
+        if menu.responder(key, game, input) {
+            continue;
+        }
+        game.responder(key);
+    }
 
     let console_player = game.consoleplayer;
     // net update does i/o and buildcmds...
     // TODO: NetUpdate(); // send out any new accumulation
 
-    // temporary block
-    game.set_running(!input.get_quit());
+    // The window was closed
+    if input.get_quit() {
+        game.set_running(false);
+    }
 
     // TODO: Network code would update each player slot with incoming TicCmds...
     let cmd = input.tic_events.build_tic_cmd(&input.config);
     game.netcmds[console_player][0] = cmd;
 
-    // Special key check
-    if input.tic_events.is_kb_pressed(Scancode::Escape) {
-        game.set_running(false);
-    }
-
     // Build tics here?
     // TODO: Doom-like timesteps
+    let mut tics = 0;
     timestep.run_this(|_| {
+        // The wipe runs alongside the game rather than blocking it
+        if wipe.as_mut().map_or(false, |w| w.ticker()) {
+            *wipe = None;
+        }
+        menu.ticker();
         // G_Ticker
         game.ticker();
+        tics += 1;
     });
+    tics
 }
@@ -104,9 +104,9 @@ pub(crate) enum ActionFunc {
     /// NULL thinker, used to tell the thinker runner to remove the thinker from list
     None,
     /// Called in the Thinker runner and State
-    MapObject(*const dyn Fn(&mut MapObject)),
-    /// Called in the Thinker runner and State
-    Player(*const dyn Fn(&mut Player, &mut PspDef)), // P_SetPsprite runs this
+    MapObject(*const dyn Fn(&mut MapObject, &mut Level)),
+    /// Called by P_SetPsprite when a psprite changes State
+    Player(*const dyn Fn(&mut Player, &mut PspDef, &mut Level)),
     // Lights
     FireFlicker(*const dyn Fn(&mut FireFlicker)),
     LightFlash(*const dyn Fn(&mut LightFlash)),
@@ -119,14 +119,16 @@ pub(crate) enum ActionFunc {
 }
 
 impl ActionFunc {
-    pub fn mobj_func(&self) -> *const dyn Fn(&mut MapObject) {
+    pub fn mobj_func(&self) -> *const dyn Fn(&mut MapObject, &mut Level) {
         match self {
             ActionFunc::MapObject(f) => *f,
             _ => panic!("Incorrect object for function"),
         }
     }
 
-    pub fn player_func(&self) -> *const dyn Fn(&mut Player, &mut PspDef) {
+    pub fn player_func(
+        &self,
+    ) -> *const dyn Fn(&mut Player, &mut PspDef, &mut Level) {
         match self {
             ActionFunc::Player(f) => *f,
             _ => panic!("Incorrect object for function"),
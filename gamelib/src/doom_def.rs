@@ -118,6 +118,24 @@ pub enum WeaponType {
     wp_nochange,
 }
 
+impl From<i32> for WeaponType {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => WeaponType::wp_fist,
+            1 => WeaponType::wp_pistol,
+            2 => WeaponType::wp_shotgun,
+            3 => WeaponType::wp_chaingun,
+            4 => WeaponType::wp_missile,
+            5 => WeaponType::wp_plasma,
+            6 => WeaponType::wp_bfg,
+            7 => WeaponType::wp_chainsaw,
+            8 => WeaponType::wp_supershotgun,
+            9 => WeaponType::NUMWEAPONS,
+            _ => WeaponType::wp_nochange,
+        }
+    }
+}
+
 pub const MAX_AMMO: [u32; 4] = [200, 50, 300, 50];
 pub const CLIP_AMMO: [u32; 4] = [10, 4, 20, 1];
 
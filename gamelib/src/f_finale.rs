@@ -0,0 +1,603 @@
+//! The end of episode and Doom 2 cluster finales: the text crawl over a
+//! flat, the end pictures and bunny scroller, and the Doom 2 cast call.
+//!
+//! TODO: the TNT and Plutonia texts, all missions use the Doom 2 ones.
+
+use sdl2::{rect::Rect, render::Canvas, surface::Surface};
+use wad::{
+    lumps::{WadFlat, WadPalette, WadPatch},
+    WadData,
+};
+
+use crate::{
+    doom_def::{GameMode, MAXPLAYERS},
+    info::{
+        map_object_info::MOBJINFO,
+        states::{get_state, State},
+        MapObjectType, StateNum, SPRNAMES,
+    },
+    player::Player,
+    v_video::{draw_patch, draw_patch_column, fill_flat},
+};
+
+const TEXTSPEED: i32 = 3;
+const TEXTWAIT: i32 = 250;
+
+/// The first and last characters in the HUD font, `STCFN033` to `STCFN095`
+const HU_FONTSTART: u8 = b'!';
+const HU_FONTEND: u8 = b'_';
+
+const FF_FRAMEMASK: i32 = 0x7fff;
+
+const E1TEXT: &str = "Once you beat the big badasses and\n\
+clean out the moon base you're supposed\n\
+to win, aren't you? Aren't you? Where's\n\
+your fat reward and ticket home? What\n\
+the hell is this? It's not supposed to\n\
+end this way!\n\
+\n\
+It stinks like rotten meat, but looks\n\
+like the lost Deimos base.  Looks like\n\
+you're stuck on The Shores of Hell.\n\
+The only way out is through.\n\
+\n\
+To continue the DOOM experience, play\n\
+The Shores of Hell and its amazing\n\
+sequel, Inferno!\n";
+
+const E2TEXT: &str = "You've done it! The hideous cyber-\n\
+demon lord that ruled the lost Deimos\n\
+moon base has been slain and you\n\
+are triumphant! But ... where are\n\
+you? You clamber to the edge of the\n\
+moon and look down to see the awful\n\
+truth.\n\
+\n\
+Deimos floats above Hell itself!\n\
+You've never heard of anyone escaping\n\
+from Hell, but you'll make the bastards\n\
+sorry they ever heard of you! Quickly,\n\
+you rappel down to  the surface of\n\
+Hell.\n\
+\n\
+Now, it's on to the final chapter of\n\
+DOOM! -- Inferno.";
+
+const E3TEXT: &str = "The loathsome spiderdemon that\n\
+masterminded the invasion of the moon\n\
+bases and caused so much death has had\n\
+its ass kicked for all time.\n\
+\n\
+A hidden doorway opens and you enter.\n\
+You've proven too tough for Hell to\n\
+contain, and now Hell at last plays\n\
+fair -- for you emerge from the door\n\
+to see the green fields of Earth!\n\
+Home at last.\n\
+\n\
+You wonder what's been happening on\n\
+Earth while you were battling evil\n\
+unleashed. It's good that no Hell-\n\
+spawn could have come through that\n\
+door with you ...";
+
+const E4TEXT: &str = "the spider mastermind must have sent forth\n\
+its legions of hellspawn before your\n\
+final confrontation with that terrible\n\
+beast from hell.  but you stepped forward\n\
+and brought forth eternal damnation and\n\
+suffering upon the horde as a true hero\n\
+would in the face of something so evil.\n\
+\n\
+besides, someone was gonna pay for what\n\
+happened to daisy, your pet rabbit.\n\
+\n\
+but now, you see spread before you more\n\
+potential pain and gibbitude as a nation\n\
+of demons run amok among our cities.\n\
+\n\
+next stop, hell on earth!";
+
+const C1TEXT: &str = "YOU HAVE ENTERED DEEPLY INTO THE INFESTED\n\
+STARPORT. BUT SOMETHING IS WRONG. THE\n\
+MONSTERS HAVE BROUGHT THEIR OWN REALITY\n\
+WITH THEM, AND THE STARPORT'S TECHNOLOGY\n\
+IS BEING SUBVERTED BY THEIR PRESENCE.\n\
+\n\
+AHEAD, YOU SEE AN OUTPOST OF HELL, A\n\
+FORTIFIED ZONE. IF YOU CAN GET PAST IT,\n\
+YOU CAN PENETRATE INTO THE HAUNTED HEART\n\
+OF THE STARBASE AND FIND THE CONTROLLING\n\
+SWITCH WHICH HOLDS EARTH'S POPULATION\n\
+HOSTAGE.";
+
+const C2TEXT: &str = "YOU HAVE WON! YOUR VICTORY HAS ENABLED\n\
+HUMANKIND TO EVACUATE EARTH AND ESCAPE\n\
+THE NIGHTMARE.  NOW YOU ARE THE ONLY\n\
+HUMAN LEFT ON THE FACE OF THE PLANET.\n\
+CANNIBAL MUTATIONS, CARNIVOROUS ALIENS,\n\
+AND EVIL SPIRITS ARE YOUR ONLY NEIGHBORS.\n\
+YOU SIT BACK AND WAIT FOR DEATH, CONTENT\n\
+THAT YOU HAVE SAVED YOUR SPECIES.\n\
+\n\
+BUT THEN, EARTH CONTROL BEAMS DOWN A\n\
+MESSAGE FROM SPACE: \"SENSORS HAVE LOCATED\n\
+THE SOURCE OF THE ALIEN INVASION. IF YOU\n\
+GO THERE, YOU MAY BE ABLE TO BLOCK THEIR\n\
+ENTRY.  THE ALIEN BASE IS IN THE HEART OF\n\
+YOUR OWN HOME CITY, NOT FAR FROM THE\n\
+STARPORT.\" SLOWLY AND PAINFULLY YOU GET\n\
+UP AND RETURN TO THE FRAY.";
+
+const C3TEXT: &str = "YOU ARE AT THE CORRUPT HEART OF THE CITY,\n\
+SURROUNDED BY THE CORPSES OF YOUR ENEMIES.\n\
+YOU SEE NO WAY TO DESTROY THE CREATURES'\n\
+ENTRYWAY ON THIS SIDE, SO YOU CLENCH YOUR\n\
+TEETH AND PLUNGE THROUGH IT.\n\
+\n\
+THERE MUST BE A WAY TO CLOSE IT ON THE\n\
+OTHER SIDE. WHAT DO YOU CARE IF YOU'VE\n\
+GOT TO GO THROUGH HELL TO GET TO IT?";
+
+const C4TEXT: &str = "THE HORRENDOUS VISAGE OF THE BIGGEST\n\
+DEMON YOU'VE EVER SEEN CRUMBLES BEFORE\n\
+YOU, AFTER YOU PUMP YOUR ROCKETS INTO\n\
+HIS EXPOSED BRAIN. THE MONSTER SHRIVELS\n\
+UP AND DIES, ITS THRASHING LIMBS\n\
+DEVASTATING UNTOLD MILES OF HELL'S\n\
+SURFACE.\n\
+\n\
+YOU'VE DONE IT. THE INVASION IS OVER.\n\
+EARTH IS SAVED. HELL IS A WRECK. YOU\n\
+WONDER WHERE BAD FOLKS WILL GO WHEN THEY\n\
+DIE, NOW. WIPING THE SWEAT FROM YOUR\n\
+FOREHEAD YOU BEGIN THE LONG TREK BACK\n\
+HOME. REBUILDING EARTH OUGHT TO BE A\n\
+LOT MORE FUN THAN RUINING IT WAS.\n";
+
+const C5TEXT: &str = "CONGRATULATIONS, YOU'VE FOUND THE SECRET\n\
+LEVEL! LOOKS LIKE IT'S BEEN BUILT BY\n\
+HUMANS, RATHER THAN DEMONS. YOU WONDER\n\
+WHO THE INMATES OF THIS CORNER OF HELL\n\
+WILL BE.";
+
+const C6TEXT: &str = "CONGRATULATIONS, YOU'VE FOUND THE\n\
+SUPER SECRET LEVEL!  YOU'D BETTER\n\
+BLAZE THROUGH THIS ONE!\n";
+
+/// Final DOOM 2 animation. Casting by id Software, in order of appearance
+const CAST_ORDER: [(&str, MapObjectType); 17] = [
+    ("ZOMBIEMAN", MapObjectType::MT_POSSESSED),
+    ("SHOTGUN GUY", MapObjectType::MT_SHOTGUY),
+    ("HEAVY WEAPON DUDE", MapObjectType::MT_CHAINGUY),
+    ("IMP", MapObjectType::MT_TROOP),
+    ("DEMON", MapObjectType::MT_SERGEANT),
+    ("LOST SOUL", MapObjectType::MT_SKULL),
+    ("CACODEMON", MapObjectType::MT_HEAD),
+    ("HELL KNIGHT", MapObjectType::MT_KNIGHT),
+    ("BARON OF HELL", MapObjectType::MT_BRUISER),
+    ("ARACHNOTRON", MapObjectType::MT_BABY),
+    ("PAIN ELEMENTAL", MapObjectType::MT_PAIN),
+    ("REVENANT", MapObjectType::MT_UNDEAD),
+    ("MANCUBUS", MapObjectType::MT_FATSO),
+    ("ARCH-VILE", MapObjectType::MT_VILE),
+    ("THE SPIDER MASTERMIND", MapObjectType::MT_SPIDER),
+    ("THE CYBERDEMON", MapObjectType::MT_CYBORG),
+    ("OUR HERO", MapObjectType::MT_PLAYER),
+];
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum FinaleStage {
+    /// The text crawl
+    Text,
+    /// The end picture or bunny scroller
+    ArtScreen,
+    /// The Doom 2 cast call
+    Cast,
+}
+
+/// The finale state, created by `F_StartFinale`
+pub(crate) struct Finale {
+    stage:         FinaleStage,
+    count:         i32,
+    text:          &'static str,
+    flat:          WadFlat,
+    game_mode:     GameMode,
+    episode:       u32,
+    map:           u32,
+    /// The last bunny scroller "THE END" stage drawn
+    laststage:     i32,
+    castnum:       usize,
+    casttics:      i32,
+    caststate:     StateNum,
+    castdeath:     bool,
+    castframes:    i32,
+    castonmelee:   bool,
+    castattacking: bool,
+    hu_font:       Vec<WadPatch>,
+    palette:       WadPalette,
+}
+
+fn state(num: StateNum) -> State { get_state(num as usize) }
+
+fn is_state(a: StateNum, b: StateNum) -> bool { a as usize == b as usize }
+
+impl Finale {
+    /// F_StartFinale
+    pub(crate) fn start(
+        game_mode: GameMode,
+        episode: u32,
+        map: u32,
+        wad: &WadData,
+        palette: &WadPalette,
+    ) -> Self {
+        // Okay - IWAD dependend stuff.
+        // This has been changed severly, and
+        //  some stuff might have changed in the process.
+        let (flat, text) = if game_mode == GameMode::Commercial {
+            // TODO: S_ChangeMusic(mus_read_m, true);
+            match map {
+                6 => ("SLIME16", C1TEXT),
+                11 => ("RROCK14", C2TEXT),
+                20 => ("RROCK07", C3TEXT),
+                30 => ("RROCK17", C4TEXT),
+                15 => ("RROCK13", C5TEXT),
+                31 => ("RROCK19", C6TEXT),
+                // Ouch.
+                _ => ("SLIME16", C1TEXT),
+            }
+        } else {
+            // TODO: S_ChangeMusic(mus_victor, true);
+            match episode {
+                1 => ("FLOOR4_8", E1TEXT),
+                2 => ("SFLR6_1", E2TEXT),
+                3 => ("MFLR8_4", E3TEXT),
+                4 => ("MFLR8_3", E4TEXT),
+                // Ouch.
+                _ => ("F_SKY1", E1TEXT),
+            }
+        };
+
+        let hu_font = (HU_FONTSTART..=HU_FONTEND)
+            .map(|c| wad.get_patch(&format!("STCFN{:03}", c)))
+            .collect();
+
+        Self {
+            stage: FinaleStage::Text,
+            count: 0,
+            text,
+            flat: wad.get_flat(flat),
+            game_mode,
+            episode,
+            map,
+            laststage: 0,
+            castnum: 0,
+            casttics: 0,
+            caststate: StateNum::S_NULL,
+            castdeath: false,
+            castframes: 0,
+            castonmelee: false,
+            castattacking: false,
+            hu_font,
+            palette: palette.clone(),
+        }
+    }
+
+    /// F_Responder, any key press kills the monster in the cast call
+    pub(crate) fn responder(&mut self) -> bool {
+        if self.stage == FinaleStage::Cast {
+            return self.cast_responder();
+        }
+        false
+    }
+
+    /// F_Ticker
+    ///
+    /// Returns true when the finale is done and `G_WorldDone` should run
+    pub(crate) fn ticker(
+        &mut self,
+        players: &[Player],
+        player_in_game: &[bool],
+    ) -> bool {
+        // check for skipping
+        if self.game_mode == GameMode::Commercial && self.count > 50 {
+            // go on to the next level
+            let skip = (0..MAXPLAYERS)
+                .any(|i| player_in_game[i] && players[i].cmd.buttons != 0);
+            if skip && self.stage != FinaleStage::Cast {
+                if self.map == 30 {
+                    self.start_cast();
+                } else {
+                    return true;
+                }
+            }
+        }
+
+        // advance animation
+        self.count += 1;
+
+        if self.stage == FinaleStage::Cast {
+            self.cast_ticker();
+            return false;
+        }
+
+        if self.game_mode == GameMode::Commercial {
+            return false;
+        }
+
+        if self.stage == FinaleStage::Text
+            && self.count > self.text.len() as i32 * TEXTSPEED + TEXTWAIT
+        {
+            self.count = 0;
+            self.stage = FinaleStage::ArtScreen;
+            // TODO: wipegamestate = -1; // force a wipe
+            // TODO: if (gameepisode == 3) S_StartMusic(mus_bunny);
+        }
+        false
+    }
+
+    fn font_patch(&self, c: char) -> Option<&WadPatch> {
+        let c = c.to_ascii_uppercase() as u8;
+        if c < HU_FONTSTART || c > HU_FONTEND {
+            return None;
+        }
+        self.hu_font.get((c - HU_FONTSTART) as usize)
+    }
+
+    /// F_TextWrite
+    fn text_write(&self, canvas: &mut Canvas<Surface>) {
+        // erase the entire screen to a tiled background
+        fill_flat(&self.flat, Rect::new(0, 0, 320, 200), &self.palette, canvas);
+
+        // draw some of the text onto the screen
+        let mut cx = 10;
+        let mut cy = 10;
+        let mut count = (self.count - 10) / TEXTSPEED;
+        if count < 0 {
+            count = 0;
+        }
+
+        for c in self.text.chars() {
+            if count == 0 {
+                break;
+            }
+            count -= 1;
+
+            if c == '\n' {
+                cx = 10;
+                cy += 11;
+                continue;
+            }
+
+            match self.font_patch(c) {
+                Some(patch) => {
+                    let w = patch.width as i32;
+                    if cx + w > 320 {
+                        break;
+                    }
+                    draw_patch(patch, cx, cy, &self.palette, canvas);
+                    cx += w;
+                }
+                None => cx += 4,
+            }
+        }
+    }
+
+    /// F_StartCast
+    fn start_cast(&mut self) {
+        // TODO: wipegamestate = -1; // force a screen wipe
+        self.castnum = 0;
+        self.caststate = MOBJINFO[CAST_ORDER[0].1 as usize].seestate;
+        self.casttics = state(self.caststate).tics;
+        self.castdeath = false;
+        self.stage = FinaleStage::Cast;
+        self.castframes = 0;
+        self.castonmelee = false;
+        self.castattacking = false;
+        // TODO: S_ChangeMusic(mus_evil, true);
+    }
+
+    /// F_CastTicker
+    fn cast_ticker(&mut self) {
+        self.casttics -= 1;
+        if self.casttics > 0 {
+            // not time to change state yet
+            return;
+        }
+
+        let current = state(self.caststate);
+        let mut stop_attack = false;
+        if current.tics == -1 || is_state(current.next_state, StateNum::S_NULL)
+        {
+            // switch from deathstate to next monster
+            self.castnum += 1;
+            self.castdeath = false;
+            if self.castnum == CAST_ORDER.len() {
+                self.castnum = 0;
+            }
+            // TODO: if (mobjinfo[castorder[castnum].type].seesound)
+            //  S_StartSound(NULL, mobjinfo[castorder[castnum].type].seesound);
+            self.caststate =
+                MOBJINFO[CAST_ORDER[self.castnum].1 as usize].seestate;
+            self.castframes = 0;
+        } else if is_state(self.caststate, StateNum::S_PLAY_ATK1) {
+            // Oh, gross hack!
+            stop_attack = true;
+        } else {
+            // just advance to next state in animation
+            self.caststate = current.next_state;
+            self.castframes += 1;
+            // TODO: sound hacks, the attack sounds for each monster's
+            //  attack frames
+        }
+
+        let info = &MOBJINFO[CAST_ORDER[self.castnum].1 as usize];
+
+        if !stop_attack && self.castframes == 12 {
+            // go into attack frame
+            self.castattacking = true;
+            self.caststate = if self.castonmelee {
+                info.meleestate
+            } else {
+                info.missilestate
+            };
+            self.castonmelee = !self.castonmelee;
+            if is_state(self.caststate, StateNum::S_NULL) {
+                self.caststate = if self.castonmelee {
+                    info.meleestate
+                } else {
+                    info.missilestate
+                };
+            }
+        }
+
+        if stop_attack
+            || (self.castattacking
+                && (self.castframes == 24
+                    || is_state(self.caststate, info.seestate)))
+        {
+            self.castattacking = false;
+            self.castframes = 0;
+            self.caststate = info.seestate;
+        }
+
+        self.casttics = state(self.caststate).tics;
+        if self.casttics == -1 {
+            self.casttics = 15;
+        }
+    }
+
+    /// F_CastResponder
+    fn cast_responder(&mut self) -> bool {
+        if self.castdeath {
+            // already in dying frames
+            return true;
+        }
+
+        // go into death frame
+        self.castdeath = true;
+        self.caststate =
+            MOBJINFO[CAST_ORDER[self.castnum].1 as usize].deathstate;
+        self.casttics = state(self.caststate).tics;
+        self.castframes = 0;
+        self.castattacking = false;
+        // TODO: if (mobjinfo[castorder[castnum].type].deathsound)
+        //  S_StartSound(NULL, mobjinfo[castorder[castnum].type].deathsound);
+        true
+    }
+
+    /// F_CastPrint
+    fn cast_print(&self, text: &str, canvas: &mut Canvas<Surface>) {
+        // find width
+        let width: i32 = text
+            .chars()
+            .map(|c| self.font_patch(c).map_or(4, |p| p.width as i32))
+            .sum();
+
+        // draw it
+        let mut cx = 160 - width / 2;
+        for c in text.chars() {
+            match self.font_patch(c) {
+                Some(patch) => {
+                    draw_patch(patch, cx, 180, &self.palette, canvas);
+                    cx += patch.width as i32;
+                }
+                None => cx += 4,
+            }
+        }
+    }
+
+    /// The first rotation of a sprite frame. Sprites without rotations use
+    /// rotation 0, the others use rotation 1 which is never flipped.
+    fn sprite_patch(wad: &WadData, st: &State) -> Option<WadPatch> {
+        let name = SPRNAMES[st.sprite as usize];
+        let frame = (b'A' + (st.frame & FF_FRAMEMASK) as u8) as char;
+        let lump = [0, 1]
+            .iter()
+            .map(|r| format!("{}{}{}", name, frame, r))
+            .find(|l| wad.lump_exists(l))?;
+        Some(wad.get_patch(&lump))
+    }
+
+    /// F_CastDrawer
+    fn cast_drawer(&self, wad: &WadData, canvas: &mut Canvas<Surface>) {
+        // erase the entire screen to a background
+        let background = wad.get_patch("BOSSBACK");
+        draw_patch(&background, 0, 0, &self.palette, canvas);
+
+        self.cast_print(CAST_ORDER[self.castnum].0, canvas);
+
+        // draw the current frame in the middle of the screen
+        if let Some(patch) = Self::sprite_patch(wad, &state(self.caststate)) {
+            draw_patch(&patch, 160, 170, &self.palette, canvas);
+        }
+    }
+
+    /// F_BunnyScroll
+    fn bunny_scroll(&mut self, wad: &WadData, canvas: &mut Canvas<Surface>) {
+        let p1 = wad.get_patch("PFUB2");
+        let p2 = wad.get_patch("PFUB1");
+
+        let scrolled = (320 - (self.count - 230) / 2).max(0).min(320);
+        for x in 0..320 {
+            if x + scrolled < 320 {
+                let col = (x + scrolled) as usize;
+                draw_patch_column(&p1, col, x, 0, &self.palette, canvas);
+            } else {
+                let col = (x + scrolled - 320) as usize;
+                draw_patch_column(&p2, col, x, 0, &self.palette, canvas);
+            }
+        }
+
+        if self.count < 1130 {
+            return;
+        }
+        let (x, y) = ((320 - 13 * 8) / 2, (200 - 8 * 8) / 2);
+        if self.count < 1180 {
+            let end = wad.get_patch("END0");
+            draw_patch(&end, x, y, &self.palette, canvas);
+            self.laststage = 0;
+            return;
+        }
+
+        let stage = ((self.count - 1180) / 5).min(6);
+        if stage > self.laststage {
+            // TODO: S_StartSound(NULL, sfx_pistol);
+            self.laststage = stage;
+        }
+
+        let end = wad.get_patch(&format!("END{}", stage));
+        draw_patch(&end, x, y, &self.palette, canvas);
+    }
+
+    /// F_Drawer
+    pub(crate) fn drawer(
+        &mut self,
+        wad: &WadData,
+        canvas: &mut Canvas<Surface>,
+    ) {
+        match self.stage {
+            FinaleStage::Cast => self.cast_drawer(wad, canvas),
+            FinaleStage::Text => self.text_write(canvas),
+            FinaleStage::ArtScreen => {
+                let page = match self.episode {
+                    1 => {
+                        if self.game_mode == GameMode::Retail {
+                            "CREDIT"
+                        } else {
+                            "HELP2"
+                        }
+                    }
+                    2 => "VICTORY2",
+                    3 => {
+                        self.bunny_scroll(wad, canvas);
+                        return;
+                    }
+                    _ => "ENDPIC",
+                };
+                let page = wad.get_patch(page);
+                draw_patch(&page, 0, 0, &self.palette, canvas);
+            }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Screen wipes between game states, done over the 320x200 render buffer.
+//!
+//! Vanilla blocks in `D_Display` until the wipe is finished. Here the wipe
+//! is advanced once per game tic alongside `G_Ticker`, and the start screen
+//! is drawn over each new frame until it is done.
+
+use sdl2::{render::Canvas, surface::Surface};
+
+use crate::{d_main::WipeKind, p_local::m_random};
+
+/// How many tics the crossfade takes
+const FADE_TICS: i32 = 24;
+
+pub(crate) struct Wipe {
+    kind:   WipeKind,
+    /// The screen to wipe away, a copy of the last frame drawn
+    start:  Vec<u8>,
+    width:  usize,
+    height: usize,
+    pitch:  usize,
+    /// Melt: the offset of each two pixel column, negative is a delay.
+    y:      Vec<i32>,
+    /// Crossfade: tics run so far
+    tics:   i32,
+}
+
+impl Wipe {
+    /// wipe_StartScreen and wipe_initMelt
+    ///
+    /// `start` is the RGBA32 pixels of the last frame, `width` and `height`
+    /// are of the render buffer
+    pub(crate) fn start(
+        kind: WipeKind,
+        start: Vec<u8>,
+        width: usize,
+        height: usize,
+    ) -> Self {
+        let pitch = start.len() / height;
+
+        // setup initial column positions
+        // (y<0 => not ready to scroll yet)
+        let mut y = vec![0; width / 2];
+        if kind == WipeKind::Melt {
+            y[0] = -((m_random() % 16) as i32);
+            for i in 1..y.len() {
+                let r = (m_random() % 3) as i32 - 1;
+                y[i] = y[i - 1] + r;
+                if y[i] > 0 {
+                    y[i] = 0;
+                } else if y[i] == -16 {
+                    y[i] = -15;
+                }
+            }
+        }
+
+        Self {
+            kind,
+            start,
+            width,
+            height,
+            pitch,
+            y,
+            tics: 0,
+        }
+    }
+
+    /// wipe_doMelt and wipe_doColorXForm for a single tic. Returns true
+    /// when the wipe is finished.
+    pub(crate) fn ticker(&mut self) -> bool {
+        match self.kind {
+            WipeKind::Melt => {
+                let height = self.height as i32;
+                let mut done = true;
+                for y in self.y.iter_mut() {
+                    if *y < 0 {
+                        *y += 1;
+                        done = false;
+                    } else if *y < height {
+                        let mut dy = if *y < 16 { *y + 1 } else { 8 };
+                        if *y + dy >= height {
+                            dy = height - *y;
+                        }
+                        *y += dy;
+                        done = false;
+                    }
+                }
+                done
+            }
+            WipeKind::Crossfade => {
+                self.tics += 1;
+                self.tics >= FADE_TICS
+            }
+        }
+    }
+
+    /// Draw what is left of the start screen over the new frame in `canvas`
+    pub(crate) fn drawer(&self, canvas: &mut Canvas<Surface>) {
+        let surface = canvas.surface_mut();
+        let pitch = surface.pitch() as usize;
+        let width = self.width.min(surface.width() as usize);
+        let height = self.height.min(surface.height() as usize);
+
+        surface.with_lock_mut(|buf| match self.kind {
+            WipeKind::Melt => {
+                for x in 0..width {
+                    let dy = self.y[x / 2].max(0) as usize;
+                    for y in dy..height {
+                        let i = y * pitch + x * 4;
+                        let s = (y - dy) * self.pitch + x * 4;
+                        buf[i..i + 4].copy_from_slice(&self.start[s..s + 4]);
+                    }
+                }
+            }
+            WipeKind::Crossfade => {
+                let alpha = self.tics as u32 * 256 / FADE_TICS as u32;
+                for y in 0..height {
+                    for x in 0..width * 4 {
+                        let i = y * pitch + x;
+                        let end = buf[i] as u32;
+                        let start = self.start[y * self.pitch + x] as u32;
+                        buf[i] =
+                            ((end * alpha + start * (256 - alpha)) >> 8) as u8;
+                    }
+                }
+            }
+        });
+    }
+}
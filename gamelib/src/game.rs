@@ -1,7 +1,21 @@
+use crate::d_thinker::ActionFunc;
+use crate::f_finale::Finale;
 use crate::level_data::level;
 use crate::level_data::level::Level;
+use crate::m_misc::Defaults;
+use crate::p_saveg::{
+    archive_players, archive_specials, archive_thinkers, archive_world,
+    save_game_file, unarchive_players, unarchive_specials, unarchive_thinkers,
+    unarchive_world, SaveHeader, SaveReader, SaveWriter,
+};
 use crate::player::{Player, WBStartStruct};
+use crate::renderer::debug::RenderDebug;
+use crate::renderer::draw_list::Rasteriser;
+use crate::renderer::stats::RenderStats;
+use crate::renderer::things::{draw_masked, SpriteData};
 use crate::tic_cmd::TicCmd;
+use crate::v_video::{PaletteShift, NUM_GAMMA};
+use crate::wi_stuff::Intermission;
 use crate::{d_main, player::PlayerState};
 use crate::{
     d_main::{GameOptions, Skill},
@@ -9,8 +23,27 @@ use crate::{
 };
 use crate::{doom_def::*, tic_cmd::TIC_CMD_BUTTONS};
 use d_main::identify_version;
-use sdl2::{rect::Rect, render::Canvas, surface::Surface};
-use wad::WadData;
+use sdl2::{keyboard::Scancode, rect::Rect, render::Canvas, surface::Surface};
+use std::time::Instant;
+use wad::{lumps::WadPalette, WadData};
+
+const GGSAVED: &str = "game saved.";
+
+/// DOOM Par Times
+const PARS: [[i32; 10]; 4] = [
+    [0; 10],
+    [0, 30, 75, 120, 90, 165, 180, 180, 30, 165],
+    [0, 90, 90, 90, 120, 90, 360, 240, 30, 170],
+    [0, 90, 45, 90, 150, 90, 90, 165, 30, 135],
+];
+
+/// DOOM II Par Times
+const CPARS: [i32; 32] = [
+    30, 90, 120, 120, 90, 150, 120, 120, 270, 90, //  1-10
+    210, 150, 150, 150, 210, 150, 420, 150, 210, 150, // 11-20
+    240, 150, 180, 150, 150, 300, 330, 420, 300, 180, // 21-30
+    120, 30, // 31-32
+];
 
 /// Game is very much driven by d_main, which operates as an orchestrator
 pub struct Game {
@@ -19,12 +52,12 @@ pub struct Game {
     pub(crate) level: Option<Level>,
     pub crop_rect:    Rect,
 
-    running:    bool,
+    running:            bool,
     // Game locals
     /// only if started as net death
-    deathmatch: bool,
+    deathmatch:         bool,
     /// only true if packets are broadcast
-    netgame:    bool,
+    pub(crate) netgame: bool,
 
     /// Tracks which players are currently active, set by d_net.c loop
     pub(crate) player_in_game: [bool; MAXPLAYERS],
@@ -46,7 +79,19 @@ pub struct Game {
     /// If non-zero, exit the level after this number of minutes.
     time_limit: Option<i32>,
 
-    pub paused: bool,
+    pub paused:               bool,
+    /// Set by the menu when it is open, the level ticker pauses while set
+    pub(crate) menu_active:   bool,
+    /// The view size set in the menu, 3-11. Anything under 10 draws a border
+    pub(crate) screen_blocks: i32,
+    /// Low detail doubles the width of each pixel in the view
+    pub(crate) detail_low:    bool,
+    /// The gamma correction level, 0 is off
+    pub(crate) usegamma:      usize,
+    /// The palettes from PLAYPAL, 0 is the normal palette
+    pub(crate) palettes:      Vec<WadPalette>,
+    /// How to get from palette 0 to each of the others in the frame
+    palette_shifts:           Vec<PaletteShift>,
 
     /// player taking events and displaying
     pub(crate) consoleplayer: usize,
@@ -61,20 +106,40 @@ pub struct Game {
     /// for intermission
     totalsecret:              i32,
 
-    wminfo: WBStartStruct,
+    wminfo:                  WBStartStruct,
+    /// The intermission screen, exists while in `GS_INTERMISSION`
+    pub(crate) intermission: Option<Intermission>,
+    /// The finale, exists while in `GS_FINALE`
+    finale:                  Option<Finale>,
+    /// Set if the level was exited through a secret exit
+    secret_exit:             bool,
 
     /// d_net.c
     pub(crate) netcmds: [[TicCmd; BACKUPTICS]; MAXPLAYERS],
     /// d_net.c
     localcmds:          [TicCmd; BACKUPTICS],
 
-    game_mode:       GameMode,
-    game_mission:    GameMission,
-    wipe_game_state: GameState,
-    usergame:        bool,
+    game_mode:                  GameMode,
+    game_mission:               GameMission,
+    /// The game state last drawn, a wipe is done when this differs from
+    /// `game_state`. `FORCE_WIPE` forces a wipe on the next frame
+    pub(crate) wipe_game_state: GameState,
+    usergame:                   bool,
+    save_game_slot:             usize,
+    save_description:           String,
 
     /// The options the game exe was started with
-    pub game_options: GameOptions,
+    pub game_options:    GameOptions,
+    /// Settings from the config file
+    pub(crate) defaults: Defaults,
+    /// Draws the view once the BSP walk is done
+    rasteriser:          Rasteriser,
+    /// The sprite frames and patches things are drawn with
+    sprites:             SpriteData,
+    /// The renderer overlays for `dev_parm`
+    render_debug:        RenderDebug,
+    /// The renderer stats of the last frame drawn
+    render_stats:        RenderStats,
 }
 
 impl Game {
@@ -107,6 +172,12 @@ impl Game {
             wad.add_file(pwad.into());
         }
 
+        let palettes: Vec<WadPalette> = wad.palette_iter().collect();
+        let palette_shifts = palettes
+            .iter()
+            .map(|p| PaletteShift::new(&palettes[0], p))
+            .collect();
+
         // Mimic the OG output
         println!(
             "\n{} Startup v{}.{}\n",
@@ -116,6 +187,13 @@ impl Game {
         );
         println!("V_Init: allocate screens.");
         println!("M_LoadDefaults: Load system defaults.");
+        let defaults = Defaults::load(&options.config);
+        let usegamma = defaults.get("usegamma").unwrap_or(0).min(NUM_GAMMA - 1);
+        let rasteriser = Rasteriser::new(options.render_threads)
+            .unwrap_or_else(|e| {
+                println!("Could not start render threads, using one: {}", e);
+                Rasteriser::default()
+            });
         println!("Z_Init: Init zone memory allocation daemon.");
         println!("W_Init: Init WADfiles.");
         match game_mode {
@@ -135,6 +213,7 @@ impl Game {
         }
         println!("M_Init: Init miscellaneous info.");
         println!("R_Init: Init DOOM refresh daemon - ");
+        let sprites = SpriteData::new(&wad);
         println!("\nP_Init: Init Playloop state.");
         println!("I_Init: Setting up machine state.");
         println!("D_CheckNetGame: Checking network game status.");
@@ -158,11 +237,17 @@ impl Game {
             player_in_game: [true, false, false, false], // should be set in d_net.c
 
             paused: false,
+            menu_active: false,
+            screen_blocks: 10,
+            detail_low: false,
+            usegamma,
+            palettes,
+            palette_shifts,
             deathmatch: false,
             netgame: false,
             turbodetected: [false; MAXPLAYERS],
             old_game_state: GameState::GS_LEVEL,
-            game_action: GameAction::ga_loadlevel, // TODO: default to ga_nothing when more state is done
+            game_action: GameAction::ga_newgame, // TODO: default to ga_nothing when more state is done
             game_state: GameState::GS_LEVEL,
             game_skill: options.skill,
             game_tic: 0,
@@ -177,6 +262,9 @@ impl Game {
             totalitems: 0,
             totalsecret: 0,
             wminfo: WBStartStruct::default(),
+            intermission: None,
+            finale: None,
+            secret_exit: false,
 
             netcmds: [[TicCmd::new(); BACKUPTICS]; MAXPLAYERS],
             localcmds: [TicCmd::new(); BACKUPTICS],
@@ -185,7 +273,14 @@ impl Game {
             game_mission,
             wipe_game_state: GameState::GS_LEVEL,
             usergame: false,
+            save_game_slot: 0,
+            save_description: String::new(),
             game_options: options,
+            defaults,
+            rasteriser,
+            sprites,
+            render_debug: RenderDebug::default(),
+            render_stats: RenderStats::default(),
         }
     }
 
@@ -209,10 +304,35 @@ impl Game {
         self.game_action = GameAction::ga_newgame;
     }
 
+    /// G_LoadGame
+    /// Called by the menu task, the load happens on the next tic
+    pub(crate) fn load_game(&mut self, slot: usize) {
+        self.save_game_slot = slot;
+        self.game_action = GameAction::ga_loadgame;
+    }
+
+    /// G_SaveGame
+    /// Called by the menu task, the save happens on the next tic
+    pub(crate) fn save_game(&mut self, slot: usize, description: String) {
+        self.save_game_slot = slot;
+        self.save_description = description;
+        self.game_action = GameAction::ga_savegame;
+    }
+
+    /// D_StartTitle
+    /// There is no demo loop yet so this only shows the title page
+    pub(crate) fn start_title(&mut self) {
+        self.game_action = GameAction::ga_nothing;
+        self.game_state = GameState::GS_DEMOSCREEN;
+        self.level = None;
+        self.usergame = false;
+        self.paused = false;
+    }
+
     fn do_new_game(&mut self) {
         self.netgame = false;
         self.deathmatch = false;
-        for i in 0..self.players.len() {
+        for i in 1..self.players.len() {
             self.player_in_game[i] = false;
         }
         self.respawn_monsters = false;
@@ -311,6 +431,90 @@ impl Game {
         //         break;
         //     }
         println!("New game!");
+        self.do_load_level();
+    }
+
+    /// G_DoLoadGame
+    fn do_load_game(&mut self) {
+        self.game_action = GameAction::ga_nothing;
+
+        let path = save_game_file(self.save_game_slot);
+        let mut save = match SaveReader::new(&path) {
+            Ok(save) => save,
+            Err(e) => {
+                println!("G_DoLoadGame: {}", e);
+                return;
+            }
+        };
+        // skip the description field
+        let header = match save.read_header() {
+            Some(header) => header,
+            None => {
+                println!("G_DoLoadGame: Bad savegame version");
+                return;
+            }
+        };
+
+        let skill = match header.skill {
+            0 => Skill::Baby,
+            1 => Skill::Easy,
+            2 => Skill::Medium,
+            3 => Skill::Hard,
+            4 => Skill::Nightmare,
+            _ => Skill::NoItems,
+        };
+        self.player_in_game = header.player_in_game;
+
+        // load a base level
+        self.init_new(skill, header.episode as u32, header.map as u32);
+
+        // dearchive all the modifications
+        if let Some(ref mut level) = self.level {
+            level.level_time = header.level_time;
+            unarchive_players(
+                &mut self.players,
+                &self.player_in_game,
+                level,
+                &mut save,
+            );
+            unarchive_world(level, &mut save);
+            unarchive_thinkers(level, &mut save);
+            unarchive_specials(level, &mut save);
+            if !save.is_complete() {
+                println!("G_DoLoadGame: Bad savegame");
+            }
+        }
+    }
+
+    /// G_DoSaveGame
+    fn do_save_game(&mut self) {
+        self.game_action = GameAction::ga_nothing;
+
+        if let Some(ref level) = self.level {
+            let mut save = SaveWriter::new(
+                &self.save_description,
+                SaveHeader {
+                    skill:          self.game_skill as u8,
+                    episode:        self.game_episode as u8,
+                    map:            self.game_map as u8,
+                    player_in_game: self.player_in_game,
+                    level_time:     level.level_time,
+                },
+            );
+            archive_players(&self.players, &self.player_in_game, &mut save);
+            archive_world(level, &mut save);
+            archive_thinkers(level, &mut save);
+            archive_specials(level, &mut save);
+
+            match save.write_file(&save_game_file(self.save_game_slot)) {
+                Ok(_) => {
+                    self.players[self.consoleplayer].message =
+                        Some(GGSAVED.to_owned());
+                    println!("{}", GGSAVED);
+                }
+                Err(e) => println!("G_DoSaveGame: {}", e),
+            }
+        }
     }
 
     fn do_load_level(&mut self) {
@@ -354,12 +558,12 @@ impl Game {
         level.game_tic = self.game_tic;
 
         println!("Level started: E{} M{}", level.episode, level.game_map);
-        self.level = Some(level);
 
         // Player setup from P_SetupLevel
-        self.totalkills = 0;
-        self.totalitems = 0;
-        self.totalsecret = 0;
+        self.totalkills = level.totalkills;
+        self.totalitems = level.totalitems;
+        self.totalsecret = level.totalsecret;
+        self.level = Some(level);
         self.wminfo.maxfrags = 0;
         self.wminfo.partime = 180;
         self.players[self.consoleplayer].viewz = 1.0;
@@ -367,15 +571,217 @@ impl Game {
         // TODO: S_Start();
     }
 
+    /// G_ExitLevel
+    pub(crate) fn exit_level(&mut self) {
+        self.secret_exit = false;
+        self.game_action = GameAction::ga_completed;
+    }
+
+    /// G_SecretExitLevel
+    pub(crate) fn secret_exit_level(&mut self) {
+        // IF NO WOLF3D LEVELS, NO SECRET EXIT!
+        self.secret_exit = !(self.game_mode == GameMode::Commercial
+            && !self.wad_data.lump_exists("MAP31"));
+        self.game_action = GameAction::ga_completed;
+    }
+
+    /// G_DoCompleted
+    fn do_completed(&mut self) {
+        self.game_action = GameAction::ga_nothing;
+
+        for i in 0..MAXPLAYERS {
+            if self.player_in_game[i] {
+                self.players[i].finish_level();
+            }
+        }
+
+        // TODO: if (automapactive) AM_Stop();
+
+        if self.game_mode != GameMode::Commercial {
+            match self.game_map {
+                8 => {
+                    self.game_action = GameAction::ga_victory;
+                    return;
+                }
+                9 => {
+                    for player in self.players.iter_mut() {
+                        player.didsecret = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.wminfo.didsecret = self.players[self.consoleplayer].didsecret;
+        self.wminfo.epsd = self.game_episode as i32 - 1;
+        self.wminfo.last = self.game_map as i32 - 1;
+
+        // wminfo.next is 0 biased, unlike gamemap
+        if self.game_mode == GameMode::Commercial {
+            if self.secret_exit {
+                match self.game_map {
+                    15 => self.wminfo.next = 30,
+                    31 => self.wminfo.next = 31,
+                    _ => {}
+                }
+            } else {
+                match self.game_map {
+                    31 | 32 => self.wminfo.next = 15,
+                    _ => self.wminfo.next = self.game_map as i32,
+                }
+            }
+        } else if self.secret_exit {
+            // go to secret level
+            self.wminfo.next = 8;
+        } else if self.game_map == 9 {
+            // returning from secret level
+            self.wminfo.next = match self.game_episode {
+                1 => 3,
+                2 => 5,
+                3 => 6,
+                4 => 2,
+                _ => 0,
+            };
+        } else {
+            // go to next level
+            self.wminfo.next = self.game_map as i32;
+        }
+
+        self.wminfo.maxkills = self.totalkills;
+        self.wminfo.maxitems = self.totalitems;
+        self.wminfo.maxsecret = self.totalsecret;
+        self.wminfo.maxfrags = 0;
+        self.wminfo.partime = if self.game_mode == GameMode::Commercial {
+            TICRATE * CPARS[self.game_map as usize - 1]
+        } else {
+            // There are no par times for episode 4
+            TICRATE
+                * PARS
+                    .get(self.game_episode as usize)
+                    .map_or(0, |p| p[self.game_map as usize])
+        };
+        self.wminfo.pnum = self.consoleplayer as i32;
+
+        let level_time = self.level.as_ref().map_or(0, |l| l.level_time);
+        for i in 0..MAXPLAYERS {
+            let player = &self.players[i];
+            let plyr = &mut self.wminfo.plyr[i];
+            plyr.inn = self.player_in_game[i];
+            plyr.skills = player.killcount;
+            plyr.sitems = player.itemcount;
+            plyr.ssecret = player.secretcount;
+            plyr.stime = level_time as i32;
+            plyr.frags = player.frags;
+        }
+
+        self.game_state = GameState::GS_INTERMISSION;
+        // TODO: viewactive = false; automapactive = false;
+
+        self.intermission = Some(Intermission::start(
+            self.wminfo,
+            self.game_mode,
+            &self.wad_data,
+            &self.palettes[0],
+        ));
+    }
+
+    /// G_WorldDone
+    fn world_done(&mut self) {
+        self.game_action = GameAction::ga_worlddone;
+
+        if self.secret_exit {
+            self.players[self.consoleplayer].didsecret = true;
+        }
+
+        if self.game_mode == GameMode::Commercial {
+            match self.game_map {
+                15 | 31 if self.secret_exit => self.start_finale(),
+                6 | 11 | 20 | 30 => self.start_finale(),
+                _ => {}
+            }
+        }
+    }
+
+    /// F_StartFinale
+    fn start_finale(&mut self) {
+        self.game_action = GameAction::ga_nothing;
+        self.game_state = GameState::GS_FINALE;
+        self.intermission = None;
+        // TODO: viewactive = false; automapactive = false;
+
+        self.finale = Some(Finale::start(
+            self.game_mode,
+            self.game_episode,
+            self.game_map,
+            &self.wad_data,
+            &self.palettes[0],
+        ));
+    }
+
+    /// G_DoWorldDone
+    fn do_world_done(&mut self) {
+        self.game_state = GameState::GS_LEVEL;
+        self.game_map = self.wminfo.next as u32 + 1;
+        self.intermission = None;
+        self.finale = None;
+        self.do_load_level();
+        self.game_action = GameAction::ga_nothing;
+        // TODO: viewactive = true;
+    }
+
     pub(crate) fn running(&self) -> bool { self.running }
 
     pub(crate) fn set_running(&mut self, run: bool) { self.running = run; }
 
+    pub(crate) fn wad_data(&self) -> &WadData { &self.wad_data }
+
+    pub(crate) fn game_mode(&self) -> GameMode { self.game_mode }
+
+    pub(crate) fn game_state(&self) -> GameState { self.game_state }
+
+    pub(crate) fn usergame(&self) -> bool { self.usergame }
+
     fn do_reborn(&mut self, player_num: usize) {
         self.game_action = GameAction::ga_loadlevel;
         // TODO: deathmatch spawns
     }
 
+    /// G_ScreenShot
+    pub(crate) fn screen_shot(&mut self) {
+        self.game_action = GameAction::ga_screenshot;
+    }
+
+    /// True if a screenshot was asked for, the action is then cleared
+    pub(crate) fn take_screen_shot(&mut self) -> bool {
+        if matches!(self.game_action, GameAction::ga_screenshot) {
+            self.game_action = GameAction::ga_nothing;
+            return true;
+        }
+        false
+    }
+
+    /// G_Responder
+    /// Get info needed to make ticcmd_ts for the players.
+    ///
+    /// Only the key presses the menu did not eat are passed here
+    pub(crate) fn responder(&mut self, key: Scancode) -> bool {
+        // TODO: allow spy mode changes even during the demo
+        // TODO: any other key pops up menu if in demos
+        if self.game_state == GameState::GS_FINALE {
+            if let Some(ref mut finale) = self.finale {
+                return finale.responder();
+            }
+        }
+        if self.game_options.dev_parm
+            && self.game_state == GameState::GS_LEVEL
+            && self.render_debug.responder(key)
+        {
+            return true;
+        }
+        // TODO: HU_Responder, ST_Responder, AM_Responder
+        false
+    }
+
     /// G_Ticker
     pub(crate) fn ticker(&mut self) {
         // // do player reborns if needed
@@ -424,6 +830,13 @@ impl Game {
         match self.game_action {
             GameAction::ga_loadlevel => self.do_load_level(),
             GameAction::ga_newgame => self.do_new_game(),
+            GameAction::ga_loadgame => self.do_load_game(),
+            GameAction::ga_savegame => self.do_save_game(),
+            GameAction::ga_completed => self.do_completed(),
+            GameAction::ga_worlddone => self.do_world_done(),
+            GameAction::ga_victory => self.start_finale(),
+            // Left for d_doom_loop to take once the screen is drawn
+            GameAction::ga_screenshot => {}
             _ => {}
         }
 
@@ -475,58 +888,202 @@ impl Game {
 
         match self.game_state {
             GameState::GS_LEVEL => {
+                // Where things were before the tic, for drawing between tics
+                if let Some(ref mut level) = self.level {
+                    level.store_previous();
+                }
+                for player in self.players.iter_mut() {
+                    player.store_previous();
+                }
                 // P_Ticker(); // player movements, run thinkers etc
                 level::ticker(self);
+                // A line special exited the level during the tic
+                let exit = self.level.as_mut().and_then(|level| {
+                    if level.exit_level {
+                        level.exit_level = false;
+                        Some(level.secret_exit)
+                    } else {
+                        None
+                    }
+                });
+                match exit {
+                    Some(true) => self.secret_exit_level(),
+                    Some(false) => self.exit_level(),
+                    None => {}
+                }
                 // ST_Ticker();
                 // AM_Ticker();
                 // HU_Ticker();
             }
             GameState::GS_INTERMISSION => {
-                //WI_Ticker();
+                if let Some(ref mut wi) = self.intermission {
+                    if wi.ticker(&mut self.players, &self.player_in_game) {
+                        self.world_done();
+                    }
+                }
             }
             GameState::GS_FINALE => {
-                // F_Ticker();
+                if let Some(ref mut finale) = self.finale {
+                    if finale.ticker(&self.players, &self.player_in_game) {
+                        self.game_action = GameAction::ga_worlddone;
+                    }
+                }
             }
             GameState::GS_DEMOSCREEN => {
                 // D_PageTicker();
             }
-            GameState::FORCE_WIPE => {
-                // do a wipe
+            // Only used in `wipe_game_state`
+            GameState::FORCE_WIPE => {}
+        }
+    }
+
+    /// F_Drawer
+    pub(crate) fn finale_drawer(&mut self, canvas: &mut Canvas<Surface>) {
+        if let Some(ref mut finale) = self.finale {
+            finale.drawer(&self.wad_data, canvas);
+        }
+    }
+
+    /// M_SaveDefaults, with the settings that can be changed while playing
+    pub(crate) fn save_defaults(&mut self) {
+        self.defaults.set("usegamma", self.usegamma);
+        if let Err(e) = self.defaults.save(&self.game_options.config) {
+            println!("M_SaveDefaults: {}", e);
+        }
+    }
+
+    /// The counts and timings of the renderer for the last frame drawn
+    pub fn render_stats(&self) -> &RenderStats { &self.render_stats }
+
+    /// ST_doPaletteStuff and I_SetPalette
+    ///
+    /// Shift the finished frame to the palette for the state of the player
+    /// being viewed, the red of damage or berserk, yellow of a pickup or the
+    /// green of the radiation suit
+    pub(crate) fn palette_drawer(&self, canvas: &mut Canvas<Surface>) {
+        if self.game_state != GameState::GS_LEVEL {
+            return;
+        }
+        let palette = self.players[self.displayplayer].palette();
+        if palette != 0 {
+            if let Some(shift) = self.palette_shifts.get(palette) {
+                shift.apply(canvas);
             }
         }
     }
 
     /// D_Display
     // TODO: Move
-    pub(crate) fn render_player_view(&mut self, canvas: &mut Canvas<Surface>) {
+    /// `frac` is how far between the last tic and the next the frame is
+    /// drawn, the view and moving sectors are interpolated by this unless
+    /// rendering is capped to tics
+    pub(crate) fn render_player_view(
+        &mut self,
+        frac: f32,
+        canvas: &mut Canvas<Surface>,
+    ) {
         if !self.player_in_game[0] {
             return;
         }
 
         if let Some(ref mut level) = self.level {
-            let map = &level.map_data;
-
+            let frac = if self.game_options.capped { 1.0 } else { frac };
             let player = &mut self.players[self.consoleplayer];
+            let view = player.interpolate_view(frac);
+            let heights = level.interpolate_sectors(frac);
+            let map = &level.map_data;
 
             level.visplanes.clear_planes();
             level.bsp_renderer.clear_clip_segs();
             level.r_data.clear_data();
+            level.r_data.debug = self.render_debug.start_frame();
+            let pitch = if self.game_options.free_look {
+                player.pitch
+            } else {
+                0.0
+            };
+            level.r_data.set_view_pitch(pitch);
+            level.visplanes.set_yslope(level.r_data.centery);
             // The state machine will handle which state renders to the surface
             //self.states.render(dt, &mut self.canvas);
 
+            let horizon = (level.r_data.centery as i32).max(0).min(200);
             let colour = sdl2::pixels::Color::RGBA(90, 80, 80, 255);
             canvas.set_draw_color(colour);
-            canvas.fill_rect(Rect::new(0, 0, 320, 100)).unwrap();
+            if horizon > 0 {
+                canvas
+                    .fill_rect(Rect::new(0, 0, 320, horizon as u32))
+                    .unwrap();
+            }
             let colour = sdl2::pixels::Color::RGBA(90, 90, 90, 255);
             canvas.set_draw_color(colour);
-            canvas.fill_rect(Rect::new(0, 100, 320, 100)).unwrap();
+            if horizon < 200 {
+                canvas
+                    .fill_rect(Rect::new(
+                        0,
+                        horizon,
+                        320,
+                        (200 - horizon) as u32,
+                    ))
+                    .unwrap();
+            }
+            let bsp_start = Instant::now();
             level.bsp_renderer.render_bsp_node(
                 &map,
                 player,
                 map.start_node(),
                 &mut level.r_data,
+            );
+            level.r_data.stats.bsp_time = bsp_start.elapsed();
+
+            // Other players are seen, the console player's own thing is at
+            // the view and never is
+            let console = self.consoleplayer;
+            let in_game = &self.player_in_game;
+            let others = self
+                .players
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != console && in_game[*i])
+                .filter_map(|(_, p)| p.mobj.as_ref().map(|mobj| &mobj.obj));
+            let things = level
+                .thinkers
+                .iter()
+                .flatten()
+                .filter(|t| matches!(t.function, ActionFunc::MapObject(_)))
+                .map(|t| &t.obj);
+            draw_masked(
+                things.chain(others),
+                frac,
+                &self.players[console],
+                &self.sprites,
+                &self.palettes[0],
+                &mut level.r_data,
+            );
+
+            let surface = canvas.surface_mut();
+            let width = surface.width() as usize;
+            let height = surface.height() as usize;
+            let pitch = surface.pitch() as usize;
+            let draw_list = &level.r_data.draw_list;
+            let rasteriser = &self.rasteriser;
+            let draw_start = Instant::now();
+            surface.with_lock_mut(|buf| {
+                rasteriser.draw(draw_list, buf, width, height, pitch)
+            });
+
+            let stats = &mut level.r_data.stats;
+            stats.draw_time = draw_start.elapsed();
+            stats.visplanes = level.visplanes.lastvisplane;
+            self.render_stats = *stats;
+            self.render_debug.end_frame(
+                &self.render_stats,
+                level.bsp_renderer.solid_segs(),
                 canvas,
             );
+
+            level.restore_sectors(heights);
+            self.players[console].restore_view(view);
         }
     }
 }
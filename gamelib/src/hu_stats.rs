@@ -0,0 +1,150 @@
+//! The frame rate HUD, shown with `--stats`. This is not in vanilla.
+//!
+//! Shows the frames drawn in the last second, the time taken by the BSP walk
+//! and drawing of the view, and a graph of the time each of the last frames
+//! took. The graph is green under 1/60th of a second, yellow under a game tic
+//! and red over.
+
+use std::{collections::VecDeque, time::Duration};
+
+use sdl2::{pixels::Color, rect::Rect, render::Canvas, surface::Surface};
+use wad::lumps::{WadPalette, WadPatch};
+
+use crate::{game::Game, renderer::stats::RenderStats, v_video::draw_patch};
+
+/// The first and last characters in the HUD font, `STCFN033` to `STCFN095`
+const HU_FONTSTART: u8 = b'!';
+const HU_FONTEND: u8 = b'_';
+
+/// How many frames are in the graph, one pixel wide each
+const GRAPH_FRAMES: usize = 64;
+/// Frame times over this many milliseconds are cut off, one pixel per ms
+const GRAPH_HEIGHT: u32 = 32;
+const GRAPH_X: i32 = 4;
+const GRAPH_Y: i32 = 200 - 4 - GRAPH_HEIGHT as i32;
+
+const MS_60HZ: f32 = 1000.0 / 60.0;
+const MS_PER_TIC: f32 = 1000.0 / 35.0;
+
+pub(crate) struct StatsHud {
+    hu_font:     Vec<WadPatch>,
+    palette:     WadPalette,
+    /// Frame times in milliseconds, oldest first
+    frame_times: VecDeque<f32>,
+    /// Frames drawn in the last full second
+    fps:         u32,
+    frames:      u32,
+    second:      Duration,
+}
+
+impl StatsHud {
+    pub(crate) fn new(game: &Game) -> Self {
+        let wad = game.wad_data();
+        let hu_font = (HU_FONTSTART..=HU_FONTEND)
+            .map(|c| wad.get_patch(&format!("STCFN{:03}", c)))
+            .collect();
+
+        Self {
+            hu_font,
+            palette: game.palettes[0].clone(),
+            frame_times: VecDeque::with_capacity(GRAPH_FRAMES),
+            fps: 0,
+            frames: 0,
+            second: Duration::default(),
+        }
+    }
+
+    /// Add the time taken by the frame just shown
+    pub(crate) fn ticker(&mut self, frame_time: Duration) {
+        if self.frame_times.len() == GRAPH_FRAMES {
+            self.frame_times.pop_front();
+        }
+        self.frame_times
+            .push_back(frame_time.as_secs_f32() * 1000.0);
+
+        self.frames += 1;
+        self.second += frame_time;
+        if self.second >= Duration::from_secs(1) {
+            self.fps = self.frames;
+            self.frames = 0;
+            self.second -= Duration::from_secs(1);
+        }
+    }
+
+    pub(crate) fn drawer(
+        &self,
+        stats: &RenderStats,
+        canvas: &mut Canvas<Surface>,
+    ) {
+        let frame_time = self.frame_times.back().copied().unwrap_or(0.0);
+        self.write_text(
+            GRAPH_X,
+            GRAPH_Y - 20,
+            &format!("FPS {} {:.1}MS", self.fps, frame_time),
+            canvas,
+        );
+        self.write_text(
+            GRAPH_X,
+            GRAPH_Y - 10,
+            &format!(
+                "BSP {:.2} DRAW {:.2}",
+                stats.bsp_time.as_secs_f32() * 1000.0,
+                stats.draw_time.as_secs_f32() * 1000.0
+            ),
+            canvas,
+        );
+
+        canvas.set_draw_color(Color::RGB(0, 0, 0));
+        canvas
+            .fill_rect(Rect::new(
+                GRAPH_X,
+                GRAPH_Y,
+                GRAPH_FRAMES as u32,
+                GRAPH_HEIGHT,
+            ))
+            .unwrap();
+        for (i, time) in self.frame_times.iter().enumerate() {
+            let colour = if *time <= MS_60HZ {
+                Color::RGB(0, 200, 0)
+            } else if *time <= MS_PER_TIC {
+                Color::RGB(200, 200, 0)
+            } else {
+                Color::RGB(200, 0, 0)
+            };
+            let height = (time.ceil() as u32).max(1).min(GRAPH_HEIGHT);
+            canvas.set_draw_color(colour);
+            canvas
+                .fill_rect(Rect::new(
+                    GRAPH_X + i as i32,
+                    GRAPH_Y + (GRAPH_HEIGHT - height) as i32,
+                    1,
+                    height,
+                ))
+                .unwrap();
+        }
+    }
+
+    /// M_WriteText, for a single line
+    fn write_text(
+        &self,
+        x: i32,
+        y: i32,
+        string: &str,
+        canvas: &mut Canvas<Surface>,
+    ) {
+        let mut cx = x;
+        for c in string.chars() {
+            let c = c.to_ascii_uppercase() as u8;
+            if c < HU_FONTSTART || c > HU_FONTEND {
+                cx += 4;
+                continue;
+            }
+            let patch = &self.hu_font[(c - HU_FONTSTART) as usize];
+            if cx + patch.width as i32 > 320 {
+                break;
+            }
+            draw_patch(patch, cx, y, &self.palette, canvas);
+            cx += patch.width as i32;
+        }
+    }
+}
@@ -1,11 +1,11 @@
 use std::collections::hash_set::HashSet;
 
-use sdl2::event::Event;
+use sdl2::event::{Event, WindowEvent};
 use sdl2::keyboard::Scancode as Sc;
 use sdl2::mouse::MouseButton as Mb;
 use sdl2::EventPump;
 
-use crate::{doom_def::WeaponType, tic_cmd::*};
+use crate::{doom_def::WeaponType, m_misc::Defaults, tic_cmd::*};
 
 #[derive(Debug, Default, Clone)]
 pub(crate) struct InputEvents {
@@ -13,6 +13,8 @@ pub(crate) struct InputEvents {
     mouse_state: HashSet<Mb>,
     mouse_delta: (i32, i32),
     mouse_scale: (i32, i32),
+    /// Unscaled mouse y, for free-look
+    mouse_look:  i32,
     turn_held:   u32,
 }
 impl InputEvents {
@@ -44,10 +46,14 @@ impl InputEvents {
         self.mouse_scale = scale;
     }
 
-    fn reset_mouse_delta(&mut self) { self.mouse_delta = (0, 0); }
+    fn reset_mouse_delta(&mut self) {
+        self.mouse_delta = (0, 0);
+        self.mouse_look = 0;
+    }
 
     fn set_mouse_pos(&mut self, state: (i32, i32)) {
         self.mouse_delta = (state.0 * self.mouse_scale.0, state.1 * self.mouse_scale.1);
+        self.mouse_look = state.1;
     }
 
     pub fn build_tic_cmd(&mut self, cfg: &InputConfig) -> TicCmd {
@@ -137,8 +143,12 @@ impl InputEvents {
         }
 
         let mousex = self.mouse_delta.0;
-
-        forward += self.mouse_delta.1;
+        if cfg.free_look {
+            // Moving the mouse forward looks up
+            cmd.lookpitch -= (self.mouse_look * 0x50) as i16;
+        } else {
+            forward += self.mouse_delta.1;
+        }
         if strafe {
             side += mousex * 2;
         } else {
@@ -182,7 +192,12 @@ pub struct Input {
     pump:                  EventPump,
     pub(crate) tic_events: InputEvents,
     pub(crate) config:     InputConfig,
+    /// Keys pressed since the last `update`, in order. Unlike `tic_events`
+    /// these are single events, used by the menus
+    key_presses:           Vec<Sc>,
     quit:                  bool,
+    /// Set when the window changes size, including going fullscreen
+    resized:               bool,
 }
 
 impl Input {
@@ -192,7 +207,9 @@ impl Input {
             pump,
             tic_events: InputEvents::new((10, 0)),
             config: InputConfig::default(),
+            key_presses: Vec::new(),
             quit: false,
+            resized: false,
         }
     }
 
@@ -207,11 +224,13 @@ impl Input {
     /// to cause delays in proccessing
     ///
     pub(crate) fn update(&mut self) {
+        self.key_presses.clear();
         while let Some(event) = self.pump.poll_event() {
             match event {
                 Event::KeyDown { scancode, .. } => {
                     if let Some(sc) = scancode {
                         self.tic_events.set_kb(sc);
+                        self.key_presses.push(sc);
                     }
                 }
                 Event::KeyUp { scancode, .. } => {
@@ -230,12 +249,27 @@ impl Input {
                     self.tic_events.set_mouse_pos((xrel, yrel));
                 }
 
+                Event::Window {
+                    win_event: WindowEvent::SizeChanged(..),
+                    ..
+                } => self.resized = true,
+
                 Event::Quit { .. } => self.quit = true, // Early out if Quit
                 _ => {}
             }
         }
     }
     pub(crate) fn get_quit(&self) -> bool { self.quit }
+
+    /// True if the window has changed size since last asked
+    pub(crate) fn take_resized(&mut self) -> bool {
+        std::mem::take(&mut self.resized)
+    }
+
+    /// Take the key presses gathered by the last `update`
+    pub(crate) fn take_key_presses(&mut self) -> Vec<Sc> {
+        std::mem::take(&mut self.key_presses)
+    }
 }
 
 pub(crate) struct InputConfig {
@@ -250,6 +284,14 @@ pub(crate) struct InputConfig {
     key_use:         Sc,
     key_strafe:      Sc,
     key_speed:       Sc,
+    /// Switch to the next shader
+    key_shader:      Sc,
+    /// Toggle fullscreen
+    key_fullscreen:  Sc,
+    /// Switch to the next way of fitting the screen to the window
+    key_scale:       Sc,
+    /// Mouse y looks up and down rather than moving
+    free_look:       bool,
 
     mousebfire:    Mb,
     mousebstrafe:  Mb,
@@ -270,6 +312,10 @@ impl Default for InputConfig {
             key_use:         Sc::Space,
             key_strafe:      Sc::RAlt,
             key_speed:       Sc::LShift,
+            key_shader:      Sc::Insert,
+            key_fullscreen:  Sc::Home,
+            key_scale:       Sc::End,
+            free_look:       false,
 
             mousebfire:    Mb::Left,
             mousebstrafe:  Mb::Middle,
@@ -279,6 +325,33 @@ impl Default for InputConfig {
 }
 
 impl InputConfig {
+    /// Set any keys given in the defaults file, by SDL scancode name
+    pub(crate) fn load_defaults(&mut self, defaults: &Defaults) {
+        let mut keys = [
+            ("key_right", &mut self.key_right),
+            ("key_left", &mut self.key_left),
+            ("key_up", &mut self.key_up),
+            ("key_down", &mut self.key_down),
+            ("key_strafeleft", &mut self.key_strafeleft),
+            ("key_straferight", &mut self.key_straferight),
+            ("key_fire", &mut self.key_fire),
+            ("key_use", &mut self.key_use),
+            ("key_strafe", &mut self.key_strafe),
+            ("key_speed", &mut self.key_speed),
+            ("key_shader", &mut self.key_shader),
+            ("key_fullscreen", &mut self.key_fullscreen),
+            ("key_scale", &mut self.key_scale),
+        ];
+        for (name, key) in keys.iter_mut() {
+            if let Some(value) = defaults.get_str(name) {
+                match Sc::from_name(value) {
+                    Some(sc) => **key = sc,
+                    None => println!("Unknown key for {}: {}", name, value),
+                }
+            }
+        }
+    }
+
     pub fn key_right(&self) -> Sc { self.key_right }
 
     pub fn key_left(&self) -> Sc { self.key_left }
@@ -299,6 +372,16 @@ impl InputConfig {
 
     pub fn key_speed(&self) -> Sc { self.key_speed }
 
+    pub fn key_shader(&self) -> Sc { self.key_shader }
+
+    pub fn key_fullscreen(&self) -> Sc { self.key_fullscreen }
+
+    pub fn key_scale(&self) -> Sc { self.key_scale }
+
+    pub fn set_free_look(&mut self, free_look: bool) {
+        self.free_look = free_look;
+    }
+
     pub fn mousebfire(&self) -> Mb { self.mousebfire }
 
     pub fn mousebstrafe(&self) -> Mb { self.mousebstrafe }
@@ -0,0 +1,205 @@
+//! The blockmap, a grid of 128 unit blocks over the level. Each block keeps
+//! a list of the things with their centre in it, so that collision checks
+//! only need to look at the things nearby.
+//!
+//! The BLOCKMAP lump is not read, the grid is built over the map extents and
+//! each block lists the lines whose bounding box touches it. Movement finds
+//! lines through the BSP, the line lists are used for tracing attacks.
+
+use std::ptr::NonNull;
+
+use glam::Vec2;
+
+use crate::level_data::map_data::MapExtents;
+use crate::level_data::map_defs::LineDef;
+use crate::p_map_object::MapObject;
+use crate::DPtr;
+
+/// MAPBLOCKUNITS
+pub(crate) const MAPBLOCKSIZE: f32 = 128.0;
+
+#[derive(Debug, Default)]
+pub(crate) struct BlockMap {
+    /// bmaporgx, bmaporgy
+    origin:  Vec2,
+    /// bmapwidth, in blocks
+    columns: i32,
+    /// bmapheight, in blocks
+    rows:    i32,
+    /// blocklinks, the things in each block
+    things:  Vec<Vec<NonNull<MapObject>>>,
+    /// blockmaplump, the lines touching each block
+    lines:   Vec<Vec<DPtr<LineDef>>>,
+}
+
+impl BlockMap {
+    pub(crate) fn new(extents: &MapExtents, linedefs: &[LineDef]) -> Self {
+        let columns = (extents.width / MAPBLOCKSIZE) as i32 + 1;
+        let rows = (extents.height / MAPBLOCKSIZE) as i32 + 1;
+        let mut blockmap = Self {
+            origin: extents.min_vertex,
+            columns,
+            rows,
+            things: vec![Vec::new(); (columns * rows) as usize],
+            lines: vec![Vec::new(); (columns * rows) as usize],
+        };
+
+        for line in linedefs {
+            let (xl, yl) = blockmap
+                .block_coords(Vec2::new(line.bbox.left, line.bbox.bottom));
+            let (xh, yh) = blockmap
+                .block_coords(Vec2::new(line.bbox.right, line.bbox.top));
+            for y in yl.max(0)..=yh.min(rows - 1) {
+                for x in xl.max(0)..=xh.min(columns - 1) {
+                    blockmap.lines[(y * columns + x) as usize]
+                        .push(DPtr::new(line));
+                }
+            }
+        }
+        blockmap
+    }
+
+    /// The column and row of the block `point` is in, this may be off the map
+    fn block_coords(&self, point: Vec2) -> (i32, i32) {
+        let rel = point - self.origin;
+        (
+            (rel.x() / MAPBLOCKSIZE).floor() as i32,
+            (rel.y() / MAPBLOCKSIZE).floor() as i32,
+        )
+    }
+
+    fn block_index(&self, point: Vec2) -> Option<usize> {
+        let (x, y) = self.block_coords(point);
+        if x < 0 || y < 0 || x >= self.columns || y >= self.rows {
+            return None;
+        }
+        Some((y * self.columns + x) as usize)
+    }
+
+    /// Add the thing to the block its centre is in. Things off the map are
+    /// not linked, the same as vanilla.
+    pub(crate) fn link(&mut self, thing: &mut MapObject) {
+        if let Some(index) = self.block_index(thing.xy) {
+            self.things[index].push(NonNull::from(thing));
+        }
+    }
+
+    /// Remove the thing from the block it is in. This must be done before
+    /// the thing moves. Nothing is done if the thing isn't linked.
+    pub(crate) fn unlink(&mut self, thing: &MapObject) {
+        if let Some(index) = self.block_index(thing.xy) {
+            let block = &mut self.things[index];
+            if let Some(i) = block
+                .iter()
+                .position(|t| t.as_ptr() as *const MapObject == thing)
+            {
+                block.swap_remove(i);
+            }
+        }
+    }
+
+    /// P_BlockThingsIterator for every block touched by the box from `min`
+    /// to `max`. The things are collected first so that the caller is free
+    /// to move or remove them while checking.
+    pub(crate) fn things_in_box(
+        &self,
+        min: Vec2,
+        max: Vec2,
+    ) -> Vec<NonNull<MapObject>> {
+        let (xl, yl) = self.block_coords(min);
+        let (xh, yh) = self.block_coords(max);
+        let mut things = Vec::new();
+        for y in yl.max(0)..=yh.min(self.rows - 1) {
+            for x in xl.max(0)..=xh.min(self.columns - 1) {
+                things.extend_from_slice(
+                    &self.things[(y * self.columns + x) as usize],
+                );
+            }
+        }
+        things
+    }
+
+    /// The blocks crossed by the line from `v1` to `v2`, in order from `v1`.
+    /// Blocks off the map are skipped.
+    pub(crate) fn blocks_on_line(&self, v1: Vec2, v2: Vec2) -> Vec<usize> {
+        let (mut x, mut y) = self.block_coords(v1);
+        let (xh, yh) = self.block_coords(v2);
+        let delta = v2 - v1;
+        let step_x = if delta.x() < 0.0 { -1 } else { 1 };
+        let step_y = if delta.y() < 0.0 { -1 } else { 1 };
+
+        // How far along the line, as a fraction, each block boundary is
+        let rel = v1 - self.origin;
+        let next_edge = |pos: f32, block: i32, step: i32, d: f32| {
+            if d == 0.0 {
+                return f32::INFINITY;
+            }
+            let edge = if step > 0 { block + 1 } else { block } as f32;
+            (edge * MAPBLOCKSIZE - pos) / d
+        };
+        let mut frac_x = next_edge(rel.x(), x, step_x, delta.x());
+        let mut frac_y = next_edge(rel.y(), y, step_y, delta.y());
+        let step_frac_x = (MAPBLOCKSIZE / delta.x()).abs();
+        let step_frac_y = (MAPBLOCKSIZE / delta.y()).abs();
+
+        let mut blocks = Vec::new();
+        let count = (xh - x).abs() + (yh - y).abs();
+        for _ in 0..=count {
+            if x >= 0 && y >= 0 && x < self.columns && y < self.rows {
+                blocks.push((y * self.columns + x) as usize);
+            }
+            if frac_x < frac_y {
+                frac_x += step_frac_x;
+                x += step_x;
+            } else {
+                frac_y += step_frac_y;
+                y += step_y;
+            }
+        }
+        blocks
+    }
+
+    /// The lines touching the block at `index`
+    pub(crate) fn lines_in(&self, index: usize) -> &[DPtr<LineDef>] {
+        &self.lines[index]
+    }
+
+    /// The things with their centre in the block at `index`
+    pub(crate) fn things_in(&self, index: usize) -> &[NonNull<MapObject>] {
+        &self.things[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::level_data::blockmap::BlockMap;
+    use crate::level_data::map_data::MapData;
+    use glam::Vec2;
+    use wad::WadData;
+
+    #[test]
+    fn e1m1_blocks_on_diagonal() {
+        let wad = WadData::new("../doom1.wad".into());
+        let mut map = MapData::new("E1M1".to_owned());
+        map.load(&wad);
+        let blockmap = BlockMap::new(map.get_map_extents(), map.get_linedefs());
+
+        let v1 = Vec2::new(1056.0, -3616.0);
+        let v2 = Vec2::new(1056.0 + 700.0, -3616.0 + 500.0);
+        let blocks = blockmap.blocks_on_line(v1, v2);
+
+        // The line starts and ends in the blocks its ends are in
+        assert_eq!(blocks.first().copied(), blockmap.block_index(v1));
+        assert_eq!(blocks.last().copied(), blockmap.block_index(v2));
+
+        // Each step is to a block sharing a side, right or up, so every
+        // column and row in between is visited once
+        let (x1, y1) = blockmap.block_coords(v1);
+        let (x2, y2) = blockmap.block_coords(v2);
+        assert_eq!(blocks.len() as i32, (x2 - x1) + (y2 - y1) + 1);
+        for pair in blocks.windows(2) {
+            let step = pair[1] as i32 - pair[0] as i32;
+            assert!(step == 1 || step == blockmap.columns);
+        }
+    }
+}
@@ -1,6 +1,11 @@
+use std::any::Any;
+use std::ptr::NonNull;
+
 use wad::{lumps::WadThing, WadData};
 
+use crate::level_data::blockmap::BlockMap;
 use crate::level_data::map_data::MapData;
+use crate::level_data::map_defs::LineDef;
 use crate::renderer::bsp::BspRenderer;
 use crate::renderer::plane::VisPlaneCtrl;
 use crate::renderer::RenderData;
@@ -14,9 +19,21 @@ use crate::{
     game::Game,
     p_map::SubSectorMinMax,
     p_map_object::MapObject,
+    p_spec::{
+        p_init_pic_anims, p_spawn_specials, p_update_specials, Anim, Button,
+        CeilingMove, FireFlicker, FloorMove, Glow, LightFlash, Platform,
+        Strobe, VerticalDoor,
+    },
+    p_switch::p_init_switch_list,
     player::Player,
+    DPtr,
 };
 
+/// Room in `Level::thinkers` for the map objects spawned during play: puffs,
+/// blood, teleport fog and dropped items. The list can't grow once things
+/// link to their thinkers, so this is reserved up front.
+const SPAWNED_THINKER_ROOM: usize = 512;
+
 /// The level is considered a `World` or sorts. One that exists only
 /// while the player is in it. Another benefit of this structure is
 /// it makes it easier for all involved thinkers and functions to
@@ -29,9 +46,41 @@ pub(crate) struct Level {
     pub r_data:            RenderData,
     pub visplanes:         VisPlaneCtrl,
     pub mobj_ctrl:         SubSectorMinMax,
+    /// The things in each block of the map, for collision checks
+    pub blockmap:          BlockMap,
     pub thinkers:          Vec<Option<Thinker<MapObject>>>,
     max_thinker_capacity:  usize,
+    /// Active door thinkers, boxed so the sector `specialdata` pointers to
+    /// them stay valid as the list grows
+    pub doors:             Vec<Box<Thinker<VerticalDoor>>>,
+    /// Platforms, including those in stasis, vanilla's `activeplats`
+    pub platforms:         Vec<Box<Thinker<Platform>>>,
+    /// Floor movers, also used for stairs and donuts
+    pub floors:            Vec<Box<Thinker<FloorMove>>>,
+    /// Ceilings and crushers, including those in stasis, vanilla's
+    /// `activeceilings`
+    pub ceilings:          Vec<Box<Thinker<CeilingMove>>>,
+    /// Sector lighting effects
+    pub fire_flickers:     Vec<Box<Thinker<FireFlicker>>>,
+    pub light_flashes:     Vec<Box<Thinker<LightFlash>>>,
+    pub strobes:           Vec<Box<Thinker<Strobe>>>,
+    pub glows:             Vec<Box<Thinker<Glow>>>,
+    /// The animated flat and texture sequences
+    pub anims:             Vec<Anim>,
+    /// The texture to draw in place of each texture number, vanilla's
+    /// `texturetranslation`. Updated each tic to animate `anims`
+    pub tex_translation:   Vec<i16>,
+    /// The flat to draw in place of each flat number
+    pub flat_translation:  Vec<i16>,
+    /// Switch textures in off/on pairs
+    pub switch_list:       Vec<i16>,
+    /// Pressed buttons waiting to pop back out
+    pub buttons:           Vec<Button>,
+    /// Lines with a special that animates every tic, the scrolling walls
+    pub line_special_list: Vec<DPtr<LineDef>>,
     pub game_skill:        Skill,
+    /// Needed for the Doom 2 items and the weapons a player can change to
+    pub game_mode:         GameMode,
     pub respawn_monsters:  bool,
     pub level_time:        u32,
     /// Required for the mobj controller (Boss check)
@@ -53,8 +102,52 @@ pub(crate) struct Level {
     pub totalitems:        i32,
     /// for intermission
     pub totalsecret:       i32,
+    /// Set when a line special exits the level, `Game` then runs
+    /// `G_ExitLevel` after the tic completes
+    pub exit_level:        bool,
+    /// Set along with `exit_level` if it was a secret exit
+    pub secret_exit:       bool,
 }
 impl Level {
+    /// Keep the heights of sectors and the positions of things at the start
+    /// of the tic, drawing interpolates from these
+    pub fn store_previous(&mut self) {
+        for sector in self.map_data.get_sectors_mut() {
+            sector.prev_floorheight = sector.floorheight;
+            sector.prev_ceilingheight = sector.ceilingheight;
+        }
+        for thinker in self.thinkers.iter_mut().flatten() {
+            thinker.obj.store_previous();
+        }
+    }
+
+    /// Set moving sectors to between their heights at the start of the tic
+    /// and now, for drawing. Returns the real heights to put back with
+    /// `restore_sectors`.
+    pub fn interpolate_sectors(&mut self, frac: f32) -> Vec<(f32, f32)> {
+        self.map_data
+            .get_sectors_mut()
+            .iter_mut()
+            .map(|sector| {
+                let real = (sector.floorheight, sector.ceilingheight);
+                sector.floorheight = sector.prev_floorheight
+                    + (real.0 - sector.prev_floorheight) * frac;
+                sector.ceilingheight = sector.prev_ceilingheight
+                    + (real.1 - sector.prev_ceilingheight) * frac;
+                real
+            })
+            .collect()
+    }
+
+    pub fn restore_sectors(&mut self, heights: Vec<(f32, f32)>) {
+        for (sector, real) in
+            self.map_data.get_sectors_mut().iter_mut().zip(heights)
+        {
+            sector.floorheight = real.0;
+            sector.ceilingheight = real.1;
+        }
+    }
+
     /// P_SetupLevel
     pub fn setup_level(
         wad_data: &WadData,
@@ -81,6 +174,15 @@ impl Level {
         map_data.load(wad_data);
 
         let thinker_count = map_data.get_things().len();
+        let blockmap =
+            BlockMap::new(map_data.get_map_extents(), map_data.get_linedefs());
+
+        // R_InitData, P_InitPicAnims, P_InitSwitchList
+        let tex_translation =
+            (0..map_data.get_textures().len() as i16).collect();
+        let flat_translation = (0..map_data.get_flats().len() as i16).collect();
+        let anims = p_init_pic_anims(wad_data, &map_data);
+        let switch_list = p_init_switch_list(game_mode, wad_data, &map_data);
 
         let mut level = Level {
             map_data,
@@ -88,9 +190,25 @@ impl Level {
             visplanes: VisPlaneCtrl::default(),
             bsp_renderer: BspRenderer::default(),
             mobj_ctrl: SubSectorMinMax::default(),
-            thinkers: Vec::with_capacity(thinker_count + 50),
-            max_thinker_capacity: thinker_count + 50,
+            blockmap,
+            thinkers: Vec::with_capacity(thinker_count + SPAWNED_THINKER_ROOM),
+            max_thinker_capacity: thinker_count + SPAWNED_THINKER_ROOM,
+            doors: Vec::new(),
+            platforms: Vec::new(),
+            floors: Vec::new(),
+            ceilings: Vec::new(),
+            fire_flickers: Vec::new(),
+            light_flashes: Vec::new(),
+            strobes: Vec::new(),
+            glows: Vec::new(),
+            anims,
+            tex_translation,
+            flat_translation,
+            switch_list,
+            buttons: Vec::new(),
+            line_special_list: Vec::new(),
             game_skill: skill,
+            game_mode,
             respawn_monsters,
             level_time: 0,
             episode,
@@ -100,10 +218,11 @@ impl Level {
             deathmatch_starts: [None; MAX_DEATHMATCH_STARTS],
             deathmatch_p: Vec::with_capacity(MAX_DEATHMATCH_STARTS),
             deathmatch: false,
-            // TODO: copy end values to game obj
             totalkills: 0,
             totalitems: 0,
             totalsecret: 0,
+            exit_level: false,
+            secret_exit: false,
         };
 
         let thing_list = (*level.map_data.get_things()).to_owned();
@@ -118,6 +237,9 @@ impl Level {
         }
         dbg!(&level.thinkers.len());
 
+        // set up world state
+        p_spawn_specials(&mut level);
+
         // G_DoReborn
         // G_CheckSpot
 
@@ -125,7 +247,12 @@ impl Level {
         // TODO: P_InitThinkers();
     }
 
-    pub fn add_thinker(&mut self, thinker: Thinker<MapObject>) -> bool {
+    /// Returns the object in its final location, or `None` if the thinker
+    /// list is full
+    pub fn add_thinker(
+        &mut self,
+        thinker: Thinker<MapObject>,
+    ) -> Option<NonNull<MapObject>> {
         let mut index = 0;
         for i in 0..self.thinkers.len() {
             if self.thinkers[i].is_none() {
@@ -139,10 +266,30 @@ impl Level {
             } else {
                 self.thinkers.push(Some(thinker));
             }
-            return true;
+            // The thinker is now at its final location so the object can
+            // link to it, and be linked in to the blockmap
+            let mut thinker =
+                NonNull::from(self.thinkers[index].as_mut().unwrap());
+            unsafe {
+                thinker.as_mut().obj.thinker = Some(thinker);
+                thinker.as_mut().obj.set_thing_position(self);
+                return Some(NonNull::from(&mut thinker.as_mut().obj));
+            }
         }
 
-        false
+        None
+    }
+
+    /// G_ExitLevel
+    pub fn do_exit_level(&mut self) {
+        self.secret_exit = false;
+        self.exit_level = true;
+    }
+
+    /// G_SecretExitLevel
+    pub fn do_secret_exit_level(&mut self) {
+        self.secret_exit = true;
+        self.exit_level = true;
     }
 
     /// Clean out the inactive thinkers. This iterates the full allocation to find
@@ -157,41 +304,92 @@ impl Level {
             if status {
                 // An item must always be replaced in place to prevent realloc of vec
                 let mut thinker = self.thinkers[i].take().unwrap();
+                thinker.obj.unset_thing_position(self);
                 thinker.unlink();
             }
         }
     }
 }
 
+/// P_RunThinkers for one of the sector mover lists. Each thinker is given the
+/// level while its list is taken out, any it spawns are appended after. The
+/// thinkers returning `true` are finished and removed.
+fn run_thinkers<T: Any + Think>(
+    level: &mut Level,
+    list: fn(&mut Level) -> &mut Vec<Box<Thinker<T>>>,
+) {
+    let mut thinkers = std::mem::take(list(level));
+    let mut i = 0;
+    while i < thinkers.len() {
+        if thinkers[i].think(level) {
+            thinkers.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    thinkers.append(list(level));
+    *list(level) = thinkers;
+}
+
+/// P_RunThinkers for the map objects. The list never reallocates, so each
+/// thinker can be reached through a pointer while it is given the level. Any
+/// spawned during the loop are pushed on the end and think this tic too.
+fn run_mobj_thinkers(level: &mut Level) {
+    let mut i = 0;
+    while i < level.thinkers.len() {
+        if let Some(thinker) = level.thinkers[i].as_mut() {
+            if matches!(thinker.function, ActionFunc::MapObject(_)) {
+                let mut thinker = NonNull::from(thinker);
+                unsafe {
+                    if thinker.as_mut().think(level) {
+                        thinker.as_mut().obj.p_remove_mobj(level);
+                    }
+                }
+            }
+        }
+        i += 1;
+    }
+}
+
 /// P_Ticker
 pub fn ticker(game: &mut Game) {
     if game.paused {
         return;
     }
-    // TODO: pause if in menu and at least one tic has been run
-    // if ( !netgame
-    //     && menuactive
-    //     && !demoplayback
-    // if game.players[game.consoleplayer].viewz as i32 != 1 {
-    //     return;
-    // }
+    // pause if in menu and at least one tic has been run
+    // TODO: && !demoplayback
+    if !game.netgame
+        && game.menu_active
+        && game.players[game.consoleplayer].viewz as i32 != 1
+    {
+        return;
+    }
 
     // Only run thinkers if a level is loaded
     if let Some(ref mut level) = game.level {
         for (i, player) in game.players.iter_mut().enumerate() {
             if game.player_in_game[i] && player.think(level) {
                 if let Some(ref mut mobj) = player.mobj {
+                    mobj.obj.unset_thing_position(level);
                     mobj.unlink();
                     mobj.function = ActionFunc::None;
                 }
             }
         }
 
-        // P_RunThinkers ();, this may need to remove thinkers..
-        // P_UpdateSpecials ();
+        // P_RunThinkers
+        run_mobj_thinkers(level);
+        run_thinkers(level, |l| &mut l.doors);
+        run_thinkers(level, |l| &mut l.platforms);
+        run_thinkers(level, |l| &mut l.floors);
+        run_thinkers(level, |l| &mut l.ceilings);
+        run_thinkers(level, |l| &mut l.fire_flickers);
+        run_thinkers(level, |l| &mut l.light_flashes);
+        run_thinkers(level, |l| &mut l.strobes);
+        run_thinkers(level, |l| &mut l.glows);
+        p_update_specials(level);
         // P_RespawnSpecials ();
 
-        // TODO: trial removal of mobs
         level.clean_thinker_list();
 
         level.level_time += 1;
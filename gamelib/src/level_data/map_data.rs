@@ -45,6 +45,10 @@ pub(crate) struct MapData {
     extents:    MapExtents,
     nodes:      Vec<Node>,
     start_node: u16,
+    /// All wall textures, the sidedef textures are an index in to this
+    textures:   Vec<WadTexture>,
+    /// All flat names, `floorpic` and `ceilingpic` are an index in to this
+    flats:      Vec<String>,
 }
 
 impl MapData {
@@ -61,6 +65,8 @@ impl MapData {
             extents: MapExtents::default(),
             nodes: Vec::new(),
             start_node: 0,
+            textures: Vec::new(),
+            flats: Vec::new(),
         }
     }
 
@@ -101,12 +107,21 @@ impl MapData {
     #[inline]
     pub fn get_linedefs(&self) -> &[LineDef] { &self.linedefs }
 
+    #[inline]
+    pub fn get_linedefs_mut(&mut self) -> &mut [LineDef] { &mut self.linedefs }
+
     #[inline]
     pub fn get_sectors(&self) -> &[Sector] { &self.sectors }
 
+    #[inline]
+    pub fn get_sectors_mut(&mut self) -> &mut [Sector] { &mut self.sectors }
+
     #[inline]
     pub fn get_sidedefs(&self) -> &[SideDef] { &self.sidedefs }
 
+    #[inline]
+    pub fn get_sidedefs_mut(&mut self) -> &mut [SideDef] { &mut self.sidedefs }
+
     #[inline]
     pub fn get_subsectors(&self) -> &[SubSector] { &self.subsectors }
 
@@ -133,6 +148,60 @@ impl MapData {
     #[inline]
     pub fn get_map_extents(&self) -> &MapExtents { &self.extents }
 
+    #[inline]
+    pub fn get_textures(&self) -> &[WadTexture] { &self.textures }
+
+    /// `textureheight`, 0 for a texture number that isn't loaded
+    pub fn texture_height(&self, texture: i16) -> f32 {
+        self.textures
+            .get(texture as usize)
+            .map_or(0.0, |t| t.height as f32)
+    }
+
+    #[inline]
+    pub fn get_flats(&self) -> &[String] { &self.flats }
+
+    /// R_CheckTextureNumForName
+    ///
+    /// "-" is the no texture marker and is always 0
+    pub fn check_texture_num_for_name(&self, name: &str) -> Option<i16> {
+        if name.starts_with('-') {
+            return Some(0);
+        }
+        self.textures
+            .iter()
+            .position(|t| t.name.eq_ignore_ascii_case(name))
+            .map(|num| num as i16)
+    }
+
+    /// R_TextureNumForName
+    ///
+    /// Vanilla errors on a missing texture, here it is drawn as no texture
+    pub fn texture_num_for_name(&self, name: &str) -> i16 {
+        self.check_texture_num_for_name(name).unwrap_or_else(|| {
+            println!("R_TextureNumForName: {} not found", name);
+            0
+        })
+    }
+
+    /// Check for a flat without erroring, `W_CheckNumForName` in vanilla
+    pub fn check_flat_num_for_name(&self, name: &str) -> Option<i16> {
+        self.flats
+            .iter()
+            .position(|f| f.eq_ignore_ascii_case(name))
+            .map(|num| num as i16)
+    }
+
+    /// R_FlatNumForName
+    ///
+    /// Vanilla errors on a missing flat, here the first flat is used
+    pub fn flat_num_for_name(&self, name: &str) -> i16 {
+        self.check_flat_num_for_name(name).unwrap_or_else(|| {
+            println!("R_FlatNumForName: {} not found", name);
+            0
+        })
+    }
+
     pub fn load(&mut self, wad: &WadData) {
         // THINGS
         self.things = wad.thing_iter(&self.name).collect();
@@ -143,21 +212,28 @@ impl MapData {
             .map(|v| Vec2::new(v.x as f32, v.y as f32))
             .collect();
 
+        // R_InitTextures, R_InitFlats
+        self.textures = wad.get_textures();
+        self.flats = wad.flat_names();
+
         // Sectors
         self.sectors = wad
             .sector_iter(&self.name)
             .map(|s| Sector {
-                floorheight:    s.floor_height as f32,
-                ceilingheight:  s.ceil_height as f32,
-                floorpic:       0, // TODO: lookup texture
-                ceilingpic:     0, // TODO: lookup texture
-                lightlevel:     s.light_level,
-                special:        s.kind,
-                tag:            s.tag,
-                soundtraversed: 0,
-                blockbox:       [0, 0, 0, 0],
-                validcount:     0,
-                lines:          Vec::new(),
+                floorheight:        s.floor_height as f32,
+                ceilingheight:      s.ceil_height as f32,
+                prev_floorheight:   s.floor_height as f32,
+                prev_ceilingheight: s.ceil_height as f32,
+                floorpic:           self.flat_num_for_name(&s.floor_tex),
+                ceilingpic:         self.flat_num_for_name(&s.ceil_tex),
+                lightlevel:         s.light_level,
+                special:            s.kind,
+                tag:                s.tag,
+                soundtraversed:     0,
+                blockbox:           [0, 0, 0, 0],
+                validcount:         0,
+                specialdata:        None,
+                lines:              Vec::new(),
             })
             .collect();
 
@@ -168,11 +244,11 @@ impl MapData {
                 let sector = &self.get_sectors()[s.sector as usize];
 
                 SideDef {
-                    textureoffset: s.y_offset as f32,
-                    rowoffset:     s.x_offset as f32,
-                    toptexture:    if s.upper_tex.is_empty() { 0 } else { 1 },
-                    bottomtexture: if s.lower_tex.is_empty() { 0 } else { 1 },
-                    midtexture:    if s.middle_tex.is_empty() { 0 } else { 1 },
+                    textureoffset: s.x_offset as f32,
+                    rowoffset:     s.y_offset as f32,
+                    toptexture:    self.texture_num_for_name(&s.upper_tex),
+                    bottomtexture: self.texture_num_for_name(&s.lower_tex),
+                    midtexture:    self.texture_num_for_name(&s.middle_tex),
                     sector:        DPtr::new(sector),
                 }
             })
@@ -234,11 +310,16 @@ impl MapData {
             })
             .collect();
 
-        // Now map sectors to lines
-        // This is going to be required for collision checks
+        // Now map sectors to lines, both sides as in P_GroupLines
+        // This is going to be required for collision checks and movers
         for line in self.linedefs.iter_mut() {
             let mut sector = line.frontsector.clone();
             sector.lines.push(DPtr::new(line));
+            if let Some(mut back) = line.backsector.clone() {
+                if back.as_ptr() != sector.as_ptr() {
+                    back.lines.push(DPtr::new(line));
+                }
+            }
         }
 
         // Sector, Sidedef, Linedef, Seg all need to be preprocessed before
@@ -1,4 +1,5 @@
 use crate::angle::Angle;
+use crate::p_spec::SpecialData;
 use crate::DPtr;
 use glam::Vec2;
 use std::f32::EPSILON;
@@ -15,15 +16,19 @@ pub(crate) enum SlopeType {
 /// Stores things/mobjs.
 #[derive(Debug)]
 pub(crate) struct Sector {
-    pub floorheight:   f32,
-    pub ceilingheight: f32,
+    pub floorheight:        f32,
+    pub ceilingheight:      f32,
+    /// Heights at the start of the tic, drawing interpolates from these
+    /// to the current
+    pub prev_floorheight:   f32,
+    pub prev_ceilingheight: f32,
     /// Is a tag or index to patch
-    pub floorpic:      i16,
+    pub floorpic:           i16,
     /// Is a tag or index to patch
-    pub ceilingpic:    i16,
-    pub lightlevel:    i16,
-    pub special:       i16,
-    pub tag:           i16,
+    pub ceilingpic:         i16,
+    pub lightlevel:         i16,
+    pub special:            i16,
+    pub tag:                i16,
 
     /// 0 = untraversed, 1,2 = sndlines -1
     pub soundtraversed: i32,
@@ -42,10 +47,9 @@ pub(crate) struct Sector {
 
     // list of mobjs in sector
     // TODO: mobj_t*	thinglist;
-
-    // thinker_t for reversable actions
-    // TODO: void*	specialdata;
-    pub lines: Vec<DPtr<LineDef>>,
+    /// thinker_t for reversable actions
+    pub specialdata: Option<SpecialData>,
+    pub lines:       Vec<DPtr<LineDef>>,
 }
 
 #[derive(Debug)]
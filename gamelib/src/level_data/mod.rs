@@ -1,3 +1,4 @@
+pub(crate) mod blockmap;
 pub(crate) mod level;
 pub(crate) mod map_data;
 pub(crate) mod map_defs;
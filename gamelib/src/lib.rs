@@ -9,29 +9,49 @@ use std::ops::{Deref, DerefMut};
 use std::ptr::NonNull;
 
 pub(crate) mod angle;
+pub(crate) mod d_items;
 pub mod d_main;
 pub(crate) mod d_thinker;
 pub(crate) mod doom_def;
 pub(crate) mod errors;
+pub(crate) mod f_finale;
+pub(crate) mod f_wipe;
 pub(crate) mod flags;
 pub mod game;
+pub(crate) mod hu_stats;
 pub(crate) mod info;
 pub mod input;
 pub(crate) mod level_data;
+pub(crate) mod m_menu;
+pub(crate) mod m_misc;
+pub(crate) mod p_ceiling;
+pub(crate) mod p_doors;
 pub(crate) mod p_enemy;
+pub(crate) mod p_floor;
+pub(crate) mod p_inter;
 pub(crate) mod p_lights;
 pub(crate) mod p_local;
 pub(crate) mod p_map;
 pub(crate) mod p_map_object;
 pub(crate) mod p_map_util;
+pub(crate) mod p_plats;
 pub(crate) mod p_player_sprite;
+pub(crate) mod p_saveg;
 pub(crate) mod p_spec;
+pub(crate) mod p_switch;
+pub(crate) mod p_telept;
 pub(crate) mod player;
 pub(crate) mod renderer;
 pub(crate) mod shaders;
 pub(crate) mod sounds;
 pub(crate) mod tic_cmd;
 pub(crate) mod timestep;
+pub(crate) mod v_video;
+pub(crate) mod wi_stuff;
+
+/// For the draw list benches
+pub use renderer::draw_list::{DrawList, Rasteriser};
+pub use renderer::stats::RenderStats;
 
 /// R_PointToDist
 fn point_to_dist(x: f32, y: f32, to: Vec2) -> f32 {
@@ -0,0 +1,900 @@
+//! The front-end menus: new game, options, load/save and quit. Everything is
+//! drawn from the WAD menu patches and the HUD font, and is driven by key
+//! presses passed in from `Input`.
+//!
+//! Vanilla builds the menus as arrays of items with function pointers for the
+//! actions. Here the menus are the same data but actions are matched on the
+//! menu and item index.
+
+use std::collections::HashMap;
+
+use sdl2::{keyboard::Scancode as Sc, render::Canvas, surface::Surface};
+use wad::lumps::{WadPalette, WadPatch};
+
+use crate::{
+    d_main::Skill,
+    doom_def::{GameMode, GameState},
+    game::Game,
+    input::Input,
+    p_saveg::{read_description, save_game_file, SAVESTRINGSIZE},
+    v_video::{draw_patch, NUM_GAMMA},
+};
+
+const SKULLXOFF: i32 = -32;
+const LINEHEIGHT: i32 = 16;
+const SKULLS: [&str; 2] = ["M_SKULL1", "M_SKULL2"];
+const LOAD_SLOTS: usize = 6;
+const EMPTYSTRING: &str = "empty slot";
+
+/// The first and last characters in the HUD font, `STCFN033` to `STCFN095`
+const HU_FONTSTART: u8 = b'!';
+const HU_FONTEND: u8 = b'_';
+
+const QUITMSG: &str =
+    "are you sure you want to\nquit this great game?\n\n(press y to quit.)";
+const NIGHTMARE: &str =
+    "are you sure? this skill level\nisn't even remotely fair.\n\npress y or n.";
+const SWSTRING: &str = "this is the shareware version of doom.\n\nyou need to order the entire trilogy.\n\npress a key.";
+const SAVEDEAD: &str = "you can't save if you aren't playing!\n\npress a key.";
+const ENDGAME: &str = "are you sure you want to end the game?\n\npress y or n.";
+const GAMMALVL: [&str; NUM_GAMMA] = [
+    "Gamma correction OFF",
+    "Gamma correction level 1",
+    "Gamma correction level 2",
+    "Gamma correction level 3",
+    "Gamma correction level 4",
+];
+
+/// Extra patches that the menus draw which are not items
+const EXTRA_PATCHES: [&str; 18] = [
+    "M_DOOM", "M_SKULL1", "M_SKULL2", "M_EPISOD", "M_NEWG", "M_SKILL",
+    "M_OPTTTL", "M_GDHIGH", "M_GDLOW", "M_THERML", "M_THERMM", "M_THERMR",
+    "M_THERMO", "M_LOADG", "M_SAVEG", "M_LSLEFT", "M_LSCNTR", "M_LSRGHT",
+];
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Status {
+    /// A spacer, skipped over when moving up and down
+    Disabled,
+    /// An empty load slot, it can be moved to but not chosen
+    Unselectable,
+    Selectable,
+    /// Left and right change the value
+    Slider,
+}
+
+#[derive(Debug, Clone)]
+struct MenuItem {
+    status:    Status,
+    /// The patch to draw, empty if the menu draws the item itself
+    patch:     &'static str,
+    /// Hotkey in menu
+    alpha_key: char,
+}
+
+impl MenuItem {
+    const fn new(status: Status, patch: &'static str, alpha_key: char) -> Self {
+        Self {
+            status,
+            patch,
+            alpha_key,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum MenuKind {
+    Main,
+    Episode,
+    NewGame,
+    Options,
+    Load,
+    Save,
+}
+
+#[derive(Debug, Clone)]
+struct MenuDef {
+    items:   Vec<MenuItem>,
+    /// The menu to go back to
+    prev:    Option<MenuKind>,
+    x:       i32,
+    y:       i32,
+    /// Last item the user was on in this menu
+    last_on: usize,
+}
+
+/// What to do with the response to a message
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum MessageAction {
+    None,
+    Quit,
+    Nightmare,
+    EndGame,
+}
+
+#[derive(Debug, Clone)]
+struct Message {
+    text:             &'static str,
+    /// Needs a Y/N response
+    needs_input:      bool,
+    action:           MessageAction,
+    /// Was the menu open when the message was started
+    last_menu_active: bool,
+}
+
+// Items in the main menu
+const NEW_GAME: usize = 0;
+const OPTIONS: usize = 1;
+const LOAD_GAME: usize = 2;
+const SAVE_GAME: usize = 3;
+const QUIT_DOOM: usize = 4;
+
+// Items in the options menu
+const END_GAME: usize = 0;
+const DETAIL: usize = 1;
+const SCREEN_SIZE: usize = 2;
+const MOUSE_SENS: usize = 4;
+
+pub(crate) struct Menu {
+    /// menuactive
+    pub active:         bool,
+    menus:              Vec<MenuDef>,
+    current:            MenuKind,
+    /// The item the skull is pointing at
+    item_on:            usize,
+    skull_anim_counter: i32,
+    which_skull:        usize,
+    message:            Option<Message>,
+    /// Episode chosen in the episode menu, to be used by the skill menu
+    episode:            u32,
+    save_strings:       [String; LOAD_SLOTS],
+    /// Typing in a save description
+    save_string_enter:  bool,
+    save_slot:          usize,
+    save_old_string:    String,
+    /// 0-9, the mouse scale is derived from this
+    mouse_sensitivity:  i32,
+    /// All the patches used by the menus, cached at startup
+    patches:            HashMap<&'static str, WadPatch>,
+    hu_font:            Vec<WadPatch>,
+    palette:            WadPalette,
+}
+
+impl Menu {
+    /// M_Init
+    pub(crate) fn new(game: &Game) -> Self {
+        let wad = game.wad_data();
+
+        let main = MenuDef {
+            items:   vec![
+                MenuItem::new(Status::Selectable, "M_NGAME", 'n'),
+                MenuItem::new(Status::Selectable, "M_OPTION", 'o'),
+                MenuItem::new(Status::Selectable, "M_LOADG", 'l'),
+                MenuItem::new(Status::Selectable, "M_SAVEG", 's'),
+                MenuItem::new(Status::Selectable, "M_QUITG", 'q'),
+            ],
+            prev:    None,
+            x:       97,
+            // Vanilla has "Read This!" in this menu, it is dropped so the
+            // menu moves down as in Doom 2
+            y:       72,
+            last_on: 0,
+        };
+
+        let mut episode_items = vec![
+            MenuItem::new(Status::Selectable, "M_EPI1", 'k'),
+            MenuItem::new(Status::Selectable, "M_EPI2", 't'),
+            MenuItem::new(Status::Selectable, "M_EPI3", 'i'),
+        ];
+        if game.game_mode() == GameMode::Retail {
+            episode_items.push(MenuItem::new(
+                Status::Selectable,
+                "M_EPI4",
+                't',
+            ));
+        }
+        let episode = MenuDef {
+            items:   episode_items,
+            prev:    Some(MenuKind::Main),
+            x:       48,
+            y:       63,
+            last_on: 0,
+        };
+
+        let new_game = MenuDef {
+            items:   vec![
+                MenuItem::new(Status::Selectable, "M_JKILL", 'i'),
+                MenuItem::new(Status::Selectable, "M_ROUGH", 'h'),
+                MenuItem::new(Status::Selectable, "M_HURT", 'h'),
+                MenuItem::new(Status::Selectable, "M_ULTRA", 'u'),
+                MenuItem::new(Status::Selectable, "M_NMARE", 'n'),
+            ],
+            prev:    if game.game_mode() == GameMode::Commercial {
+                Some(MenuKind::Main)
+            } else {
+                Some(MenuKind::Episode)
+            },
+            x:       48,
+            y:       63,
+            last_on: Skill::Medium as usize,
+        };
+
+        let options = MenuDef {
+            items:   vec![
+                MenuItem::new(Status::Selectable, "M_ENDGAM", 'e'),
+                MenuItem::new(Status::Selectable, "M_DETAIL", 'g'),
+                MenuItem::new(Status::Slider, "M_SCRNSZ", 's'),
+                MenuItem::new(Status::Disabled, "", '\0'),
+                MenuItem::new(Status::Slider, "M_MSENS", 'm'),
+                MenuItem::new(Status::Disabled, "", '\0'),
+            ],
+            prev:    Some(MenuKind::Main),
+            x:       60,
+            y:       37,
+            last_on: 0,
+        };
+
+        let slots: Vec<MenuItem> = (0..LOAD_SLOTS)
+            .map(|i| {
+                MenuItem::new(Status::Selectable, "", (b'1' + i as u8) as char)
+            })
+            .collect();
+        let load = MenuDef {
+            items:   slots.clone(),
+            prev:    Some(MenuKind::Main),
+            x:       80,
+            y:       54,
+            last_on: 0,
+        };
+        let save = MenuDef {
+            items:   slots,
+            prev:    Some(MenuKind::Main),
+            x:       80,
+            y:       54,
+            last_on: 0,
+        };
+
+        let menus = vec![main, episode, new_game, options, load, save];
+
+        let mut patches = HashMap::new();
+        let names = menus
+            .iter()
+            .flat_map(|m| m.items.iter().map(|i| i.patch))
+            .chain(EXTRA_PATCHES.iter().copied());
+        for name in names {
+            if !name.is_empty() && wad.lump_exists(name) {
+                patches.insert(name, wad.get_patch(name));
+            }
+        }
+
+        let hu_font = (HU_FONTSTART..=HU_FONTEND)
+            .map(|c| wad.get_patch(&format!("STCFN{:03}", c)))
+            .collect();
+
+        Self {
+            active: false,
+            menus,
+            current: MenuKind::Main,
+            item_on: 0,
+            skull_anim_counter: 10,
+            which_skull: 0,
+            message: None,
+            episode: 1,
+            save_strings: Default::default(),
+            save_string_enter: false,
+            save_slot: 0,
+            save_old_string: String::new(),
+            mouse_sensitivity: 5,
+            patches,
+            hu_font,
+            palette: game.palettes[0].clone(),
+        }
+    }
+
+    fn menu(&self, kind: MenuKind) -> &MenuDef { &self.menus[kind as usize] }
+
+    fn menu_mut(&mut self, kind: MenuKind) -> &mut MenuDef {
+        &mut self.menus[kind as usize]
+    }
+
+    /// M_SetupNextMenu
+    fn setup_next_menu(&mut self, kind: MenuKind) {
+        self.current = kind;
+        self.item_on = self.menu(kind).last_on;
+    }
+
+    /// M_StartControlPanel
+    fn start_control_panel(&mut self, game: &mut Game) {
+        if self.active {
+            return;
+        }
+        self.active = true;
+        game.menu_active = true;
+        self.setup_next_menu(MenuKind::Main);
+    }
+
+    /// M_ClearMenus
+    fn clear_menus(&mut self, game: &mut Game) {
+        self.active = false;
+        game.menu_active = false;
+    }
+
+    /// M_StartMessage
+    fn start_message(
+        &mut self,
+        text: &'static str,
+        action: MessageAction,
+        needs_input: bool,
+        game: &mut Game,
+    ) {
+        self.message = Some(Message {
+            text,
+            needs_input,
+            action,
+            last_menu_active: self.active,
+        });
+        self.active = true;
+        game.menu_active = true;
+    }
+
+    /// M_ReadSaveStrings
+    fn read_save_strings(&mut self) {
+        for i in 0..LOAD_SLOTS {
+            let status = match read_description(&save_game_file(i)) {
+                Some(desc) => {
+                    self.save_strings[i] = desc;
+                    Status::Selectable
+                }
+                None => {
+                    self.save_strings[i] = EMPTYSTRING.to_owned();
+                    Status::Unselectable
+                }
+            };
+            self.menu_mut(MenuKind::Load).items[i].status = status;
+        }
+    }
+
+    fn start_load_menu(&mut self) {
+        self.setup_next_menu(MenuKind::Load);
+        self.read_save_strings();
+    }
+
+    fn start_save_menu(&mut self, game: &mut Game) {
+        if !game.usergame() || game.game_state() != GameState::GS_LEVEL {
+            self.start_message(SAVEDEAD, MessageAction::None, false, game);
+            return;
+        }
+        self.setup_next_menu(MenuKind::Save);
+        self.read_save_strings();
+    }
+
+    /// M_Ticker
+    pub(crate) fn ticker(&mut self) {
+        self.skull_anim_counter -= 1;
+        if self.skull_anim_counter <= 0 {
+            self.which_skull ^= 1;
+            self.skull_anim_counter = 8;
+        }
+    }
+
+    /// M_Responder
+    ///
+    /// Returns true if the key was eaten by the menu
+    pub(crate) fn responder(
+        &mut self,
+        key: Sc,
+        game: &mut Game,
+        input: &mut Input,
+    ) -> bool {
+        // Save game string input
+        if self.save_string_enter {
+            match key {
+                Sc::Backspace => {
+                    self.save_strings[self.save_slot].pop();
+                }
+                Sc::Escape => {
+                    self.save_string_enter = false;
+                    self.save_strings[self.save_slot] =
+                        self.save_old_string.clone();
+                }
+                Sc::Return | Sc::KpEnter => {
+                    self.save_string_enter = false;
+                    if !self.save_strings[self.save_slot].is_empty() {
+                        game.save_game(
+                            self.save_slot,
+                            self.save_strings[self.save_slot].clone(),
+                        );
+                        self.clear_menus(game);
+                    }
+                }
+                _ => {
+                    if let Some(c) = key_to_char(key) {
+                        let s = &self.save_strings[self.save_slot];
+                        if s.len() < SAVESTRINGSIZE - 1
+                            && self.string_width(s)
+                                < (SAVESTRINGSIZE as i32 - 2) * 8
+                        {
+                            self.save_strings[self.save_slot].push(c);
+                        }
+                    }
+                }
+            }
+            return true;
+        }
+
+        // Take care of any messages that need input
+        if let Some(message) = self.message.take() {
+            if message.needs_input
+                && !matches!(key, Sc::Space | Sc::N | Sc::Y | Sc::Escape)
+            {
+                self.message = Some(message);
+                return false;
+            }
+
+            self.active = message.last_menu_active;
+            game.menu_active = self.active;
+            if key == Sc::Y {
+                match message.action {
+                    MessageAction::Quit => game.set_running(false),
+                    MessageAction::Nightmare => {
+                        game.defered_init_new(
+                            Skill::Nightmare,
+                            self.episode,
+                            1,
+                        );
+                        self.clear_menus(game);
+                    }
+                    MessageAction::EndGame => {
+                        self.menu_mut(self.current).last_on = self.item_on;
+                        self.clear_menus(game);
+                        game.start_title();
+                    }
+                    MessageAction::None => {}
+                }
+            }
+            return true;
+        }
+
+        // Pop-up menu?
+        if !self.active {
+            match key {
+                Sc::F1 if game.game_options.dev_parm => game.screen_shot(),
+                Sc::Escape => self.start_control_panel(game),
+                Sc::F2 => {
+                    self.start_control_panel(game);
+                    self.start_save_menu(game);
+                }
+                Sc::F3 => {
+                    self.start_control_panel(game);
+                    self.start_load_menu();
+                }
+                Sc::F10 => {
+                    self.start_message(QUITMSG, MessageAction::Quit, true, game)
+                }
+                Sc::F11 => {
+                    game.usegamma = (game.usegamma + 1) % NUM_GAMMA;
+                    game.players[game.consoleplayer].message =
+                        Some(GAMMALVL[game.usegamma].to_owned());
+                }
+                Sc::Minus if game.game_state() == GameState::GS_LEVEL => {
+                    self.change_screen_size(game, false)
+                }
+                Sc::Equals if game.game_state() == GameState::GS_LEVEL => {
+                    self.change_screen_size(game, true)
+                }
+                _ => return false,
+            }
+            return true;
+        }
+
+        // Keys usable within menu
+        let count = self.menu(self.current).items.len();
+        match key {
+            Sc::Down => {
+                for _ in 0..count {
+                    self.item_on = (self.item_on + 1) % count;
+                    if self.menu(self.current).items[self.item_on].status
+                        != Status::Disabled
+                    {
+                        break;
+                    }
+                }
+            }
+            Sc::Up => {
+                for _ in 0..count {
+                    self.item_on = (self.item_on + count - 1) % count;
+                    if self.menu(self.current).items[self.item_on].status
+                        != Status::Disabled
+                    {
+                        break;
+                    }
+                }
+            }
+            Sc::Left | Sc::Right => {
+                if self.menu(self.current).items[self.item_on].status
+                    == Status::Slider
+                {
+                    self.change_slider(key == Sc::Right, game, input);
+                }
+            }
+            Sc::Return | Sc::KpEnter => {
+                let status = self.menu(self.current).items[self.item_on].status;
+                if status != Status::Disabled && status != Status::Unselectable
+                {
+                    self.menu_mut(self.current).last_on = self.item_on;
+                    if self.menu(self.current).items[self.item_on].status
+                        == Status::Slider
+                    {
+                        self.change_slider(true, game, input);
+                    } else {
+                        self.choose(game);
+                    }
+                }
+            }
+            Sc::Escape => {
+                self.menu_mut(self.current).last_on = self.item_on;
+                self.clear_menus(game);
+            }
+            Sc::Backspace => {
+                self.menu_mut(self.current).last_on = self.item_on;
+                if let Some(prev) = self.menu(self.current).prev {
+                    self.setup_next_menu(prev);
+                }
+            }
+            _ => {
+                // Jump to the next item with this hotkey
+                if let Some(c) = key_to_char(key) {
+                    let c = c.to_ascii_lowercase();
+                    for i in 1..=count {
+                        let idx = (self.item_on + i) % count;
+                        if self.menu(self.current).items[idx].alpha_key == c {
+                            self.item_on = idx;
+                            return true;
+                        }
+                    }
+                }
+                return false;
+            }
+        }
+        true
+    }
+
+    /// The routines of each menu item
+    fn choose(&mut self, game: &mut Game) {
+        let choice = self.item_on;
+        match self.current {
+            MenuKind::Main => match choice {
+                // M_NewGame
+                NEW_GAME => {
+                    if game.game_mode() == GameMode::Commercial {
+                        self.setup_next_menu(MenuKind::NewGame);
+                    } else {
+                        self.setup_next_menu(MenuKind::Episode);
+                    }
+                }
+                OPTIONS => self.setup_next_menu(MenuKind::Options),
+                LOAD_GAME => self.start_load_menu(),
+                SAVE_GAME => self.start_save_menu(game),
+                QUIT_DOOM => {
+                    self.start_message(QUITMSG, MessageAction::Quit, true, game)
+                }
+                _ => {}
+            },
+            // M_Episode
+            MenuKind::Episode => {
+                if game.game_mode() == GameMode::Shareware && choice > 0 {
+                    self.start_message(
+                        SWSTRING,
+                        MessageAction::None,
+                        false,
+                        game,
+                    );
+                    self.setup_next_menu(MenuKind::Main);
+                    return;
+                }
+                self.episode = choice as u32 + 1;
+                self.setup_next_menu(MenuKind::NewGame);
+            }
+            // M_ChooseSkill
+            MenuKind::NewGame => {
+                let skill = match choice {
+                    0 => Skill::Baby,
+                    1 => Skill::Easy,
+                    2 => Skill::Medium,
+                    3 => Skill::Hard,
+                    _ => {
+                        self.start_message(
+                            NIGHTMARE,
+                            MessageAction::Nightmare,
+                            true,
+                            game,
+                        );
+                        return;
+                    }
+                };
+                let episode = if game.game_mode() == GameMode::Commercial {
+                    1
+                } else {
+                    self.episode
+                };
+                game.defered_init_new(skill, episode, 1);
+                self.clear_menus(game);
+            }
+            MenuKind::Options => match choice {
+                // M_EndGame
+                END_GAME => {
+                    if game.usergame() {
+                        self.start_message(
+                            ENDGAME,
+                            MessageAction::EndGame,
+                            true,
+                            game,
+                        );
+                    }
+                }
+                // M_ChangeDetail
+                DETAIL => game.detail_low = !game.detail_low,
+                _ => {}
+            },
+            // M_LoadSelect
+            MenuKind::Load => {
+                game.load_game(choice);
+                self.clear_menus(game);
+            }
+            // M_SaveSelect
+            MenuKind::Save => {
+                self.save_string_enter = true;
+                self.save_slot = choice;
+                self.save_old_string = self.save_strings[choice].clone();
+                if self.save_strings[choice] == EMPTYSTRING {
+                    self.save_strings[choice].clear();
+                }
+            }
+        }
+    }
+
+    fn change_slider(
+        &mut self,
+        right: bool,
+        game: &mut Game,
+        input: &mut Input,
+    ) {
+        if self.current != MenuKind::Options {
+            return;
+        }
+        match self.item_on {
+            SCREEN_SIZE => self.change_screen_size(game, right),
+            // M_ChangeSensitivity
+            MOUSE_SENS => {
+                if right && self.mouse_sensitivity < 9 {
+                    self.mouse_sensitivity += 1;
+                } else if !right && self.mouse_sensitivity > 0 {
+                    self.mouse_sensitivity -= 1;
+                }
+                input
+                    .tic_events
+                    .set_mouse_scale((self.mouse_sensitivity + 5, 0));
+            }
+            _ => {}
+        }
+    }
+
+    /// M_SizeDisplay
+    fn change_screen_size(&mut self, game: &mut Game, bigger: bool) {
+        if bigger && game.screen_blocks < 11 {
+            game.screen_blocks += 1;
+        } else if !bigger && game.screen_blocks > 3 {
+            game.screen_blocks -= 1;
+        }
+    }
+
+    /// M_Drawer
+    ///
+    /// Called after the view has been rendered, the menu is drawn on top
+    pub(crate) fn drawer(&self, game: &Game, canvas: &mut Canvas<Surface>) {
+        // Horiz. & Vertically center string and print it.
+        if let Some(ref message) = self.message {
+            let mut y = 100 - self.string_height(message.text) / 2;
+            for line in message.text.split('\n') {
+                let x = 160 - self.string_width(line) / 2;
+                self.write_text(x, y, line, canvas);
+                y += self.hu_font[0].height as i32;
+            }
+            return;
+        }
+
+        if !self.active {
+            return;
+        }
+
+        let def = self.menu(self.current);
+        match self.current {
+            MenuKind::Main => self.draw_patch("M_DOOM", 94, 2, canvas),
+            MenuKind::Episode => self.draw_patch("M_EPISOD", 54, 38, canvas),
+            MenuKind::NewGame => {
+                self.draw_patch("M_NEWG", 96, 14, canvas);
+                self.draw_patch("M_SKILL", 54, 38, canvas);
+            }
+            MenuKind::Options => {
+                self.draw_patch("M_OPTTTL", 108, 15, canvas);
+                let detail = if game.detail_low {
+                    "M_GDLOW"
+                } else {
+                    "M_GDHIGH"
+                };
+                self.draw_patch(
+                    detail,
+                    def.x + 175,
+                    def.y + LINEHEIGHT * DETAIL as i32,
+                    canvas,
+                );
+                self.draw_thermo(
+                    def.x,
+                    def.y + LINEHEIGHT * (SCREEN_SIZE as i32 + 1),
+                    9,
+                    game.screen_blocks - 3,
+                    canvas,
+                );
+                self.draw_thermo(
+                    def.x,
+                    def.y + LINEHEIGHT * (MOUSE_SENS as i32 + 1),
+                    10,
+                    self.mouse_sensitivity,
+                    canvas,
+                );
+            }
+            MenuKind::Load | MenuKind::Save => {
+                if self.current == MenuKind::Load {
+                    self.draw_patch("M_LOADG", 72, 28, canvas);
+                } else {
+                    self.draw_patch("M_SAVEG", 72, 28, canvas);
+                }
+                for (i, desc) in self.save_strings.iter().enumerate() {
+                    let y = def.y + LINEHEIGHT * i as i32;
+                    self.draw_save_load_border(def.x, y, canvas);
+                    self.write_text(def.x, y, desc, canvas);
+                }
+                if self.save_string_enter {
+                    let i =
+                        self.string_width(&self.save_strings[self.save_slot]);
+                    self.write_text(
+                        def.x + i,
+                        def.y + LINEHEIGHT * self.save_slot as i32,
+                        "_",
+                        canvas,
+                    );
+                }
+            }
+        }
+
+        // DRAW MENU
+        let mut y = def.y;
+        for item in def.items.iter() {
+            if !item.patch.is_empty() {
+                self.draw_patch(item.patch, def.x, y, canvas);
+            }
+            y += LINEHEIGHT;
+        }
+
+        // DRAW SKULL
+        self.draw_patch(
+            SKULLS[self.which_skull],
+            def.x + SKULLXOFF,
+            def.y - 5 + self.item_on as i32 * LINEHEIGHT,
+            canvas,
+        );
+    }
+
+    fn draw_patch(
+        &self,
+        name: &str,
+        x: i32,
+        y: i32,
+        canvas: &mut Canvas<Surface>,
+    ) {
+        if let Some(patch) = self.patches.get(name) {
+            draw_patch(patch, x, y, &self.palette, canvas);
+        }
+    }
+
+    /// M_DrawThermo
+    fn draw_thermo(
+        &self,
+        x: i32,
+        y: i32,
+        width: i32,
+        dot: i32,
+        canvas: &mut Canvas<Surface>,
+    ) {
+        let mut xx = x;
+        self.draw_patch("M_THERML", xx, y, canvas);
+        xx += 8;
+        for _ in 0..width {
+            self.draw_patch("M_THERMM", xx, y, canvas);
+            xx += 8;
+        }
+        self.draw_patch("M_THERMR", xx, y, canvas);
+        self.draw_patch("M_THERMO", x + 8 + dot * 8, y, canvas);
+    }
+
+    /// M_DrawSaveLoadBorder
+    fn draw_save_load_border(
+        &self,
+        mut x: i32,
+        y: i32,
+        canvas: &mut Canvas<Surface>,
+    ) {
+        self.draw_patch("M_LSLEFT", x - 8, y + 7, canvas);
+        for _ in 0..24 {
+            self.draw_patch("M_LSCNTR", x, y + 7, canvas);
+            x += 8;
+        }
+        self.draw_patch("M_LSRGHT", x, y + 7, canvas);
+    }
+
+    fn font_patch(&self, c: char) -> Option<&WadPatch> {
+        let c = c.to_ascii_uppercase() as u8;
+        if c < HU_FONTSTART || c > HU_FONTEND {
+            return None;
+        }
+        self.hu_font.get((c - HU_FONTSTART) as usize)
+    }
+
+    /// M_StringWidth
+    fn string_width(&self, string: &str) -> i32 {
+        string
+            .chars()
+            .map(|c| self.font_patch(c).map_or(4, |p| p.width as i32))
+            .sum()
+    }
+
+    /// M_StringHeight
+    fn string_height(&self, string: &str) -> i32 {
+        let height = self.hu_font[0].height as i32;
+        height + string.matches('\n').count() as i32 * height
+    }
+
+    /// M_WriteText
+    fn write_text(
+        &self,
+        x: i32,
+        y: i32,
+        string: &str,
+        canvas: &mut Canvas<Surface>,
+    ) {
+        let mut cx = x;
+        let mut cy = y;
+        for c in string.chars() {
+            if c == '\n' {
+                cx = x;
+                cy += 12;
+                continue;
+            }
+            match self.font_patch(c) {
+                Some(patch) => {
+                    if cx + patch.width as i32 > 320 {
+                        break;
+                    }
+                    draw_patch(patch, cx, cy, &self.palette, canvas);
+                    cx += patch.width as i32;
+                }
+                None => cx += 4,
+            }
+        }
+    }
+}
+
+/// Get the character a key types, only for the characters the HUD font has
+fn key_to_char(key: Sc) -> Option<char> {
+    if key == Sc::Space {
+        return Some(' ');
+    }
+    let name = key.name();
+    if name.len() == 1 {
+        let c = name.chars().next()?;
+        if (HU_FONTSTART..=HU_FONTEND).contains(&(c as u8)) {
+            return Some(c.to_ascii_uppercase());
+        }
+    }
+    None
+}
@@ -0,0 +1,191 @@
+//! Miscellaneous support, the loading of the defaults (config) file and
+//! saving screenshots.
+//!
+//! The defaults file is in the vanilla `default.cfg` layout, one setting per
+//! line as a name followed by whitespace and the value. String values may be
+//! quoted:
+//!
+//! ```text
+//! shader          lottes
+//! shader_chain    "scale.glsl,crt.glsl,bloom.glsl"
+//! key_shader      Insert
+//! usegamma        2
+//! ```
+//!
+//! Lines which can't be parsed are skipped, as are lines starting with `#`.
+//! The file is written back on quit so that settings changed while playing,
+//! such as the gamma level, are kept.
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{copy, create_dir_all, read_to_string, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// The settings loaded from the defaults file
+#[derive(Debug, Default)]
+pub(crate) struct Defaults {
+    values: HashMap<String, String>,
+}
+
+impl Defaults {
+    /// M_LoadDefaults
+    ///
+    /// A missing file is not an error, all settings will use their defaults
+    pub(crate) fn load(path: &str) -> Self {
+        let mut defaults = Self::default();
+        let data = match read_to_string(path) {
+            Ok(data) => data,
+            Err(_) => return defaults,
+        };
+
+        for line in data.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut split = line.splitn(2, char::is_whitespace);
+            if let (Some(name), Some(value)) = (split.next(), split.next()) {
+                defaults.values.insert(
+                    name.to_owned(),
+                    value.trim().trim_matches('"').to_owned(),
+                );
+            }
+        }
+        defaults
+    }
+
+    /// The raw string value of a setting
+    pub(crate) fn get_str(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(|s| s.as_str())
+    }
+
+    /// Parse a setting, `None` if missing or invalid
+    pub(crate) fn get<T: FromStr>(&self, name: &str) -> Option<T> {
+        let value = self.get_str(name)?;
+        match value.parse() {
+            Ok(v) => Some(v),
+            Err(_) => {
+                println!(
+                    "M_LoadDefaults: invalid value for {}: {}",
+                    name, value
+                );
+                None
+            }
+        }
+    }
+
+    /// Set a setting, to be saved with `save`
+    pub(crate) fn set<T: Display>(&mut self, name: &str, value: T) {
+        self.values.insert(name.to_owned(), value.to_string());
+    }
+
+    /// M_SaveDefaults
+    ///
+    /// Writes every setting loaded or set, in name order. Values which
+    /// aren't numbers are quoted.
+    pub(crate) fn save(&self, path: &str) -> Result<(), String> {
+        let mut names: Vec<&String> = self.values.keys().collect();
+        names.sort();
+
+        let mut file = File::create(path).map_err(|e| e.to_string())?;
+        for name in names {
+            let value = &self.values[name];
+            if value.parse::<f32>().is_ok() {
+                writeln!(file, "{:<24}{}", name, value)
+            } else {
+                writeln!(file, "{:<24}\"{}\"", name, value)
+            }
+            .map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+/// Write RGBA32 pixels, `width * 4` bytes per row, to a PNG. The alpha is
+/// dropped as the render buffer doesn't use it.
+pub(crate) fn write_png(
+    path: &Path,
+    width: u32,
+    height: u32,
+    rgba: &[u8],
+) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| e.to_string())?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), width, height);
+    encoder.set_color(png::ColorType::RGB);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let rgb: Vec<u8> = rgba
+        .chunks_exact(4)
+        .flat_map(|p| p[..3].iter().copied())
+        .collect();
+    encoder
+        .write_header()
+        .and_then(|mut w| w.write_image_data(&rgb))
+        .map_err(|e| e.to_string())
+}
+
+/// M_ScreenShot
+///
+/// Saves to the first free `DOOMnn.png` in the working dir, returns the
+/// file name
+pub(crate) fn screen_shot(
+    width: u32,
+    height: u32,
+    rgba: &[u8],
+) -> Result<PathBuf, String> {
+    let path = (0..=99)
+        .map(|i| PathBuf::from(format!("DOOM{:02}.png", i)))
+        .find(|p| !p.exists())
+        .ok_or_else(|| "M_ScreenShot: Couldn't create a PNG".to_owned())?;
+    write_png(&path, width, height, rgba)?;
+    Ok(path)
+}
+
+/// Saves each frame as a numbered PNG, for making videos
+pub(crate) struct FrameRecorder {
+    dir:     PathBuf,
+    /// Save once per game tic rather than once per frame drawn
+    per_tic: bool,
+    frame:   u32,
+}
+
+impl FrameRecorder {
+    pub(crate) fn new(dir: &str, per_tic: bool) -> Result<Self, String> {
+        create_dir_all(dir).map_err(|e| e.to_string())?;
+        Ok(Self {
+            dir: dir.into(),
+            per_tic,
+            frame: 0,
+        })
+    }
+
+    /// Record the frame just drawn. `tics` is how many game tics were run
+    /// before it was drawn, when recording per tic the frame is saved that
+    /// many times so the result plays at 35 frames a second.
+    pub(crate) fn record(
+        &mut self,
+        tics: u32,
+        width: u32,
+        height: u32,
+        rgba: &[u8],
+    ) -> Result<(), String> {
+        let count = if self.per_tic { tics } else { 1 };
+        let mut first: Option<PathBuf> = None;
+        for _ in 0..count {
+            let path = self.dir.join(format!("frame{:06}.png", self.frame));
+            match first {
+                Some(ref first) => {
+                    copy(first, &path).map_err(|e| e.to_string())?;
+                }
+                None => write_png(&path, width, height, rgba)?,
+            }
+            first.get_or_insert(path);
+            self.frame += 1;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,212 @@
+//!	Ceiling aninmation (lowering, crushing, raising)
+
+use std::ptr::NonNull;
+
+use crate::d_thinker::{Think, Thinker};
+use crate::level_data::level::Level;
+use crate::level_data::map_defs::LineDef;
+use crate::p_floor::t_move_plane;
+use crate::p_spec::{
+    find_highest_ceiling_surrounding, find_sectors_from_line_tag, CeilingKind,
+    CeilingMove, PlaneResult, SpecialData,
+};
+use crate::DPtr;
+
+const CEILSPEED: f32 = 1.0;
+
+/// T_MoveCeiling
+impl Think for CeilingMove {
+    fn think(&mut self, level: &mut Level) -> bool {
+        match self.direction {
+            0 => {
+                // IN STASIS
+            }
+            1 => {
+                // UP
+                let res = t_move_plane(
+                    self.sector.clone(),
+                    self.speed,
+                    self.topheight,
+                    false,
+                    1,
+                    self.direction,
+                    level,
+                );
+
+                // TODO: if (!(leveltime&7)) S_StartSound(sfx_stnmov) if
+                //  not silentCrushAndRaise
+
+                if res == PlaneResult::PastDest {
+                    match self.kind {
+                        CeilingKind::raiseToHighest => {
+                            // P_RemoveActiveCeiling
+                            self.sector.specialdata = None;
+                            return true;
+                        }
+                        CeilingKind::silentCrushAndRaise
+                        | CeilingKind::fastCrushAndRaise
+                        | CeilingKind::crushAndRaise => {
+                            // TODO: S_StartSound(sfx_pstop) if silent
+                            self.direction = -1;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            -1 => {
+                // DOWN
+                let res = t_move_plane(
+                    self.sector.clone(),
+                    self.speed,
+                    self.bottomheight,
+                    self.crush,
+                    1,
+                    self.direction,
+                    level,
+                );
+
+                // TODO: if (!(leveltime&7)) S_StartSound(sfx_stnmov) if
+                //  not silentCrushAndRaise
+
+                if res == PlaneResult::PastDest {
+                    match self.kind {
+                        CeilingKind::silentCrushAndRaise
+                        | CeilingKind::crushAndRaise => {
+                            // TODO: S_StartSound(sfx_pstop) if silent
+                            self.speed = CEILSPEED;
+                            self.direction = 1;
+                        }
+                        CeilingKind::fastCrushAndRaise => {
+                            self.direction = 1;
+                        }
+                        CeilingKind::lowerAndCrush
+                        | CeilingKind::lowerToFloor => {
+                            // P_RemoveActiveCeiling
+                            self.sector.specialdata = None;
+                            return true;
+                        }
+                        _ => {}
+                    }
+                } else if res == PlaneResult::Crushed {
+                    match self.kind {
+                        CeilingKind::silentCrushAndRaise
+                        | CeilingKind::crushAndRaise
+                        | CeilingKind::lowerAndCrush => {
+                            self.speed = CEILSPEED / 8.0;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+        false
+    }
+}
+
+/// EV_DoCeiling
+/// Move a ceiling up/down and all around!
+pub(crate) fn ev_do_ceiling(
+    line: DPtr<LineDef>,
+    kind: CeilingKind,
+    level: &mut Level,
+) -> bool {
+    //	Reactivate in-stasis ceilings...for certain types.
+    match kind {
+        CeilingKind::fastCrushAndRaise
+        | CeilingKind::silentCrushAndRaise
+        | CeilingKind::crushAndRaise => {
+            activate_in_stasis_ceiling(&line, level);
+        }
+        _ => {}
+    }
+
+    let mut rtn = false;
+    for mut sec in find_sectors_from_line_tag(&line, level) {
+        if sec.specialdata.is_some() {
+            continue;
+        }
+
+        // new door thinker
+        rtn = true;
+        let mut ceiling = CeilingMove {
+            thinker: None,
+            sector: sec.clone(),
+            kind,
+            bottomheight: 0.0,
+            topheight: 0.0,
+            speed: CEILSPEED,
+            crush: false,
+            direction: 0,
+            tag: sec.tag,
+            olddirection: 0,
+        };
+
+        match kind {
+            CeilingKind::fastCrushAndRaise => {
+                ceiling.crush = true;
+                ceiling.topheight = sec.ceilingheight;
+                ceiling.bottomheight = sec.floorheight + 8.0;
+                ceiling.direction = -1;
+                ceiling.speed = CEILSPEED * 2.0;
+            }
+            CeilingKind::silentCrushAndRaise
+            | CeilingKind::crushAndRaise
+            | CeilingKind::lowerAndCrush
+            | CeilingKind::lowerToFloor => {
+                if kind == CeilingKind::silentCrushAndRaise
+                    || kind == CeilingKind::crushAndRaise
+                {
+                    ceiling.crush = true;
+                    ceiling.topheight = sec.ceilingheight;
+                }
+                ceiling.bottomheight = sec.floorheight;
+                if kind != CeilingKind::lowerToFloor {
+                    ceiling.bottomheight += 8.0;
+                }
+                ceiling.direction = -1;
+            }
+            CeilingKind::raiseToHighest => {
+                ceiling.topheight = find_highest_ceiling_surrounding(&sec);
+                ceiling.direction = 1;
+            }
+        }
+
+        // P_AddActiveCeiling
+        let mut thinker = Box::new(Thinker::new(ceiling));
+        let ptr = NonNull::from(&mut *thinker);
+        thinker.obj.thinker = Some(ptr);
+        sec.specialdata = Some(SpecialData::Ceiling(ptr));
+        level.ceilings.push(thinker);
+    }
+    rtn
+}
+
+/// P_ActivateInStasisCeiling
+/// Restart a ceiling that's in-stasis
+fn activate_in_stasis_ceiling(line: &LineDef, level: &mut Level) {
+    for ceiling in level.ceilings.iter_mut() {
+        let ceiling = &mut ceiling.obj;
+        if ceiling.tag == line.tag && ceiling.direction == 0 {
+            ceiling.direction = ceiling.olddirection;
+        }
+    }
+}
+
+/// EV_CeilingCrushStop
+/// Stop a ceiling from crushing!
+pub(crate) fn ev_ceiling_crush_stop(
+    line: DPtr<LineDef>,
+    level: &mut Level,
+) -> bool {
+    let mut rtn = false;
+    for ceiling in level.ceilings.iter_mut() {
+        let ceiling = &mut ceiling.obj;
+        if ceiling.direction != 0 && ceiling.tag == line.tag {
+            ceiling.olddirection = ceiling.direction;
+            ceiling.direction = 0; // in-stasis
+            rtn = true;
+        }
+    }
+    rtn
+}
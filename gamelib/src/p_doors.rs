@@ -0,0 +1,374 @@
+//!	Door animation code (opening/closing)
+
+use std::ptr::NonNull;
+
+use crate::d_thinker::{Think, Thinker};
+use crate::doom_def::{Card, TICRATE};
+use crate::level_data::level::Level;
+use crate::level_data::map_defs::{LineDef, Sector};
+use crate::p_floor::t_move_plane;
+use crate::p_map_object::MapObject;
+use crate::p_spec::{
+    find_lowest_ceiling_surrounding, find_sectors_from_line_tag, DoorKind,
+    PlaneResult, SpecialData, VerticalDoor,
+};
+use crate::player::Player;
+use crate::DPtr;
+
+const VDOORSPEED: f32 = 2.0;
+const VDOORWAIT: i32 = 150;
+
+const PD_BLUEO: &str = "You need a blue key to activate this object";
+const PD_REDO: &str = "You need a red key to activate this object";
+const PD_YELLOWO: &str = "You need a yellow key to activate this object";
+const PD_BLUEK: &str = "You need a blue key to open this door";
+const PD_REDK: &str = "You need a red key to open this door";
+const PD_YELLOWK: &str = "You need a yellow key to open this door";
+
+/// T_VerticalDoor
+impl Think for VerticalDoor {
+    fn think(&mut self, level: &mut Level) -> bool {
+        match self.direction {
+            0 => {
+                // WAITING
+                self.topcountdown -= 1;
+                if self.topcountdown == 0 {
+                    match self.kind {
+                        DoorKind::blazeRaise | DoorKind::normal => {
+                            // time to go back down
+                            self.direction = -1;
+                            // TODO: S_StartSound sfx_bdcls or sfx_dorcls
+                        }
+                        DoorKind::close30ThenOpen => {
+                            self.direction = 1;
+                            // TODO: S_StartSound sfx_doropn
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            2 => {
+                // INITIAL WAIT
+                self.topcountdown -= 1;
+                if self.topcountdown == 0 && self.kind == DoorKind::raiseIn5Mins
+                {
+                    self.direction = 1;
+                    self.kind = DoorKind::normal;
+                    // TODO: S_StartSound sfx_doropn
+                }
+            }
+            -1 => {
+                // DOWN
+                let res = t_move_plane(
+                    self.sector.clone(),
+                    self.speed,
+                    self.sector.floorheight,
+                    false,
+                    1,
+                    self.direction,
+                    level,
+                );
+                match res {
+                    PlaneResult::PastDest => match self.kind {
+                        DoorKind::blazeRaise
+                        | DoorKind::blazeClose
+                        | DoorKind::normal
+                        | DoorKind::close => {
+                            // TODO: S_StartSound sfx_bdcls for blazing
+                            self.sector.specialdata = None;
+                            return true; // unlink and free
+                        }
+                        DoorKind::close30ThenOpen => {
+                            self.direction = 0;
+                            self.topcountdown = TICRATE * 30;
+                        }
+                        _ => {}
+                    },
+                    PlaneResult::Crushed => match self.kind {
+                        DoorKind::blazeClose | DoorKind::close => {
+                            // DO NOT GO BACK UP!
+                        }
+                        _ => {
+                            self.direction = 1;
+                            // TODO: S_StartSound sfx_doropn
+                        }
+                    },
+                    PlaneResult::Ok => {}
+                }
+            }
+            1 => {
+                // UP
+                let res = t_move_plane(
+                    self.sector.clone(),
+                    self.speed,
+                    self.topheight,
+                    false,
+                    1,
+                    self.direction,
+                    level,
+                );
+                if let PlaneResult::PastDest = res {
+                    match self.kind {
+                        DoorKind::blazeRaise | DoorKind::normal => {
+                            // wait at top
+                            self.direction = 0;
+                            self.topcountdown = self.topwait;
+                        }
+                        DoorKind::close30ThenOpen
+                        | DoorKind::blazeOpen
+                        | DoorKind::open => {
+                            self.sector.specialdata = None;
+                            return true; // unlink and free
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+        false
+    }
+}
+
+/// P_AddThinker for a door, setting it as the sector's `specialdata`
+fn add_door(
+    mut sector: DPtr<Sector>,
+    kind: DoorKind,
+    level: &mut Level,
+) -> &mut VerticalDoor {
+    let mut thinker = Box::new(Thinker::new(VerticalDoor {
+        thinker: None,
+        sector: sector.clone(),
+        kind,
+        topheight: 0.0,
+        speed: VDOORSPEED,
+        direction: 1,
+        topwait: VDOORWAIT,
+        topcountdown: 0,
+    }));
+    let ptr = NonNull::from(&mut *thinker);
+    thinker.obj.thinker = Some(ptr);
+    sector.specialdata = Some(SpecialData::Door(ptr));
+    level.doors.push(thinker);
+    &mut level.doors.last_mut().unwrap().obj
+}
+
+/// Checks `player` has either the card or skull key, setting `message` if
+/// they don't
+fn has_key(
+    player: &mut Player,
+    card: Card,
+    skull: Card,
+    message: &str,
+) -> bool {
+    if !player.cards[card as usize] && !player.cards[skull as usize] {
+        player.message = Some(message.to_owned());
+        // TODO: S_StartSound(NULL,sfx_oof);
+        return false;
+    }
+    true
+}
+
+/// EV_DoLockedDoor
+///
+/// Move a locked door up/down
+pub(crate) fn ev_do_locked_door(
+    line: DPtr<LineDef>,
+    kind: DoorKind,
+    thing: &mut MapObject,
+    level: &mut Level,
+) -> bool {
+    let player = match thing.player {
+        Some(mut player) => unsafe { player.as_mut() },
+        None => return false,
+    };
+
+    let unlocked = match line.special {
+        // Blue Lock
+        99 | 133 => {
+            has_key(player, Card::it_bluecard, Card::it_blueskull, PD_BLUEO)
+        }
+        // Red Lock
+        134 | 135 => {
+            has_key(player, Card::it_redcard, Card::it_redskull, PD_REDO)
+        }
+        // Yellow Lock
+        136 | 137 => has_key(
+            player,
+            Card::it_yellowcard,
+            Card::it_yellowskull,
+            PD_YELLOWO,
+        ),
+        _ => true,
+    };
+    if !unlocked {
+        return false;
+    }
+
+    ev_do_door(line, kind, level)
+}
+
+/// EV_DoDoor
+pub(crate) fn ev_do_door(
+    line: DPtr<LineDef>,
+    kind: DoorKind,
+    level: &mut Level,
+) -> bool {
+    let mut rtn = false;
+
+    for sector in find_sectors_from_line_tag(&line, level) {
+        if sector.specialdata.is_some() {
+            continue;
+        }
+
+        // new door thinker
+        rtn = true;
+        let door = add_door(sector.clone(), kind, level);
+
+        match kind {
+            DoorKind::blazeClose => {
+                door.topheight = find_lowest_ceiling_surrounding(&sector) - 4.0;
+                door.direction = -1;
+                door.speed = VDOORSPEED * 4.0;
+                // TODO: S_StartSound sfx_bdcls
+            }
+            DoorKind::close => {
+                door.topheight = find_lowest_ceiling_surrounding(&sector) - 4.0;
+                door.direction = -1;
+                // TODO: S_StartSound sfx_dorcls
+            }
+            DoorKind::close30ThenOpen => {
+                door.topheight = sector.ceilingheight;
+                door.direction = -1;
+                // TODO: S_StartSound sfx_dorcls
+            }
+            DoorKind::blazeRaise | DoorKind::blazeOpen => {
+                door.direction = 1;
+                door.topheight = find_lowest_ceiling_surrounding(&sector) - 4.0;
+                door.speed = VDOORSPEED * 4.0;
+                // TODO: S_StartSound sfx_bdopn if the door will move
+            }
+            DoorKind::normal | DoorKind::open => {
+                door.direction = 1;
+                door.topheight = find_lowest_ceiling_surrounding(&sector) - 4.0;
+                // TODO: S_StartSound sfx_doropn if the door will move
+            }
+            _ => {}
+        }
+    }
+    rtn
+}
+
+/// EV_VerticalDoor : open a door manually, no tag value
+pub(crate) fn ev_vertical_door(
+    mut line: DPtr<LineDef>,
+    thing: &mut MapObject,
+    level: &mut Level,
+) {
+    // Check for locks
+    if let 26 | 27 | 28 | 32 | 33 | 34 = line.special {
+        let player = match thing.player {
+            Some(mut player) => unsafe { player.as_mut() },
+            None => return,
+        };
+        let unlocked = match line.special {
+            // Blue Lock
+            26 | 32 => {
+                has_key(player, Card::it_bluecard, Card::it_blueskull, PD_BLUEK)
+            }
+            // Yellow Lock
+            27 | 34 => has_key(
+                player,
+                Card::it_yellowcard,
+                Card::it_yellowskull,
+                PD_YELLOWK,
+            ),
+            // Red Lock
+            _ => has_key(player, Card::it_redcard, Card::it_redskull, PD_REDK),
+        };
+        if !unlocked {
+            return;
+        }
+    }
+
+    // if the sector has an active thinker, use it
+    let sector = match line.backsector {
+        Some(ref sector) => sector.clone(),
+        None => return,
+    };
+
+    if let Some(SpecialData::Door(mut door)) = sector.specialdata {
+        let door = unsafe { &mut door.as_mut().obj };
+        if let 1 | 26 | 27 | 28 | 117 = line.special {
+            // ONLY FOR "RAISE" DOORS, NOT "OPEN"s
+            if door.direction == -1 {
+                // go back up
+                door.direction = 1;
+            } else {
+                if thing.player.is_none() {
+                    // JDC: bad guys never close doors
+                    return;
+                }
+                // start going down immediately
+                door.direction = -1;
+            }
+            return;
+        }
+    }
+    if sector.specialdata.is_some() {
+        return;
+    }
+
+    // TODO: S_StartSound sfx_bdopn for 117 and 118, else sfx_doropn
+
+    // new door thinker
+    let door = add_door(sector.clone(), DoorKind::normal, level);
+    match line.special {
+        31 | 32 | 33 | 34 => {
+            door.kind = DoorKind::open;
+            line.special = 0;
+        }
+        117 => {
+            // blazing door raise
+            door.kind = DoorKind::blazeRaise;
+            door.speed = VDOORSPEED * 4.0;
+        }
+        118 => {
+            // blazing door open
+            door.kind = DoorKind::blazeOpen;
+            line.special = 0;
+            door.speed = VDOORSPEED * 4.0;
+        }
+        _ => {}
+    }
+
+    // find the top and bottom of the movement range
+    door.topheight = find_lowest_ceiling_surrounding(&sector) - 4.0;
+}
+
+/// P_SpawnDoorCloseIn30
+///
+/// Spawn a door that closes after 30 seconds
+pub(crate) fn p_spawn_door_close_in_30(
+    mut sector: DPtr<Sector>,
+    level: &mut Level,
+) {
+    let door = add_door(sector.clone(), DoorKind::normal, level);
+    door.direction = 0;
+    door.topcountdown = 30 * TICRATE;
+    sector.special = 0;
+}
+
+/// P_SpawnDoorRaiseIn5Mins
+///
+/// Spawn a door that opens after 5 minutes
+pub(crate) fn p_spawn_door_raise_in_5_mins(
+    mut sector: DPtr<Sector>,
+    level: &mut Level,
+) {
+    let door = add_door(sector.clone(), DoorKind::raiseIn5Mins, level);
+    door.direction = 2;
+    door.topheight = find_lowest_ceiling_surrounding(&sector) - 4.0;
+    door.topcountdown = 5 * 60 * TICRATE;
+    sector.special = 0;
+}
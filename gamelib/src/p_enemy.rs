@@ -1,28 +1,122 @@
+use crate::angle::Angle;
+use crate::level_data::level::Level;
+use crate::p_local::{p_random, random_spread, MISSILERANGE};
 use crate::p_map_object::MapObjectFlag;
 use crate::renderer::bsp::point_to_angle_2;
 use crate::{p_map::p_radius_attack, p_map_object::MapObject};
 
 /// A_FaceTarget
-pub(crate) fn a_facetarget<'t>(actor: &'t mut MapObject) {
+pub(crate) fn a_facetarget(actor: &mut MapObject, _level: &mut Level) {
+    let target = match actor.target {
+        Some(target) => unsafe { target.as_ref() },
+        None => return,
+    };
+
     actor.flags &= !(MapObjectFlag::MF_AMBUSH as u32);
+    actor.angle = point_to_angle_2(&target.xy, &actor.xy);
 
-    unsafe {
-        let angle =
-            point_to_angle_2(&actor.xy, &actor.target.unwrap().as_mut().xy);
-        actor.angle = angle;
+    if target.flags & MapObjectFlag::MF_SHADOW as u32 != 0 {
+        actor.angle += random_spread(21);
+    }
+}
 
-        if actor.target.unwrap().as_mut().flags
-            & MapObjectFlag::MF_SHADOW as u32
-            == MapObjectFlag::MF_SHADOW as u32
-        {
-            // TODO: actor.angle += P_SubRandom() << 21;
-        }
+/// The aim and bullet of the hitscan monster attacks, a shot at the target
+/// with some spread
+fn monster_shot(
+    actor: &mut MapObject,
+    angle: Angle,
+    slope: f32,
+    level: &mut Level,
+) {
+    let angle = angle + random_spread(20);
+    let damage = ((p_random() % 5) + 1) as i32 * 3;
+    actor.p_line_attack(angle, MISSILERANGE, slope, damage, level);
+}
+
+/// The slope to aim a hitscan attack along `actor.angle` at
+fn monster_aim(actor: &mut MapObject, level: &mut Level) -> f32 {
+    actor
+        .p_aim_line_attack(actor.angle, MISSILERANGE, level)
+        .map_or(0.0, |(slope, _)| slope)
+}
+
+/// A_PosAttack, the zombieman's pistol
+pub(crate) fn a_posattack(actor: &mut MapObject, level: &mut Level) {
+    if actor.target.is_none() {
+        return;
+    }
+
+    a_facetarget(actor, level);
+    let angle = actor.angle;
+    let slope = monster_aim(actor, level);
+    // TODO: S_StartSound(actor, sfx_pistol);
+    monster_shot(actor, angle, slope, level);
+}
+
+/// A_SPosAttack, the shotgun guy and the spider mastermind
+pub(crate) fn a_sposattack(actor: &mut MapObject, level: &mut Level) {
+    if actor.target.is_none() {
+        return;
+    }
+
+    // TODO: S_StartSound(actor, sfx_shotgn);
+    a_facetarget(actor, level);
+    let angle = actor.angle;
+    let slope = monster_aim(actor, level);
+    for _ in 0..3 {
+        monster_shot(actor, angle, slope, level);
+    }
+}
+
+/// A_CPosAttack, the chaingunner and the Wolfenstein SS
+pub(crate) fn a_cposattack(actor: &mut MapObject, level: &mut Level) {
+    if actor.target.is_none() {
+        return;
+    }
+
+    // TODO: S_StartSound(actor, sfx_shotgn);
+    a_facetarget(actor, level);
+    let angle = actor.angle;
+    let slope = monster_aim(actor, level);
+    monster_shot(actor, angle, slope, level);
+}
+
+/// A_CPosRefire
+pub(crate) fn a_cposrefire(actor: &mut MapObject, level: &mut Level) {
+    // keep firing unless target got out of sight
+    a_facetarget(actor, level);
+
+    if p_random() < 40 {
+        return;
+    }
+    stop_firing_at_dead_target(actor, level);
+}
+
+/// A_SpidRefire
+pub(crate) fn a_spidrefire(actor: &mut MapObject, level: &mut Level) {
+    // keep firing unless target got out of sight
+    a_facetarget(actor, level);
+
+    if p_random() < 10 {
+        return;
+    }
+    stop_firing_at_dead_target(actor, level);
+}
+
+/// Go back to chasing if the target is gone or dead
+fn stop_firing_at_dead_target(actor: &mut MapObject, level: &mut Level) {
+    // TODO: also stop if !P_CheckSight(actor, actor->target)
+    let dead = actor
+        .target
+        .map_or(true, |target| unsafe { target.as_ref().health <= 0 });
+    if dead {
+        actor.p_set_mobj_state(actor.info.seestate, level);
     }
 }
 
 /// Actor has a melee attack,
 /// so it tries to close as fast as possible
-pub(crate) fn a_chase<'t>(actor: &'t mut MapObject) {
+pub(crate) fn a_chase(actor: &mut MapObject, _level: &mut Level) {
     if actor.reactiontime > 0 {
         actor.reactiontime -= 1;
     }
@@ -41,7 +135,7 @@ pub(crate) fn a_chase<'t>(actor: &'t mut MapObject) {
         }
     }
 
-    unimplemented!()
+    // TODO: the rest of A_Chase, it needs P_Move and P_CheckMissileRange
     //
 
     //
@@ -125,8 +219,9 @@ pub(crate) fn a_chase<'t>(actor: &'t mut MapObject) {
 }
 
 /// Stay in state until a player is sighted.
-pub(crate) fn a_look<'t>(actor: &'t mut MapObject) {
-    unimplemented!()
+pub(crate) fn a_look(_actor: &mut MapObject, _level: &mut Level) {
+    // TODO: A_Look, it needs P_LookForPlayers and P_CheckSight. Until then
+    //  monsters stay in their spawn state
     // mobj_t *targ;
     //
     // actor->threshold = 0; // any shot will wake up
@@ -184,8 +279,8 @@ pub(crate) fn a_look<'t>(actor: &'t mut MapObject) {
     // P_SetMobjState(actor, actor->info->seestate);
 }
 
-pub(crate) fn a_fire<'t>(actor: &'t mut MapObject) {
-    unimplemented!()
+pub(crate) fn a_fire(_actor: &mut MapObject, _level: &mut Level) {
+    // TODO: A_Fire, it needs P_CheckSight and the Arch-vile's tracer
     // mobj_t *dest;
     // mobj_t *target;
     // unsigned an;
@@ -209,8 +304,8 @@ pub(crate) fn a_fire<'t>(actor: &'t mut MapObject) {
     // P_SetThingPosition(actor);
 }
 
-pub(crate) fn a_scream<'t>(actor: &'t mut MapObject) {
-    unimplemented!()
+pub(crate) fn a_scream(_actor: &mut MapObject, _level: &mut Level) {
+    // TODO: S_StartSound, this action only plays a sound
     // int sound;
     //
     // switch (actor->info->deathsound)
@@ -244,13 +339,13 @@ pub(crate) fn a_scream<'t>(actor: &'t mut MapObject) {
     // S_StartSound(actor, sound);
 }
 
-pub(crate) fn a_pain<'t>(actor: &'t mut MapObject) {
-    unimplemented!()
+pub(crate) fn a_pain(_actor: &mut MapObject, _level: &mut Level) {
+    // TODO: S_StartSound, this action only plays a sound
     // if (actor->info->painsound)
     // S_StartSound(actor, actor->info->painsound);
 }
 
-pub(crate) fn a_fall<'t>(actor: &'t mut MapObject) {
+pub(crate) fn a_fall(actor: &mut MapObject, _level: &mut Level) {
     // actor is on ground, it can be walked over
     actor.flags &= !(MapObjectFlag::MF_SOLID as u32);
 
@@ -258,16 +353,11 @@ pub(crate) fn a_fall<'t>(actor: &'t mut MapObject) {
     // are meant to be obstacles.
 }
 
-pub(crate) fn a_explode<'t>(actor: &'t mut MapObject) {
-    if let Some(mut target) = actor.target {
-        // just casually breaking lifetimes
-        let target = unsafe { target.as_mut() };
-        p_radius_attack(actor, target, 128.0);
-    }
+pub(crate) fn a_explode(actor: &mut MapObject, level: &mut Level) {
+    p_radius_attack(actor, actor.target, 128, level);
 }
 
-pub(crate) fn a_xscream<'t>(actor: &'t mut MapObject) {
-    unimplemented!()
-    // if (actor->info->painsound)
-    // S_StartSound(actor, actor->info->painsound);
+pub(crate) fn a_xscream(_actor: &mut MapObject, _level: &mut Level) {
+    // TODO: S_StartSound, this action only plays a sound
+    // S_StartSound(actor, sfx_slop);
 }
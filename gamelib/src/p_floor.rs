@@ -0,0 +1,378 @@
+//!	Floor animation: raising stairs.
+
+use std::ptr::NonNull;
+
+use crate::d_thinker::{Think, Thinker};
+use crate::flags::LineDefFlags;
+use crate::level_data::level::Level;
+use crate::level_data::map_defs::{LineDef, Sector};
+use crate::p_map::p_change_sector;
+use crate::p_spec::{
+    find_highest_floor_surrounding, find_lowest_ceiling_surrounding,
+    find_lowest_floor_surrounding, find_next_highest_floor,
+    find_sectors_from_line_tag, get_next_sector, FloorEnum, FloorMove,
+    PlaneResult, SpecialData, StairEnum,
+};
+use crate::DPtr;
+
+pub(crate) const FLOORSPEED: f32 = 1.0;
+
+/// T_MovePlane
+///
+/// Move a plane (floor or ceiling) and check for crushing. A
+/// `floor_or_ceiling` of 0 is the floor, `direction` is 1 for up or -1 down.
+pub(crate) fn t_move_plane(
+    mut sector: DPtr<Sector>,
+    speed: f32,
+    dest: f32,
+    crush: bool,
+    floor_or_ceiling: i32,
+    direction: i32,
+    level: &mut Level,
+) -> PlaneResult {
+    match (floor_or_ceiling, direction) {
+        // FLOOR, DOWN
+        (0, -1) => {
+            let lastpos = sector.floorheight;
+            if sector.floorheight - speed < dest {
+                sector.floorheight = dest;
+                if p_change_sector(sector.clone(), crush, level) {
+                    sector.floorheight = lastpos;
+                    p_change_sector(sector, crush, level);
+                    //return crushed;
+                }
+                return PlaneResult::PastDest;
+            }
+            sector.floorheight -= speed;
+            if p_change_sector(sector.clone(), crush, level) {
+                sector.floorheight = lastpos;
+                p_change_sector(sector, crush, level);
+                return PlaneResult::Crushed;
+            }
+        }
+        // FLOOR, UP
+        (0, 1) => {
+            let lastpos = sector.floorheight;
+            if sector.floorheight + speed > dest {
+                sector.floorheight = dest;
+                if p_change_sector(sector.clone(), crush, level) {
+                    sector.floorheight = lastpos;
+                    p_change_sector(sector, crush, level);
+                    //return crushed;
+                }
+                return PlaneResult::PastDest;
+            }
+            // COULD GET CRUSHED
+            sector.floorheight += speed;
+            if p_change_sector(sector.clone(), crush, level) {
+                if crush {
+                    return PlaneResult::Crushed;
+                }
+                sector.floorheight = lastpos;
+                p_change_sector(sector, crush, level);
+                return PlaneResult::Crushed;
+            }
+        }
+        // CEILING, DOWN
+        (1, -1) => {
+            let lastpos = sector.ceilingheight;
+            if sector.ceilingheight - speed < dest {
+                sector.ceilingheight = dest;
+                if p_change_sector(sector.clone(), crush, level) {
+                    sector.ceilingheight = lastpos;
+                    p_change_sector(sector, crush, level);
+                    //return crushed;
+                }
+                return PlaneResult::PastDest;
+            }
+            // COULD GET CRUSHED
+            sector.ceilingheight -= speed;
+            if p_change_sector(sector.clone(), crush, level) {
+                if crush {
+                    return PlaneResult::Crushed;
+                }
+                sector.ceilingheight = lastpos;
+                p_change_sector(sector, crush, level);
+                return PlaneResult::Crushed;
+            }
+        }
+        // CEILING, UP
+        (1, 1) => {
+            let lastpos = sector.ceilingheight;
+            if sector.ceilingheight + speed > dest {
+                sector.ceilingheight = dest;
+                if p_change_sector(sector.clone(), crush, level) {
+                    sector.ceilingheight = lastpos;
+                    p_change_sector(sector, crush, level);
+                    //return crushed;
+                }
+                return PlaneResult::PastDest;
+            }
+            sector.ceilingheight += speed;
+            p_change_sector(sector, crush, level);
+            // UNUSED
+        }
+        _ => {}
+    }
+    PlaneResult::Ok
+}
+
+/// T_MoveFloor
+///
+/// MOVE A FLOOR TO IT'S DESTINATION (UP OR DOWN)
+impl Think for FloorMove {
+    fn think(&mut self, level: &mut Level) -> bool {
+        let res = t_move_plane(
+            self.sector.clone(),
+            self.speed,
+            self.floordestheight,
+            self.crush,
+            0,
+            self.direction,
+            level,
+        );
+
+        // TODO: if (!(leveltime&7)) S_StartSound(sfx_stnmov);
+
+        if res == PlaneResult::PastDest {
+            self.sector.specialdata = None;
+
+            if self.direction == 1 && self.kind == FloorEnum::donutRaise
+                || self.direction == -1
+                    && self.kind == FloorEnum::lowerAndChange
+            {
+                self.sector.special = self.newspecial;
+                self.sector.floorpic = self.texture;
+            }
+
+            // TODO: S_StartSound(sfx_pstop);
+            return true; // remove thinker
+        }
+        false
+    }
+}
+
+/// P_AddThinker for a floor mover, setting it as the sector's
+/// `specialdata`. The floor starts moving up at `FLOORSPEED`.
+pub(crate) fn add_floor(
+    mut sector: DPtr<Sector>,
+    kind: FloorEnum,
+    level: &mut Level,
+) -> &mut FloorMove {
+    let floordestheight = sector.floorheight;
+    let texture = sector.floorpic;
+    let mut thinker = Box::new(Thinker::new(FloorMove {
+        thinker: None,
+        sector: sector.clone(),
+        kind,
+        speed: FLOORSPEED,
+        crush: false,
+        direction: 1,
+        newspecial: 0,
+        texture,
+        floordestheight,
+    }));
+    let ptr = NonNull::from(&mut *thinker);
+    thinker.obj.thinker = Some(ptr);
+    sector.specialdata = Some(SpecialData::Floor(ptr));
+    level.floors.push(thinker);
+    &mut level.floors.last_mut().unwrap().obj
+}
+
+/// EV_DoFloor
+///
+/// HANDLE FLOOR TYPES
+pub(crate) fn ev_do_floor(
+    line: DPtr<LineDef>,
+    kind: FloorEnum,
+    level: &mut Level,
+) -> bool {
+    let mut rtn = false;
+    for mut sec in find_sectors_from_line_tag(&line, level) {
+        // ALREADY MOVING?  IF SO, KEEP GOING...
+        if sec.specialdata.is_some() {
+            continue;
+        }
+
+        // The floor mover borrows the level, so the texture heights are
+        // looked up first
+        let lower_texture = if kind == FloorEnum::raiseToTexture {
+            shortest_lower_texture(&sec, level)
+        } else {
+            0.0
+        };
+
+        // new floor thinker
+        rtn = true;
+        let floor = add_floor(sec.clone(), kind, level);
+
+        match kind {
+            FloorEnum::lowerFloor => {
+                floor.direction = -1;
+                floor.floordestheight = find_highest_floor_surrounding(&sec);
+            }
+            FloorEnum::lowerFloorToLowest => {
+                floor.direction = -1;
+                floor.floordestheight = find_lowest_floor_surrounding(&sec);
+            }
+            FloorEnum::turboLower => {
+                floor.direction = -1;
+                floor.speed = FLOORSPEED * 4.0;
+                floor.floordestheight = find_highest_floor_surrounding(&sec);
+                #[allow(clippy::float_cmp)]
+                if floor.floordestheight != sec.floorheight {
+                    floor.floordestheight += 8.0;
+                }
+            }
+            FloorEnum::raiseFloorCrush | FloorEnum::raiseFloor => {
+                floor.crush = kind == FloorEnum::raiseFloorCrush;
+                floor.floordestheight = find_lowest_ceiling_surrounding(&sec);
+                if floor.floordestheight > sec.ceilingheight {
+                    floor.floordestheight = sec.ceilingheight;
+                }
+                if floor.crush {
+                    floor.floordestheight -= 8.0;
+                }
+            }
+            FloorEnum::raiseFloorTurbo => {
+                floor.speed = FLOORSPEED * 4.0;
+                floor.floordestheight =
+                    find_next_highest_floor(&sec, sec.floorheight);
+            }
+            FloorEnum::raiseFloorToNearest => {
+                floor.floordestheight =
+                    find_next_highest_floor(&sec, sec.floorheight);
+            }
+            FloorEnum::raiseFloor24 => {
+                floor.floordestheight = sec.floorheight + 24.0;
+            }
+            FloorEnum::raiseFloor512 => {
+                floor.floordestheight = sec.floorheight + 512.0;
+            }
+            FloorEnum::raiseFloor24AndChange => {
+                floor.floordestheight = sec.floorheight + 24.0;
+                sec.floorpic = line.frontsector.floorpic;
+                sec.special = line.frontsector.special;
+            }
+            FloorEnum::raiseToTexture => {
+                floor.floordestheight = sec.floorheight + lower_texture;
+            }
+            FloorEnum::lowerAndChange => {
+                floor.direction = -1;
+                floor.floordestheight = find_lowest_floor_surrounding(&sec);
+                floor.texture = sec.floorpic;
+
+                for line in sec.lines.iter() {
+                    if let Some(other) = get_next_sector(line, &sec) {
+                        #[allow(clippy::float_cmp)]
+                        if other.floorheight == floor.floordestheight {
+                            floor.texture = other.floorpic;
+                            floor.newspecial = other.special;
+                            break;
+                        }
+                    }
+                }
+            }
+            FloorEnum::donutRaise => {}
+        }
+    }
+    rtn
+}
+
+/// The height of the shortest lower texture on either side of the sector's
+/// two-sided lines, for `raiseToTexture`. As in vanilla "-" is texture 0 and
+/// is counted, and with no two-sided lines the height is `MAXINT`.
+fn shortest_lower_texture(sec: &Sector, level: &Level) -> f32 {
+    let mut minsize = i32::MAX as f32;
+    for line in sec.lines.iter() {
+        if line.flags & LineDefFlags::TwoSided as i16 == 0 {
+            continue;
+        }
+        let sides = Some(&line.front_sidedef)
+            .into_iter()
+            .chain(line.back_sidedef.as_ref());
+        for side in sides {
+            if side.bottomtexture >= 0 {
+                let height = level.map_data.texture_height(side.bottomtexture);
+                if height < minsize {
+                    minsize = height;
+                }
+            }
+        }
+    }
+    minsize
+}
+
+/// EV_BuildStairs
+///
+/// BUILD A STAIRCASE!
+pub(crate) fn ev_build_stairs(
+    line: DPtr<LineDef>,
+    kind: StairEnum,
+    level: &mut Level,
+) -> bool {
+    let (speed, stairsize) = match kind {
+        StairEnum::build8 => (FLOORSPEED / 4.0, 8.0),
+        StairEnum::turbo16 => (FLOORSPEED * 4.0, 16.0),
+    };
+
+    let mut rtn = false;
+    for mut sec in find_sectors_from_line_tag(&line, level) {
+        // ALREADY MOVING?  IF SO, KEEP GOING...
+        if sec.specialdata.is_some() {
+            continue;
+        }
+
+        // new floor thinker
+        rtn = true;
+        let mut height = sec.floorheight + stairsize;
+        let floor = add_floor(sec.clone(), FloorEnum::raiseFloor, level);
+        floor.speed = speed;
+        floor.floordestheight = height;
+
+        let texture = sec.floorpic;
+
+        // Find next sector to raise
+        // 1.	Find 2-sided line with same sector side[0]
+        // 2.	Other side is the next sector to raise
+        loop {
+            let mut next = None;
+            for line in sec.lines.iter() {
+                if line.flags & LineDefFlags::TwoSided as i16 == 0 {
+                    continue;
+                }
+                if line.frontsector.as_ptr() != sec.as_ptr() {
+                    continue;
+                }
+                let tsec = match line.backsector {
+                    Some(ref tsec) => tsec.clone(),
+                    None => continue,
+                };
+                if tsec.floorpic != texture {
+                    continue;
+                }
+
+                height += stairsize;
+
+                if tsec.specialdata.is_some() {
+                    continue;
+                }
+
+                next = Some(tsec);
+                break;
+            }
+
+            match next {
+                Some(tsec) => {
+                    sec = tsec;
+                    let floor =
+                        add_floor(sec.clone(), FloorEnum::raiseFloor, level);
+                    floor.speed = speed;
+                    floor.floordestheight = height;
+                }
+                None => break,
+            }
+        }
+    }
+    rtn
+}
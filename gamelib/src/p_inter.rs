@@ -0,0 +1,799 @@
+//!	Handling interactions (i.e., collisions).
+
+use std::f32::consts::PI;
+use std::ptr::NonNull;
+
+use crate::d_items::WEAPON_INFO;
+use crate::d_main::Skill;
+use crate::doom_def::{
+    AmmoType, Card, GameMode, PowerDuration, PowerType, WeaponType, CLIP_AMMO,
+};
+use crate::info::states::get_state;
+use crate::info::{MapObjectType, SpriteNum, StateNum};
+use crate::level_data::level::Level;
+use crate::p_local::{p_random, MAXHEALTH, ONFLOORZ};
+use crate::p_map_object::{MapObject, MapObjectFlag};
+use crate::p_player_sprite::p_drop_weapon;
+use crate::player::{Cheat, Player, PlayerState};
+use crate::renderer::bsp::point_to_angle_2;
+
+/// How much the bonus palette is shown for each item picked up
+pub(crate) const BONUSADD: i32 = 6;
+
+/// Follow a player exlusively for 3 seconds
+const BASETHRESHOLD: i32 = 100;
+
+/// The ammo types in `Player::ammo` order, for the backpack
+const AMMO_TYPES: [AmmoType; NUM_AMMO] = [
+    AmmoType::am_clip,
+    AmmoType::am_shell,
+    AmmoType::am_cell,
+    AmmoType::am_misl,
+];
+const NUM_AMMO: usize = AmmoType::NUMAMMO as usize;
+
+const GOTARMOR: &str = "Picked up the armor.";
+const GOTMEGA: &str = "Picked up the MegaArmor!";
+const GOTHTHBONUS: &str = "Picked up a health bonus.";
+const GOTARMBONUS: &str = "Picked up an armor bonus.";
+const GOTSTIM: &str = "Picked up a stimpack.";
+const GOTMEDINEED: &str = "Picked up a medikit that you REALLY need!";
+const GOTMEDIKIT: &str = "Picked up a medikit.";
+const GOTSUPER: &str = "Supercharge!";
+
+const GOTBLUECARD: &str = "Picked up a blue keycard.";
+const GOTYELWCARD: &str = "Picked up a yellow keycard.";
+const GOTREDCARD: &str = "Picked up a red keycard.";
+const GOTBLUESKUL: &str = "Picked up a blue skull key.";
+const GOTYELWSKUL: &str = "Picked up a yellow skull key.";
+const GOTREDSKULL: &str = "Picked up a red skull key.";
+
+const GOTINVUL: &str = "Invulnerability!";
+const GOTBERSERK: &str = "Berserk!";
+const GOTINVIS: &str = "Partial Invisibility";
+const GOTSUIT: &str = "Radiation Shielding Suit";
+const GOTMAP: &str = "Computer Area Map";
+const GOTVISOR: &str = "Light Amplification Visor";
+const GOTMSPHERE: &str = "MegaSphere!";
+
+const GOTCLIP: &str = "Picked up a clip.";
+const GOTCLIPBOX: &str = "Picked up a box of bullets.";
+const GOTROCKET: &str = "Picked up a rocket.";
+const GOTROCKBOX: &str = "Picked up a box of rockets.";
+const GOTCELL: &str = "Picked up an energy cell.";
+const GOTCELLBOX: &str = "Picked up an energy cell pack.";
+const GOTSHELLS: &str = "Picked up 4 shotgun shells.";
+const GOTSHELLBOX: &str = "Picked up a box of shotgun shells.";
+const GOTBACKPACK: &str = "Picked up a backpack full of ammo!";
+
+const GOTBFG9000: &str = "You got the BFG9000!  Oh, yes.";
+const GOTCHAINGUN: &str = "You got the chaingun!";
+const GOTCHAINSAW: &str = "A chainsaw!  Find some meat!";
+const GOTLAUNCHER: &str = "You got the rocket launcher!";
+const GOTPLASMA: &str = "You got the plasma gun!";
+const GOTSHOTGUN: &str = "You got the shotgun!";
+const GOTSHOTGUN2: &str = "You got the super shotgun!";
+
+/// P_GiveAmmo
+///
+/// `num` is the number of clip loads, not the individual count (0 = 1/2
+/// clip). Returns false if the ammo can't be picked up at all
+fn p_give_ammo(
+    player: &mut Player,
+    ammo: AmmoType,
+    num: u32,
+    skill: Skill,
+) -> bool {
+    if ammo as usize >= NUM_AMMO {
+        return false;
+    }
+    let ammo_num = ammo as usize;
+
+    if player.ammo[ammo_num] == player.maxammo[ammo_num] {
+        return false;
+    }
+
+    let mut num = if num != 0 {
+        num * CLIP_AMMO[ammo_num]
+    } else {
+        CLIP_AMMO[ammo_num] / 2
+    };
+
+    if skill == Skill::Baby || skill == Skill::Nightmare {
+        // give double ammo in trainer mode,
+        // you'll need in nightmare
+        num <<= 1;
+    }
+
+    let oldammo = player.ammo[ammo_num];
+    player.ammo[ammo_num] =
+        (player.ammo[ammo_num] + num).min(player.maxammo[ammo_num]);
+
+    // If non zero ammo,
+    // don't change up weapons,
+    // player was lower on purpose.
+    if oldammo != 0 {
+        return true;
+    }
+
+    // We were down to zero,
+    // so select a new weapon.
+    // Preferences are not user selectable.
+    let ready = player.readyweapon as usize;
+    let fist = WeaponType::wp_fist as usize;
+    let pistol = WeaponType::wp_pistol as usize;
+    let owned = |weapon: WeaponType| player.weaponowned[weapon as usize];
+    match ammo {
+        AmmoType::am_clip if ready == fist => {
+            player.pendingweapon = if owned(WeaponType::wp_chaingun) {
+                WeaponType::wp_chaingun
+            } else {
+                WeaponType::wp_pistol
+            };
+        }
+        AmmoType::am_shell
+            if (ready == fist || ready == pistol)
+                && owned(WeaponType::wp_shotgun) =>
+        {
+            player.pendingweapon = WeaponType::wp_shotgun;
+        }
+        AmmoType::am_cell
+            if (ready == fist || ready == pistol)
+                && owned(WeaponType::wp_plasma) =>
+        {
+            player.pendingweapon = WeaponType::wp_plasma;
+        }
+        AmmoType::am_misl if ready == fist && owned(WeaponType::wp_missile) => {
+            player.pendingweapon = WeaponType::wp_missile;
+        }
+        _ => {}
+    }
+    true
+}
+
+/// P_GiveWeapon
+///
+/// A `dropped` weapon gives one clip of ammo instead of two.
+fn p_give_weapon(
+    player: &mut Player,
+    weapon: WeaponType,
+    dropped: bool,
+    skill: Skill,
+) -> bool {
+    // TODO: in netgames other than deathmatch 2.0 weapons are left in place,
+    //  and give 5 clips (2 in deathmatch) if not already owned
+
+    let ammo = WEAPON_INFO[weapon as usize].ammo;
+    let gaveammo = if ammo as usize != AmmoType::am_noammo as usize {
+        // give one clip with a dropped weapon,
+        // two clips with a found weapon
+        p_give_ammo(player, ammo, if dropped { 1 } else { 2 }, skill)
+    } else {
+        false
+    };
+
+    let gaveweapon = if player.weaponowned[weapon as usize] {
+        false
+    } else {
+        player.weaponowned[weapon as usize] = true;
+        player.pendingweapon = weapon;
+        true
+    };
+
+    gaveweapon || gaveammo
+}
+
+/// P_GiveBody
+///
+/// Returns false if the body isn't needed at all. The caller keeps the
+/// player's map object health in step.
+fn p_give_body(player: &mut Player, num: i32) -> bool {
+    if player.health >= MAXHEALTH {
+        return false;
+    }
+    player.health = (player.health + num).min(MAXHEALTH);
+    true
+}
+
+/// P_GiveArmor
+///
+/// Returns false if the armor is worse than the current armor.
+fn p_give_armor(player: &mut Player, armortype: i32) -> bool {
+    let hits = armortype * 100;
+    if player.armorpoints >= hits {
+        return false; // don't pick up
+    }
+    player.armortype = armortype;
+    player.armorpoints = hits;
+    true
+}
+
+/// P_GiveCard
+fn p_give_card(player: &mut Player, card: Card, message: &str) {
+    if player.cards[card as usize] {
+        return;
+    }
+    player.message = Some(message.to_owned());
+    player.bonuscount = BONUSADD;
+    player.cards[card as usize] = true;
+}
+
+/// P_GivePower
+///
+/// `mobj` is the player's map object, invisibility makes it a shadow
+fn p_give_power(
+    player: &mut Player,
+    mobj: &mut MapObject,
+    power: PowerType,
+) -> bool {
+    let duration = match power {
+        PowerType::pw_invulnerability => PowerDuration::INVULNTICS,
+        PowerType::pw_invisibility => {
+            mobj.flags |= MapObjectFlag::MF_SHADOW as u32;
+            PowerDuration::INVISTICS
+        }
+        PowerType::pw_infrared => PowerDuration::INFRATICS,
+        PowerType::pw_ironfeet => PowerDuration::IRONTICS,
+        PowerType::pw_strength => {
+            p_give_body(player, 100);
+            player.powers[power as usize] = 1;
+            return true;
+        }
+        _ => {
+            if player.powers[power as usize] != 0 {
+                return false; // already got it
+            }
+            player.powers[power as usize] = 1;
+            return true;
+        }
+    };
+    player.powers[power as usize] = duration as i32;
+    true
+}
+
+impl MapObject {
+    /// P_TouchSpecialThing
+    ///
+    /// `self` is the special being touched by `toucher`
+    pub(crate) fn p_touch_special_thing(
+        &mut self,
+        toucher: &mut MapObject,
+        level: &mut Level,
+    ) {
+        let delta = self.z - toucher.z;
+        if delta > toucher.height || delta < -8.0 {
+            // out of reach
+            return;
+        }
+
+        // Dead thing touching.
+        // Can happen with a sliding player corpse.
+        if toucher.health <= 0 {
+            return;
+        }
+
+        let mut player = match toucher.player {
+            Some(player) => player,
+            None => return,
+        };
+        let player = unsafe { player.as_mut() };
+        let skill = level.game_skill;
+        let dropped = self.flags & MapObjectFlag::MF_DROPPED as u32 != 0;
+
+        // Identify by sprite.
+        match self.sprite {
+            // armor
+            SpriteNum::SPR_ARM1 => {
+                if !p_give_armor(player, 1) {
+                    return;
+                }
+                player.message = Some(GOTARMOR.to_owned());
+            }
+            SpriteNum::SPR_ARM2 => {
+                if !p_give_armor(player, 2) {
+                    return;
+                }
+                player.message = Some(GOTMEGA.to_owned());
+            }
+            // bonus items
+            SpriteNum::SPR_BON1 => {
+                // can go over 100%
+                player.health = (player.health + 1).min(200);
+                player.message = Some(GOTHTHBONUS.to_owned());
+            }
+            SpriteNum::SPR_BON2 => {
+                // can go over 100%
+                player.armorpoints = (player.armorpoints + 1).min(200);
+                if player.armortype == 0 {
+                    player.armortype = 1;
+                }
+                player.message = Some(GOTARMBONUS.to_owned());
+            }
+            SpriteNum::SPR_SOUL => {
+                player.health = (player.health + 100).min(200);
+                player.message = Some(GOTSUPER.to_owned());
+                // TODO: sound = sfx_getpow;
+            }
+            SpriteNum::SPR_MEGA => {
+                if level.game_mode != GameMode::Commercial {
+                    return;
+                }
+                player.health = 200;
+                p_give_armor(player, 2);
+                player.message = Some(GOTMSPHERE.to_owned());
+                // TODO: sound = sfx_getpow;
+            }
+            // cards
+            // TODO: leave cards for everyone in a netgame
+            SpriteNum::SPR_BKEY => {
+                p_give_card(player, Card::it_bluecard, GOTBLUECARD)
+            }
+            SpriteNum::SPR_YKEY => {
+                p_give_card(player, Card::it_yellowcard, GOTYELWCARD)
+            }
+            SpriteNum::SPR_RKEY => {
+                p_give_card(player, Card::it_redcard, GOTREDCARD)
+            }
+            SpriteNum::SPR_BSKU => {
+                p_give_card(player, Card::it_blueskull, GOTBLUESKUL)
+            }
+            SpriteNum::SPR_YSKU => {
+                p_give_card(player, Card::it_yellowskull, GOTYELWSKUL)
+            }
+            SpriteNum::SPR_RSKU => {
+                p_give_card(player, Card::it_redskull, GOTREDSKULL)
+            }
+            // medikits, heals
+            SpriteNum::SPR_STIM => {
+                if !p_give_body(player, 10) {
+                    return;
+                }
+                player.message = Some(GOTSTIM.to_owned());
+            }
+            SpriteNum::SPR_MEDI => {
+                if !p_give_body(player, 25) {
+                    return;
+                }
+                // The health is checked after it was given, so this
+                // message is never shown. Kept as vanilla.
+                if player.health < 25 {
+                    player.message = Some(GOTMEDINEED.to_owned());
+                } else {
+                    player.message = Some(GOTMEDIKIT.to_owned());
+                }
+            }
+            // power ups
+            SpriteNum::SPR_PINV => {
+                if !p_give_power(player, toucher, PowerType::pw_invulnerability)
+                {
+                    return;
+                }
+                player.message = Some(GOTINVUL.to_owned());
+                // TODO: sound = sfx_getpow;
+            }
+            SpriteNum::SPR_PSTR => {
+                if !p_give_power(player, toucher, PowerType::pw_strength) {
+                    return;
+                }
+                player.message = Some(GOTBERSERK.to_owned());
+                if player.readyweapon as usize != WeaponType::wp_fist as usize {
+                    player.pendingweapon = WeaponType::wp_fist;
+                }
+                // TODO: sound = sfx_getpow;
+            }
+            SpriteNum::SPR_PINS => {
+                if !p_give_power(player, toucher, PowerType::pw_invisibility) {
+                    return;
+                }
+                player.message = Some(GOTINVIS.to_owned());
+                // TODO: sound = sfx_getpow;
+            }
+            SpriteNum::SPR_SUIT => {
+                if !p_give_power(player, toucher, PowerType::pw_ironfeet) {
+                    return;
+                }
+                player.message = Some(GOTSUIT.to_owned());
+                // TODO: sound = sfx_getpow;
+            }
+            SpriteNum::SPR_PMAP => {
+                if !p_give_power(player, toucher, PowerType::pw_allmap) {
+                    return;
+                }
+                player.message = Some(GOTMAP.to_owned());
+                // TODO: sound = sfx_getpow;
+            }
+            SpriteNum::SPR_PVIS => {
+                if !p_give_power(player, toucher, PowerType::pw_infrared) {
+                    return;
+                }
+                player.message = Some(GOTVISOR.to_owned());
+                // TODO: sound = sfx_getpow;
+            }
+            // ammo
+            SpriteNum::SPR_CLIP => {
+                let num = if dropped { 0 } else { 1 };
+                if !p_give_ammo(player, AmmoType::am_clip, num, skill) {
+                    return;
+                }
+                player.message = Some(GOTCLIP.to_owned());
+            }
+            SpriteNum::SPR_AMMO => {
+                if !p_give_ammo(player, AmmoType::am_clip, 5, skill) {
+                    return;
+                }
+                player.message = Some(GOTCLIPBOX.to_owned());
+            }
+            SpriteNum::SPR_ROCK => {
+                if !p_give_ammo(player, AmmoType::am_misl, 1, skill) {
+                    return;
+                }
+                player.message = Some(GOTROCKET.to_owned());
+            }
+            SpriteNum::SPR_BROK => {
+                if !p_give_ammo(player, AmmoType::am_misl, 5, skill) {
+                    return;
+                }
+                player.message = Some(GOTROCKBOX.to_owned());
+            }
+            SpriteNum::SPR_CELL => {
+                if !p_give_ammo(player, AmmoType::am_cell, 1, skill) {
+                    return;
+                }
+                player.message = Some(GOTCELL.to_owned());
+            }
+            SpriteNum::SPR_CELP => {
+                if !p_give_ammo(player, AmmoType::am_cell, 5, skill) {
+                    return;
+                }
+                player.message = Some(GOTCELLBOX.to_owned());
+            }
+            SpriteNum::SPR_SHEL => {
+                if !p_give_ammo(player, AmmoType::am_shell, 1, skill) {
+                    return;
+                }
+                player.message = Some(GOTSHELLS.to_owned());
+            }
+            SpriteNum::SPR_SBOX => {
+                if !p_give_ammo(player, AmmoType::am_shell, 5, skill) {
+                    return;
+                }
+                player.message = Some(GOTSHELLBOX.to_owned());
+            }
+            SpriteNum::SPR_BPAK => {
+                if !player.backpack {
+                    for max in player.maxammo.iter_mut() {
+                        *max *= 2;
+                    }
+                    player.backpack = true;
+                }
+                for ammo in AMMO_TYPES.iter() {
+                    p_give_ammo(player, *ammo, 1, skill);
+                }
+                player.message = Some(GOTBACKPACK.to_owned());
+            }
+            // weapons
+            SpriteNum::SPR_BFUG => {
+                if !p_give_weapon(player, WeaponType::wp_bfg, false, skill) {
+                    return;
+                }
+                player.message = Some(GOTBFG9000.to_owned());
+                // TODO: sound = sfx_wpnup;
+            }
+            SpriteNum::SPR_MGUN => {
+                if !p_give_weapon(
+                    player,
+                    WeaponType::wp_chaingun,
+                    dropped,
+                    skill,
+                ) {
+                    return;
+                }
+                player.message = Some(GOTCHAINGUN.to_owned());
+                // TODO: sound = sfx_wpnup;
+            }
+            SpriteNum::SPR_CSAW => {
+                if !p_give_weapon(player, WeaponType::wp_chainsaw, false, skill)
+                {
+                    return;
+                }
+                player.message = Some(GOTCHAINSAW.to_owned());
+                // TODO: sound = sfx_wpnup;
+            }
+            SpriteNum::SPR_LAUN => {
+                if !p_give_weapon(player, WeaponType::wp_missile, false, skill)
+                {
+                    return;
+                }
+                player.message = Some(GOTLAUNCHER.to_owned());
+                // TODO: sound = sfx_wpnup;
+            }
+            SpriteNum::SPR_PLAS => {
+                if !p_give_weapon(player, WeaponType::wp_plasma, false, skill) {
+                    return;
+                }
+                player.message = Some(GOTPLASMA.to_owned());
+                // TODO: sound = sfx_wpnup;
+            }
+            SpriteNum::SPR_SHOT => {
+                if !p_give_weapon(
+                    player,
+                    WeaponType::wp_shotgun,
+                    dropped,
+                    skill,
+                ) {
+                    return;
+                }
+                player.message = Some(GOTSHOTGUN.to_owned());
+                // TODO: sound = sfx_wpnup;
+            }
+            SpriteNum::SPR_SGN2 => {
+                if !p_give_weapon(
+                    player,
+                    WeaponType::wp_supershotgun,
+                    dropped,
+                    skill,
+                ) {
+                    return;
+                }
+                player.message = Some(GOTSHOTGUN2.to_owned());
+                // TODO: sound = sfx_wpnup;
+            }
+            _ => {
+                println!("P_SpecialThing: Unknown gettable thing");
+                return;
+            }
+        }
+        // The player's map object shares the player's health
+        toucher.health = player.health;
+
+        if self.flags & MapObjectFlag::MF_COUNTITEM as u32 != 0 {
+            player.itemcount += 1;
+        }
+        self.p_remove_mobj(level);
+        player.bonuscount += BONUSADD;
+        // TODO: if (player == &players[consoleplayer]) S_StartSound (NULL, sound);
+    }
+
+    /// P_KillMobj
+    ///
+    /// `self` is the target, killed by `source` which is `None` for sector
+    /// damage and crushers
+    fn p_kill_mobj(
+        &mut self,
+        source: Option<NonNull<MapObject>>,
+        level: &mut Level,
+    ) {
+        self.flags &= !(MapObjectFlag::MF_SHOOTABLE as u32
+            | MapObjectFlag::MF_FLOAT as u32
+            | MapObjectFlag::MF_SKULLFLY as u32);
+
+        if self.kind != MapObjectType::MT_SKULL as u16 {
+            self.flags &= !(MapObjectFlag::MF_NOGRAVITY as u32);
+        }
+
+        self.flags |=
+            MapObjectFlag::MF_CORPSE as u32 | MapObjectFlag::MF_DROPOFF as u32;
+        self.height /= 4.0;
+
+        let source_player =
+            source.and_then(|source| unsafe { source.as_ref().player });
+        if let Some(mut player) = source_player {
+            // count for intermission
+            if self.flags & MapObjectFlag::MF_COUNTKILL as u32 != 0 {
+                unsafe { player.as_mut().killcount += 1 };
+            }
+            // TODO: if (target->player) source->player->frags[target->player-players]++;
+        } else if self.flags & MapObjectFlag::MF_COUNTKILL as u32 != 0 {
+            // TODO: count all monster deaths, even those caused by other
+            //  monsters, players[0].killcount++ if !netgame
+        }
+
+        if let Some(mut player) = self.player {
+            let player = unsafe { player.as_mut() };
+            // TODO: count environment kills against you, frags
+            self.flags &= !(MapObjectFlag::MF_SOLID as u32);
+            player.player_state = PlayerState::PstDead;
+            p_drop_weapon(player, level);
+            // TODO: AM_Stop() if automap is active for the consoleplayer
+        }
+
+        if self.health < -self.info.spawnhealth
+            && self.info.xdeathstate as usize != StateNum::S_NULL as usize
+        {
+            self.p_set_mobj_state(self.info.xdeathstate, level);
+        } else {
+            self.p_set_mobj_state(self.info.deathstate, level);
+        }
+        self.tics -= (p_random() & 3) as i32;
+        if self.tics < 1 {
+            self.tics = 1;
+        }
+
+        // Drop stuff.
+        // This determines the kind of object spawned
+        // during the death frame of a thing.
+        let item = if self.kind == MapObjectType::MT_WOLFSS as u16
+            || self.kind == MapObjectType::MT_POSSESSED as u16
+        {
+            MapObjectType::MT_CLIP
+        } else if self.kind == MapObjectType::MT_SHOTGUY as u16 {
+            MapObjectType::MT_SHOTGUN
+        } else if self.kind == MapObjectType::MT_CHAINGUY as u16 {
+            MapObjectType::MT_CHAINGUN
+        } else {
+            return;
+        };
+
+        let mut mo = MapObject::p_spawn_map_object(
+            self.xy.x(),
+            self.xy.y(),
+            ONFLOORZ,
+            item as u16,
+            level,
+        );
+        // special versions of items
+        mo.obj.flags |= MapObjectFlag::MF_DROPPED as u32;
+        if level.add_thinker(mo).is_none() {
+            println!("P_KillMobj: no room for another thinker");
+        }
+    }
+
+    /// P_DamageMobj
+    ///
+    /// Damages both enemies and players. `self` is the target.
+    ///
+    /// `inflictor` is the thing that caused the damage, creature or missile,
+    /// and can be `None` for slime, barrel explosions and other environmental
+    /// stuff. `source` is the thing to target after taking damage, a
+    /// creature or `None`.
+    ///
+    /// Source and inflictor are the same for melee attacks. Source can be
+    /// `None` for slime, barrel explosions and other environmental stuff.
+    pub(crate) fn p_damage_mobj(
+        &mut self,
+        inflictor: Option<NonNull<MapObject>>,
+        source: Option<NonNull<MapObject>>,
+        mut damage: i32,
+        level: &mut Level,
+    ) {
+        if self.flags & MapObjectFlag::MF_SHOOTABLE as u32 == 0 {
+            // shouldn't happen...
+            return;
+        }
+
+        if self.health <= 0 {
+            return;
+        }
+
+        if self.flags & MapObjectFlag::MF_SKULLFLY as u32 != 0 {
+            self.momxy = Default::default();
+            self.momz = 0.0;
+        }
+
+        if self.player.is_some() && level.game_skill == Skill::Baby {
+            // take half damage in trainer mode
+            damage >>= 1;
+        }
+
+        // Some close combat weapons should not
+        // inflict thrust and push the victim out of reach,
+        // thus kick away unless using the chainsaw.
+        if let Some(inflictor) = inflictor {
+            let chainsaw = source.map_or(false, |source| unsafe {
+                source.as_ref().player.map_or(false, |player| {
+                    player.as_ref().readyweapon as usize
+                        == WeaponType::wp_chainsaw as usize
+                })
+            });
+            if self.flags & MapObjectFlag::MF_NOCLIP as u32 == 0 && !chainsaw {
+                let inflictor = unsafe { inflictor.as_ref() };
+                let mut angle = point_to_angle_2(&self.xy, &inflictor.xy);
+                let mut thrust = damage as f32 * 12.5 / self.info.mass as f32;
+
+                // make fall forwards sometimes
+                if damage < 40
+                    && damage > self.health
+                    && self.z - inflictor.z > 64.0
+                    && p_random() & 1 != 0
+                {
+                    angle += PI;
+                    thrust *= 4.0;
+                }
+
+                self.momxy += angle.unit() * thrust;
+            }
+        }
+
+        // player specific
+        if let Some(mut player) = self.player {
+            let player = unsafe { player.as_mut() };
+            // end of game hell hack
+            if self.subsector.sector.special == 11 && damage >= self.health {
+                damage = self.health - 1;
+            }
+
+            // Below certain threshold,
+            // ignore damage in GOD mode, or with INVUL power.
+            if damage < 1000
+                && (player.cheats & Cheat::Godmode as i32 != 0
+                    || player.powers[PowerType::pw_invulnerability as usize]
+                        != 0)
+            {
+                return;
+            }
+
+            if player.armortype != 0 {
+                let mut saved = if player.armortype == 1 {
+                    damage / 3
+                } else {
+                    damage / 2
+                };
+
+                if player.armorpoints <= saved {
+                    // armor is used up
+                    saved = player.armorpoints;
+                    player.armortype = 0;
+                }
+                player.armorpoints -= saved;
+                damage -= saved;
+            }
+            // mirror mobj health here for Dave
+            player.health -= damage;
+            if player.health < 0 {
+                player.health = 0;
+            }
+
+            // TODO: player->attacker = source;
+            // add damage after armor / invuln
+            player.damagecount += damage;
+            // teleport stomp does 10k points...
+            if player.damagecount > 100 {
+                player.damagecount = 100;
+            }
+            // TODO: I_Tactile (40,10,40+temp*2);
+        }
+
+        // do the damage
+        self.health -= damage;
+        if self.health <= 0 {
+            self.p_kill_mobj(source, level);
+            return;
+        }
+
+        if (p_random() as i32) < self.info.painchance
+            && self.flags & MapObjectFlag::MF_SKULLFLY as u32 == 0
+        {
+            // fight back!
+            self.flags |= MapObjectFlag::MF_JUSTHIT as u32;
+            self.p_set_mobj_state(self.info.painstate, level);
+        }
+
+        // we're awake now...
+        self.reactiontime = 0;
+
+        let is_vile = |kind: u16| kind == MapObjectType::MT_VILE as u16;
+        if let Some(source_ptr) = source {
+            let source = unsafe { source_ptr.as_ref() };
+            if (self.threshold == 0 || is_vile(self.kind))
+                && !std::ptr::eq(source, self)
+                && !is_vile(source.kind)
+            {
+                // if not intent on another player,
+                // chase after this one
+                self.target = Some(source_ptr);
+                self.threshold = BASETHRESHOLD;
+                // The state is a copy, so compare it with what the spawn
+                // state is made of
+                let spawn = get_state(self.info.spawnstate as usize);
+                if self.state.sprite as usize == spawn.sprite as usize
+                    && self.state.frame == spawn.frame
+                    && self.state.next_state as usize
+                        == spawn.next_state as usize
+                    && self.info.seestate as usize != StateNum::S_NULL as usize
+                {
+                    self.p_set_mobj_state(self.info.seestate, level);
+                }
+            }
+        }
+    }
+}
@@ -1,30 +1,272 @@
+//!	Handle Sector base lighting effects.
+
+use std::ptr::NonNull;
+
+use crate::d_thinker::{Think, Thinker};
 use crate::level_data::level::Level;
-use crate::{d_thinker::Think, p_spec::*};
+use crate::level_data::map_defs::{LineDef, Sector};
+use crate::p_local::p_random;
+use crate::p_spec::{
+    find_sectors_from_line_tag, get_next_sector, FireFlicker, Glow, LightFlash,
+    Strobe,
+};
+use crate::DPtr;
+
+const GLOWSPEED: i16 = 8;
+const STROBEBRIGHT: i32 = 5;
+pub(crate) const FASTDARK: i32 = 15;
+pub(crate) const SLOWDARK: i32 = 35;
 
+/// T_FireFlicker
 impl Think for FireFlicker {
-    fn think(&mut self, level: &mut Level) -> bool { false }
+    fn think(&mut self, _level: &mut Level) -> bool {
+        self.count -= 1;
+        if self.count != 0 {
+            return false;
+        }
+
+        let amount = (p_random() & 3) as i32 * 16;
+        if self.sector.lightlevel as i32 - amount < self.min_light {
+            self.sector.lightlevel = self.min_light as i16;
+        } else {
+            self.sector.lightlevel = (self.max_light - amount) as i16;
+        }
+
+        self.count = 4;
+        false
+    }
 }
 
+/// T_LightFlash
+/// Do flashing lights.
 impl Think for LightFlash {
-    fn think(&mut self, level: &mut Level) -> bool { false }
+    fn think(&mut self, _level: &mut Level) -> bool {
+        self.count -= 1;
+        if self.count != 0 {
+            return false;
+        }
+
+        if self.sector.lightlevel as i32 == self.max_light {
+            self.sector.lightlevel = self.min_light as i16;
+            self.count = (p_random() as i32 & self.min_time) + 1;
+        } else {
+            self.sector.lightlevel = self.max_light as i16;
+            self.count = (p_random() as i32 & self.max_time) + 1;
+        }
+        false
+    }
 }
 
+/// T_StrobeFlash
 impl Think for Strobe {
-    fn think(&mut self, level: &mut Level) -> bool { false }
+    fn think(&mut self, _level: &mut Level) -> bool {
+        self.count -= 1;
+        if self.count != 0 {
+            return false;
+        }
+
+        if self.sector.lightlevel as i32 == self.min_light {
+            self.sector.lightlevel = self.max_light as i16;
+            self.count = self.bright_time;
+        } else if self.sector.lightlevel as i32 == self.max_light {
+            self.sector.lightlevel = self.min_light as i16;
+            self.count = self.dark_time;
+        }
+        false
+    }
 }
 
+/// T_Glow
+/// Spawn glowing light
 impl Think for Glow {
-    fn think(&mut self, level: &mut Level) -> bool { false }
+    fn think(&mut self, _level: &mut Level) -> bool {
+        match self.direction {
+            -1 => {
+                // DOWN
+                self.sector.lightlevel -= GLOWSPEED;
+                if self.sector.lightlevel as i32 <= self.min_light {
+                    self.sector.lightlevel += GLOWSPEED;
+                    self.direction = 1;
+                }
+            }
+            1 => {
+                // UP
+                self.sector.lightlevel += GLOWSPEED;
+                if self.sector.lightlevel as i32 >= self.max_light {
+                    self.sector.lightlevel -= GLOWSPEED;
+                    self.direction = -1;
+                }
+            }
+            _ => {}
+        }
+        false
+    }
+}
+
+/// P_FindMinSurroundingLight
+fn find_min_surrounding_light(sector: &Sector, max: i32) -> i32 {
+    let mut min = max;
+    for line in &sector.lines {
+        if let Some(check) = get_next_sector(line, sector) {
+            if (check.lightlevel as i32) < min {
+                min = check.lightlevel as i32;
+            }
+        }
+    }
+    min
+}
+
+/// P_SpawnFireFlicker
+pub(crate) fn p_spawn_fire_flicker(
+    mut sector: DPtr<Sector>,
+    level: &mut Level,
+) {
+    // Note that we are resetting sector attributes.
+    // Nothing special about it during gameplay.
+    sector.special = 0;
+
+    let max_light = sector.lightlevel as i32;
+    let min_light = find_min_surrounding_light(&sector, max_light) + 16;
+    let mut thinker = Box::new(Thinker::new(FireFlicker {
+        thinker: None,
+        sector,
+        count: 4,
+        max_light,
+        min_light,
+    }));
+    thinker.obj.thinker = Some(NonNull::from(&mut *thinker));
+    level.fire_flickers.push(thinker);
+}
+
+/// P_SpawnLightFlash
+/// After the map has been loaded, scan each sector
+/// for specials that spawn thinkers
+pub(crate) fn p_spawn_light_flash(mut sector: DPtr<Sector>, level: &mut Level) {
+    // nothing special about it during gameplay
+    sector.special = 0;
+
+    let max_light = sector.lightlevel as i32;
+    let min_light = find_min_surrounding_light(&sector, max_light);
+    let max_time = 64;
+    let mut thinker = Box::new(Thinker::new(LightFlash {
+        thinker: None,
+        sector,
+        count: (p_random() as i32 & max_time) + 1,
+        max_light,
+        min_light,
+        max_time,
+        min_time: 7,
+    }));
+    thinker.obj.thinker = Some(NonNull::from(&mut *thinker));
+    level.light_flashes.push(thinker);
+}
+
+/// P_SpawnStrobeFlash
+/// After the map has been loaded, scan each sector
+/// for specials that spawn thinkers
+pub(crate) fn p_spawn_strobe_flash(
+    mut sector: DPtr<Sector>,
+    fast_or_slow: i32,
+    in_sync: bool,
+    level: &mut Level,
+) {
+    let max_light = sector.lightlevel as i32;
+    let mut min_light = find_min_surrounding_light(&sector, max_light);
+    if min_light == max_light {
+        min_light = 0;
+    }
+
+    // nothing special about it during gameplay
+    sector.special = 0;
+
+    let count = if !in_sync {
+        (p_random() & 7) as i32 + 1
+    } else {
+        1
+    };
+    let mut thinker = Box::new(Thinker::new(Strobe {
+        thinker: None,
+        sector,
+        count,
+        min_light,
+        max_light,
+        dark_time: fast_or_slow,
+        bright_time: STROBEBRIGHT,
+    }));
+    thinker.obj.thinker = Some(NonNull::from(&mut *thinker));
+    level.strobes.push(thinker);
+}
+
+/// P_SpawnGlowingLight
+pub(crate) fn p_spawn_glowing_light(
+    mut sector: DPtr<Sector>,
+    level: &mut Level,
+) {
+    let max_light = sector.lightlevel as i32;
+    let min_light = find_min_surrounding_light(&sector, max_light);
+    sector.special = 0;
+
+    let mut thinker = Box::new(Thinker::new(Glow {
+        thinker: None,
+        sector,
+        min_light,
+        max_light,
+        direction: -1,
+    }));
+    thinker.obj.thinker = Some(NonNull::from(&mut *thinker));
+    level.glows.push(thinker);
 }
 
-impl Think for Platform {
-    fn think(&mut self, level: &mut Level) -> bool { false }
+/// EV_StartLightStrobing
+///
+/// Start strobing lights (usually from a trigger)
+pub(crate) fn ev_start_light_strobing(line: DPtr<LineDef>, level: &mut Level) {
+    for sector in find_sectors_from_line_tag(&line, level) {
+        if sector.specialdata.is_some() {
+            continue;
+        }
+        p_spawn_strobe_flash(sector, SLOWDARK, false, level);
+    }
 }
 
-impl Think for FloorMove {
-    fn think(&mut self, level: &mut Level) -> bool { false }
+/// EV_TurnTagLightsOff
+///
+/// TURN LINE'S TAG LIGHTS OFF
+pub(crate) fn ev_turn_tag_lights_off(line: DPtr<LineDef>, level: &mut Level) {
+    for mut sector in find_sectors_from_line_tag(&line, level) {
+        let mut min = sector.lightlevel;
+        for line in &sector.lines {
+            if let Some(tsec) = get_next_sector(line, &sector) {
+                if tsec.lightlevel < min {
+                    min = tsec.lightlevel;
+                }
+            }
+        }
+        sector.lightlevel = min;
+    }
 }
 
-impl Think for CeilingMove {
-    fn think(&mut self, level: &mut Level) -> bool { false }
+/// EV_LightTurnOn
+///
+/// TURN LINE'S TAG LIGHTS ON. A `bright` of 0 uses the brightest neighbour.
+pub(crate) fn ev_light_turn_on(
+    line: DPtr<LineDef>,
+    mut bright: i32,
+    level: &mut Level,
+) {
+    for mut sector in find_sectors_from_line_tag(&line, level) {
+        // bright = 0 means to search
+        // for highest light level
+        // surrounding sector
+        if bright == 0 {
+            for line in &sector.lines {
+                if let Some(temp) = get_next_sector(line, &sector) {
+                    if temp.lightlevel as i32 > bright {
+                        bright = temp.lightlevel as i32;
+                    }
+                }
+            }
+        }
+        sector.lightlevel = bright as i16;
+    }
 }
@@ -8,6 +8,11 @@ pub static VIEWHEIGHT: f32 = 41.0;
 
 pub static MAXRADIUS: f32 = 32.0;
 
+/// Range of melee attacks
+pub const MELEERANGE: f32 = 64.0;
+/// Range of hitscan attacks
+pub const MISSILERANGE: f32 = 32.0 * 64.0;
+
 /// The Doom `FRACUNIT` is `1 << FRACBITS`
 pub const FRACUNIT: f32 = 65536.0; //(1 << FRACBITS) as f32;
 
@@ -47,14 +52,21 @@ pub const RNDTABLE: [u32; 256] = [
 
 pub fn p_random() -> u32 {
     unsafe {
-        PRNDINDEX = (RNDTABLE[PRNDINDEX + 1] & 0xFF) as usize;
+        PRNDINDEX = (PRNDINDEX + 1) & 0xFF;
         RNDTABLE[PRNDINDEX] as u32
     }
 }
 
+/// `(P_Random() - P_Random()) << shift` as an angle in radians, used to
+/// spread shots
+pub fn random_spread(shift: u32) -> f32 {
+    let bam = (p_random() as i32 - p_random() as i32) << shift;
+    bam as f32 * std::f32::consts::PI / 2147483648.0
+}
+
 pub fn m_random() -> u32 {
     unsafe {
-        RNDINDEX = (RNDTABLE[RNDINDEX + 1] & 0xFF) as usize;
+        RNDINDEX = (RNDINDEX + 1) & 0xFF;
         RNDTABLE[RNDINDEX] as u32
     }
 }
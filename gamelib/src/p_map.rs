@@ -1,19 +1,28 @@
 //!	Movement, collision handling.
 //!	Shooting and aiming.
+use std::ptr::NonNull;
+
 use glam::Vec2;
 
+use crate::angle::Angle;
 use crate::flags::LineDefFlags;
+use crate::info::{MapObjectType, StateNum};
 use crate::level_data::level::Level;
 use crate::level_data::map_data::MapData;
-use crate::level_data::map_defs::{BBox, LineDef, SubSector};
-use crate::p_local::MAXRADIUS;
+use crate::level_data::map_defs::{BBox, LineDef, Sector, SubSector};
+use crate::p_local::{p_random, MAXRADIUS};
 use crate::p_map_object::{MapObject, MapObjectFlag, MAXMOVE};
 use crate::p_map_util::{
-    circle_to_seg_intersect, unit_vec_from, LineContact, PortalZ,
+    box_on_line_side, circle_to_seg_intersect, path_traverse, unit_vec_from,
+    InterceptKind, LineContact, PortalZ, PT_ADDLINES, PT_ADDTHINGS,
 };
+use crate::p_spec::{p_cross_special_line, p_shoot_special_line};
+use crate::p_switch::p_use_special_line;
 use crate::DPtr;
 
 const MAXSPECIALCROSS: i32 = 8;
+/// How far a player can reach to use a line
+const USERANGE: f32 = 64.0;
 
 /// The pupose of this struct is to record the highest and lowest points in a
 /// subsector. When a mob crosses a seg it may be between floor/ceiling heights.
@@ -89,6 +98,7 @@ impl MapObject {
     pub fn p_try_move(&mut self, level: &mut Level) {
         // P_CrossSpecialLine
         level.mobj_ctrl.floatok = false;
+        level.mobj_ctrl.spec_hits.clear();
 
         let ctrl = &mut level.mobj_ctrl;
         // TODO: ceilingline = NULL;
@@ -105,13 +115,45 @@ impl MapObject {
         }
 
         // Check things first, possibly picking things up.
-        // TODO: P_BlockThingsIterator, PIT_CheckThing
+        // This is effectively P_BlockThingsIterator, PIT_CheckThing
+        let mv_xy = self.xy + self.momxy;
+        let reach = Vec2::new(self.radius + MAXRADIUS, self.radius + MAXRADIUS);
+        for mut thing in
+            level.blockmap.things_in_box(mv_xy - reach, mv_xy + reach)
+        {
+            if thing.as_ptr() as *const MapObject == self {
+                continue;
+            }
+            let thing = unsafe { thing.as_mut() };
+            let skull_fly = self.flags & MapObjectFlag::MF_SKULLFLY as u32 != 0;
+            if self.pit_check_thing(thing, level) {
+                continue;
+            }
+            if skull_fly {
+                // The slam stopped the skull
+                return;
+            }
+            if self.flags & MapObjectFlag::MF_MISSILE as u32 != 0 {
+                self.p_explode_missile(level);
+                return;
+            }
+            self.resolve_thing_contact(thing);
+        }
 
         // This is effectively P_BlockLinesIterator, PIT_CheckLine
+        let ctrl = &mut level.mobj_ctrl;
         let mv_ssect =
             level.map_data.point_in_subsector(&(self.xy + self.momxy));
         let contacts = self.get_contacts(&mv_ssect, ctrl, &level.map_data);
 
+        if !contacts.is_empty()
+            && self.flags & MapObjectFlag::MF_MISSILE as u32 != 0
+        {
+            // TODO: don't explode on the sky, vanilla removes the missile
+            self.p_explode_missile(level);
+            return;
+        }
+
         // TODO: find the most suitable contact to move with (wall sliding)
         if !contacts.is_empty() {
             if contacts[0].point_contacted.is_some() {
@@ -133,28 +175,440 @@ impl MapObject {
 
         let old_pos = self.xy;
 
+        self.unset_thing_position(level);
         self.xy += self.momxy;
+        self.set_thing_position(level);
+
+        let ctrl = &level.mobj_ctrl;
         if ctrl.min_floor_z - self.z <= 24.0 || ctrl.min_floor_z <= self.z {
             self.floorz = ctrl.min_floor_z;
             self.ceilingz = ctrl.max_ceil_z;
         }
 
-        // TODO: if any special lines were hit, do the effect
-        // if (!(thing->flags & (MF_TELEPORT | MF_NOCLIP)))
-        // {
-        //     while (numspechit--)
-        //     {
-        //         // see if the line was crossed
-        //         ld = spechit[numspechit];
-        //         side = P_PointOnLineSide(thing->x, thing->y, ld);
-        //         oldside = P_PointOnLineSide(oldx, oldy, ld);
-        //         if (side != oldside)
-        //         {
-        //             if (ld->special)
-        //                 P_CrossSpecialLine(ld - lines, oldside, thing);
-        //         }
-        //     }
-        // }
+        // if any special lines were hit, do the effect
+        if self.flags
+            & (MapObjectFlag::MF_TELEPORT as u32
+                | MapObjectFlag::MF_NOCLIP as u32)
+            == 0
+        {
+            let spec_hits = std::mem::take(&mut level.mobj_ctrl.spec_hits);
+            for ld in spec_hits.into_iter().rev() {
+                // see if the line was crossed
+                let side = ld.point_on_side(&self.xy);
+                let old_side = ld.point_on_side(&old_pos);
+                if side != old_side && ld.special != 0 {
+                    p_cross_special_line(old_side, ld, self, level);
+                }
+            }
+        }
+    }
+
+    /// P_UseLines
+    /// Looks for special lines in front of the player to activate.
+    pub(crate) fn p_use_lines(&mut self, level: &mut Level) {
+        let origin = self.xy;
+        let endpoint = origin + self.angle.unit() * USERANGE;
+
+        path_traverse(
+            origin,
+            endpoint,
+            PT_ADDLINES,
+            level,
+            |intercept, level| {
+                // PTR_UseTraverse
+                let line = match &intercept.kind {
+                    InterceptKind::Line(line) => line.clone(),
+                    InterceptKind::Thing(_) => return true,
+                };
+                if line.special == 0 {
+                    if PortalZ::new(&line).range <= 0.0 {
+                        // TODO: S_StartSound (usething, sfx_noway);
+                        // can't use through a wall
+                        return false;
+                    }
+                    // not a special line, but keep checking
+                    return true;
+                }
+
+                let side = line.point_on_side(&self.xy);
+                p_use_special_line(side, line, self, level);
+
+                // can't use for than one special line in a row
+                false
+            },
+        );
+    }
+
+    /// P_AimLineAttack
+    ///
+    /// Finds the first shootable thing along `angle` within `distance` that
+    /// can be seen past the openings of two sided lines. Returns the slope to
+    /// aim at it and the thing (vanilla `linetarget`), or `None` if there is
+    /// nothing to aim at.
+    pub(crate) fn p_aim_line_attack(
+        &mut self,
+        angle: Angle,
+        distance: f32,
+        level: &mut Level,
+    ) -> Option<(f32, NonNull<MapObject>)> {
+        let origin = self.xy;
+        let endpoint = origin + angle.unit() * distance;
+        let shootz = self.z + (self.height / 2.0) + 8.0;
+        let shooter: *const MapObject = self;
+
+        // can't shoot outside view angles
+        let mut topslope = 100.0 / 160.0;
+        let mut bottomslope = -100.0 / 160.0;
+        let mut target = None;
+
+        let flags = PT_ADDLINES | PT_ADDTHINGS;
+        path_traverse(origin, endpoint, flags, level, |intercept, _| {
+            // PTR_AimTraverse
+            let dist = distance * intercept.frac;
+            match &intercept.kind {
+                InterceptKind::Line(line) => {
+                    if line.flags & LineDefFlags::TwoSided as i16 == 0 {
+                        // stop
+                        return false;
+                    }
+
+                    // Crosses a two sided line.
+                    // A two sided line will restrict
+                    // the possible target ranges.
+                    let portal = PortalZ::new(line);
+                    if portal.bottom_z >= portal.top_z {
+                        // stop
+                        return false;
+                    }
+
+                    let back = line.backsector.as_ref().unwrap();
+                    if line.frontsector.floorheight != back.floorheight {
+                        let slope = (portal.bottom_z - shootz) / dist;
+                        if slope > bottomslope {
+                            bottomslope = slope;
+                        }
+                    }
+
+                    if line.frontsector.ceilingheight != back.ceilingheight {
+                        let slope = (portal.top_z - shootz) / dist;
+                        if slope < topslope {
+                            topslope = slope;
+                        }
+                    }
+
+                    // shot continues if there is still an opening
+                    topslope > bottomslope
+                }
+                InterceptKind::Thing(thing) => {
+                    if std::ptr::eq(thing.as_ptr(), shooter) {
+                        // can't shoot self
+                        return true;
+                    }
+
+                    let th = unsafe { thing.as_ref() };
+                    if th.flags & MapObjectFlag::MF_SHOOTABLE as u32 == 0 {
+                        // corpse or something
+                        return true;
+                    }
+
+                    // check angles to see if the thing can be aimed at
+                    let thingtopslope = (th.z + th.height - shootz) / dist;
+                    if thingtopslope < bottomslope {
+                        // shot over the thing
+                        return true;
+                    }
+
+                    let thingbottomslope = (th.z - shootz) / dist;
+                    if thingbottomslope > topslope {
+                        // shot under the thing
+                        return true;
+                    }
+
+                    // this thing can be hit!
+                    let top = thingtopslope.min(topslope);
+                    let bottom = thingbottomslope.max(bottomslope);
+                    target = Some(((top + bottom) / 2.0, *thing));
+                    // don't go any farther
+                    false
+                }
+            }
+        });
+
+        target
+    }
+
+    /// P_LineAttack
+    ///
+    /// Fires a hitscan attack along `angle` at `slope`, shooting any shootable
+    /// special lines crossed. Spawns a puff where it hits a wall, or blood if
+    /// it hits a thing that bleeds. If `damage` is 0 the attack is only a
+    /// puff, as for a missed punch.
+    pub(crate) fn p_line_attack(
+        &mut self,
+        angle: Angle,
+        distance: f32,
+        slope: f32,
+        damage: i32,
+        level: &mut Level,
+    ) {
+        let origin = self.xy;
+        let delta = angle.unit() * distance;
+        let shootz = self.z + (self.height / 2.0) + 8.0;
+        let shooter = NonNull::from(&mut *self);
+        let sky = level.map_data.check_flat_num_for_name("F_SKY1");
+
+        let flags = PT_ADDLINES | PT_ADDTHINGS;
+        path_traverse(
+            origin,
+            origin + delta,
+            flags,
+            level,
+            |intercept, level| {
+                // PTR_ShootTraverse
+                let dist = distance * intercept.frac;
+                match &intercept.kind {
+                    InterceptKind::Line(line) => {
+                        if line.special != 0 {
+                            p_shoot_special_line(line.clone(), self, level);
+                        }
+
+                        if line.flags & LineDefFlags::TwoSided as i16 != 0 {
+                            // crosses a two sided line
+                            let portal = PortalZ::new(line);
+                            let back = line.backsector.as_ref().unwrap();
+                            let mut hit = portal.bottom_z >= portal.top_z;
+
+                            if line.frontsector.floorheight != back.floorheight
+                            {
+                                let s = (portal.bottom_z - shootz) / dist;
+                                hit |= s > slope;
+                            }
+
+                            if line.frontsector.ceilingheight
+                                != back.ceilingheight
+                            {
+                                let s = (portal.top_z - shootz) / dist;
+                                hit |= s < slope;
+                            }
+
+                            if !hit {
+                                // shot continues
+                                return true;
+                            }
+                        }
+
+                        // hit line
+                        // position a bit closer
+                        let frac = intercept.frac - 4.0 / distance;
+                        let xy = origin + delta * frac;
+                        let z = shootz + slope * (frac * distance);
+
+                        if Some(line.frontsector.ceilingpic) == sky {
+                            // don't shoot the sky!
+                            if z > line.frontsector.ceilingheight {
+                                return false;
+                            }
+
+                            // it's a sky hack wall
+                            if let Some(back) = &line.backsector {
+                                if Some(back.ceilingpic) == sky {
+                                    return false;
+                                }
+                            }
+                        }
+
+                        // Spawn bullet puffs.
+                        MapObject::p_spawn_puff(
+                            xy.x(),
+                            xy.y(),
+                            z,
+                            distance,
+                            level,
+                        );
+
+                        // don't go any farther
+                        false
+                    }
+                    InterceptKind::Thing(thing) => {
+                        if *thing == shooter {
+                            // can't shoot self
+                            return true;
+                        }
+
+                        let mut thing = *thing;
+                        let th = unsafe { thing.as_mut() };
+                        if th.flags & MapObjectFlag::MF_SHOOTABLE as u32 == 0 {
+                            // corpse or something
+                            return true;
+                        }
+
+                        // check angles to see if the thing can be aimed at
+                        let thingtopslope = (th.z + th.height - shootz) / dist;
+                        if thingtopslope < slope {
+                            // shot over the thing
+                            return true;
+                        }
+
+                        let thingbottomslope = (th.z - shootz) / dist;
+                        if thingbottomslope > slope {
+                            // shot under the thing
+                            return true;
+                        }
+
+                        // hit thing
+                        // position a bit closer
+                        let frac = intercept.frac - 10.0 / distance;
+                        let xy = origin + delta * frac;
+                        let z = shootz + slope * (frac * distance);
+
+                        // Spawn bullet puffs or blod spots,
+                        // depending on target type.
+                        if th.flags & MapObjectFlag::MF_NOBLOOD as u32 != 0 {
+                            MapObject::p_spawn_puff(
+                                xy.x(),
+                                xy.y(),
+                                z,
+                                distance,
+                                level,
+                            );
+                        } else {
+                            MapObject::p_spawn_blood(
+                                xy.x(),
+                                xy.y(),
+                                z,
+                                damage,
+                                level,
+                            );
+                        }
+
+                        if damage != 0 {
+                            th.p_damage_mobj(
+                                Some(shooter),
+                                Some(shooter),
+                                damage,
+                                level,
+                            );
+                        }
+
+                        // don't go any farther
+                        false
+                    }
+                }
+            },
+        );
+    }
+
+    /// PIT_CheckThing
+    ///
+    /// Checks the move to `self.xy + self.momxy` against `thing`. Returns
+    /// false if the move is blocked.
+    fn pit_check_thing(
+        &mut self,
+        thing: &mut MapObject,
+        level: &mut Level,
+    ) -> bool {
+        if thing.flags
+            & (MapObjectFlag::MF_SOLID as u32
+                | MapObjectFlag::MF_SPECIAL as u32
+                | MapObjectFlag::MF_SHOOTABLE as u32)
+            == 0
+        {
+            return true;
+        }
+
+        let blockdist = thing.radius + self.radius;
+        let delta = thing.xy - (self.xy + self.momxy);
+        if delta.x().abs() >= blockdist || delta.y().abs() >= blockdist {
+            // didn't hit it
+            return true;
+        }
+
+        // check for skulls slamming into things
+        if self.flags & MapObjectFlag::MF_SKULLFLY as u32 != 0 {
+            let damage = ((p_random() % 8) + 1) as i32 * self.info.damage;
+            let skull = NonNull::from(&mut *self);
+            thing.p_damage_mobj(Some(skull), Some(skull), damage, level);
+
+            self.flags &= !(MapObjectFlag::MF_SKULLFLY as u32);
+            self.momxy = Vec2::default();
+            self.momz = 0.0;
+            self.p_set_mobj_state(self.info.spawnstate, level);
+            return false; // stop moving
+        }
+
+        // missiles can hit other things
+        if self.flags & MapObjectFlag::MF_MISSILE as u32 != 0 {
+            // see if it went over / under
+            if self.z > thing.z + thing.height {
+                return true; // overhead
+            }
+            if self.z + self.height < thing.z {
+                return true; // underneath
+            }
+
+            if let Some(target) = self.target {
+                let target = unsafe { target.as_ref() };
+                if target.kind == thing.kind
+                    || (target.kind == MapObjectType::MT_KNIGHT as u16
+                        && thing.kind == MapObjectType::MT_BRUISER as u16)
+                    || (target.kind == MapObjectType::MT_BRUISER as u16
+                        && thing.kind == MapObjectType::MT_KNIGHT as u16)
+                {
+                    // Don't hit same species as originator.
+                    if std::ptr::eq(target, thing) {
+                        return true;
+                    }
+
+                    if thing.kind != MapObjectType::MT_PLAYER as u16 {
+                        // Explode, but do no damage.
+                        // Let players missile other players.
+                        return false;
+                    }
+                }
+            }
+
+            if thing.flags & MapObjectFlag::MF_SHOOTABLE as u32 == 0 {
+                // didn't do any damage
+                return thing.flags & MapObjectFlag::MF_SOLID as u32 == 0;
+            }
+
+            // damage / explode
+            let damage = ((p_random() % 8) + 1) as i32 * self.info.damage;
+            let missile = NonNull::from(&mut *self);
+            thing.p_damage_mobj(Some(missile), self.target, damage, level);
+
+            // don't traverse any more
+            return false;
+        }
+
+        // check for special pickup
+        if thing.flags & MapObjectFlag::MF_SPECIAL as u32 != 0 {
+            let solid = thing.flags & MapObjectFlag::MF_SOLID as u32 != 0;
+            if self.flags & MapObjectFlag::MF_PICKUP as u32 != 0 {
+                // can remove thing
+                thing.p_touch_special_thing(self, level);
+            }
+            return !solid;
+        }
+
+        thing.flags & MapObjectFlag::MF_SOLID as u32 == 0
+    }
+
+    /// Vanilla refuses a move blocked by a thing. Instead the momentum is
+    /// cut back along the axis the boxes overlap least on, so the mover is
+    /// stopped against the side of the thing and slides along it.
+    fn resolve_thing_contact(&mut self, thing: &MapObject) {
+        let blockdist = thing.radius + self.radius;
+        let delta = (self.xy + self.momxy) - thing.xy;
+        let overlap_x = blockdist - delta.x().abs();
+        let overlap_y = blockdist - delta.y().abs();
+        if overlap_x < overlap_y {
+            self.momxy
+                .set_x(self.momxy.x() + overlap_x * delta.x().signum());
+        } else {
+            self.momxy
+                .set_y(self.momxy.y() + overlap_y * delta.y().signum());
+        }
     }
 
     /// PIT_CheckLine
@@ -235,7 +689,12 @@ impl MapObject {
                 ctrl.max_dropoff = portal.lowest_z;
             }
 
-            if ld.special != 0 {
+            if ld.special != 0
+                && !ctrl
+                    .spec_hits
+                    .iter()
+                    .any(|l| l.as_ptr() as *const LineDef == ld)
+            {
                 ctrl.spec_hits.push(DPtr::new(ld));
             }
 
@@ -265,25 +724,279 @@ impl MapObject {
     }
 }
 
+impl MapObject {
+    /// The floor and ceiling heights at the thing's position, from its own
+    /// sector and the openings of the lines of `moved` and its own sector
+    /// that it stands over. This is the height part of P_CheckPosition.
+    fn position_heights(&self, moved: &Sector) -> (f32, f32) {
+        let sector = &self.subsector.sector;
+        let mut floorz = sector.floorheight;
+        let mut ceilingz = sector.ceilingheight;
+
+        let tmbox = BBox {
+            top:    self.xy.y() + self.radius,
+            bottom: self.xy.y() - self.radius,
+            left:   self.xy.x() - self.radius,
+            right:  self.xy.x() + self.radius,
+        };
+        for line in moved.lines.iter().chain(sector.lines.iter()) {
+            if line.backsector.is_none()
+                || tmbox.right <= line.bbox.left
+                || tmbox.left >= line.bbox.right
+                || tmbox.top <= line.bbox.bottom
+                || tmbox.bottom >= line.bbox.top
+                || box_on_line_side(&tmbox, line) != -1
+            {
+                continue;
+            }
+            let portal = PortalZ::new(line);
+            if portal.top_z < ceilingz {
+                ceilingz = portal.top_z;
+            }
+            if portal.bottom_z > floorz {
+                floorz = portal.bottom_z;
+            }
+        }
+        (floorz, ceilingz)
+    }
+
+    /// P_ThingHeightClip
+    /// Takes a valid thing and adjusts the thing->floorz,
+    /// thing->ceilingz, and possibly thing->z.
+    /// This is called for all nearby monsters
+    /// whenever a sector changes height.
+    /// If the thing doesn't fit,
+    /// the z will be set to the lowest value
+    /// and false will be returned.
+    fn p_thing_height_clip(&mut self, moved: &Sector) -> bool {
+        let onfloor = self.z <= self.floorz;
+
+        let (floorz, ceilingz) = self.position_heights(moved);
+        self.floorz = floorz;
+        self.ceilingz = ceilingz;
+
+        if onfloor {
+            // walking monsters rise and fall with the floor
+            self.z = self.floorz;
+        } else {
+            // don't adjust a floating monster unless forced to
+            if self.z + self.height > self.ceilingz {
+                self.z = self.ceilingz - self.height;
+            }
+        }
+
+        self.ceilingz - self.floorz >= self.height
+    }
+
+    /// PIT_ChangeSector, returns false if the thing doesn't fit
+    fn pit_change_sector(
+        &mut self,
+        moved: &Sector,
+        crunch: bool,
+        level: &mut Level,
+    ) -> bool {
+        if self.p_thing_height_clip(moved) {
+            // keep checking
+            return true;
+        }
+
+        // crunch bodies to giblets
+        if self.health <= 0 {
+            self.p_set_mobj_state(StateNum::S_GIBS, level);
+            self.flags &= !(MapObjectFlag::MF_SOLID as u32);
+            self.height = 0.0;
+            self.radius = 0.0;
+            // keep checking
+            return true;
+        }
+
+        // crunch dropped items
+        if self.flags & MapObjectFlag::MF_DROPPED as u32 != 0 {
+            self.p_remove_mobj(level);
+            // keep checking
+            return true;
+        }
+
+        if self.flags & MapObjectFlag::MF_SHOOTABLE as u32 == 0 {
+            // assume it is bloody gibs or something
+            return true;
+        }
+
+        if crunch && level.level_time & 3 == 0 {
+            self.p_damage_mobj(None, None, 10, level);
+
+            // spray blood in a random direction
+            let mut mo = MapObject::p_spawn_map_object(
+                self.xy.x(),
+                self.xy.y(),
+                (self.z + self.height / 2.0) as i32,
+                MapObjectType::MT_BLOOD as u16,
+                level,
+            );
+            mo.obj.momxy = Vec2::new(
+                (p_random() as i32 - p_random() as i32) as f32 / 16.0,
+                (p_random() as i32 - p_random() as i32) as f32 / 16.0,
+            );
+            if level.add_thinker(mo).is_none() {
+                println!("PIT_ChangeSector: no room for another thinker");
+            }
+        }
+        false
+    }
+
+    /// P_TeleportMove
+    ///
+    /// Move the thing to `xy`, stomping on any shootable things there. Returns
+    /// false if a monster is blocked by a thing, which is only allowed to
+    /// telefrag on MAP30.
+    pub(crate) fn p_teleport_move(
+        &mut self,
+        xy: Vec2,
+        level: &mut Level,
+    ) -> bool {
+        let new_subsect = level.map_data.point_in_subsector(&xy);
+        // TODO: ceilingline = NULL;
+
+        // The base floor/ceiling is from the subsector
+        // that contains the point.
+        // Any contacted lines the step closer together
+        // will adjust them.
+        let floorz = new_subsect.sector.floorheight;
+        let ceilingz = new_subsect.sector.ceilingheight;
+
+        // stomp on any things contacted
+        let reach = Vec2::new(self.radius + MAXRADIUS, self.radius + MAXRADIUS);
+        for mut thing in level.blockmap.things_in_box(xy - reach, xy + reach) {
+            if thing.as_ptr() as *const MapObject == self {
+                continue;
+            }
+            let thing = unsafe { thing.as_mut() };
+            if !self.pit_stomp_thing(thing, xy, level) {
+                return false;
+            }
+        }
+
+        // the move is ok,
+        // so link the thing into its new position
+        self.unset_thing_position(level);
+        self.floorz = floorz;
+        self.ceilingz = ceilingz;
+        self.xy = xy;
+        self.set_thing_position(level);
+        true
+    }
+
+    /// PIT_StompThing
+    fn pit_stomp_thing(
+        &mut self,
+        thing: &mut MapObject,
+        xy: Vec2,
+        level: &mut Level,
+    ) -> bool {
+        if thing.flags & MapObjectFlag::MF_SHOOTABLE as u32 == 0 {
+            return true;
+        }
+
+        let blockdist = thing.radius + self.radius;
+        if (thing.xy.x() - xy.x()).abs() >= blockdist
+            || (thing.xy.y() - xy.y()).abs() >= blockdist
+        {
+            // didn't hit it
+            return true;
+        }
+
+        // monsters don't stomp things except on boss level
+        if self.player.is_none() && level.game_map != 30 {
+            return false;
+        }
+
+        let stomper = NonNull::from(&mut *self);
+        thing.p_damage_mobj(Some(stomper), Some(stomper), 10000, level);
+        true
+    }
+}
+
+/// P_ChangeSector
+///
+/// Re-checks the heights of the things touching a sector after its floor or
+/// ceiling has moved. Returns true if any thing no longer fits.
+pub(crate) fn p_change_sector(
+    sector: DPtr<Sector>,
+    crunch: bool,
+    level: &mut Level,
+) -> bool {
+    let mut min = Vec2::new(f32::MAX, f32::MAX);
+    let mut max = Vec2::new(f32::MIN, f32::MIN);
+    for line in &sector.lines {
+        min = min.min(Vec2::new(line.bbox.left, line.bbox.bottom));
+        max = max.max(Vec2::new(line.bbox.right, line.bbox.top));
+    }
+    let reach = Vec2::new(MAXRADIUS, MAXRADIUS);
+
+    let mut nofit = false;
+    // re-check heights for all things near the moving sector
+    for mut thing in level.blockmap.things_in_box(min - reach, max + reach) {
+        let thing = unsafe { thing.as_mut() };
+        if !thing.pit_change_sector(&sector, crunch, level) {
+            nofit = true;
+        }
+    }
+    nofit
+}
+
 /// P_RadiusAttack
 /// Source is the creature that caused the explosion at spot.
 pub(crate) fn p_radius_attack(
     spot: &mut MapObject,
-    source: &mut MapObject,
-    damage: f32,
+    source: Option<NonNull<MapObject>>,
+    damage: i32,
+    level: &mut Level,
+) {
+    let dist = damage as f32 + MAXRADIUS;
+    let reach = Vec2::new(dist, dist);
+    let bombspot = NonNull::from(&mut *spot);
+    for mut thing in level
+        .blockmap
+        .things_in_box(spot.xy - reach, spot.xy + reach)
+    {
+        if thing.as_ptr() as *const MapObject == spot {
+            continue;
+        }
+        let thing = unsafe { thing.as_mut() };
+        pit_radius_attack(thing, bombspot, source, damage, level);
+    }
+}
+
+/// PIT_RadiusAttack
+/// "bombsource" is the creature that caused the explosion at "bombspot".
+fn pit_radius_attack(
+    thing: &mut MapObject,
+    bombspot: NonNull<MapObject>,
+    bombsource: Option<NonNull<MapObject>>,
+    bombdamage: i32,
+    level: &mut Level,
 ) {
-    let dist = damage + MAXRADIUS;
-    unimplemented!()
-    // // origin of block level is bmaporgx and bmaporgy
-    // let yh = (spot.xy.y() + dist - bmaporgy) >> MAPBLOCKSHIFT;
-    // let yl = (spot.xy.y() - dist - bmaporgy) >> MAPBLOCKSHIFT;
-    // let xh = (spot.xy.x() + dist - bmaporgx) >> MAPBLOCKSHIFT;
-    // let xl = (spot.xy.x() - dist - bmaporgx) >> MAPBLOCKSHIFT;
-    // bombspot = spot;
-    // bombsource = source;
-    // bombdamage = damage;
-
-    // for (y = yl; y <= yh; y++)
-    // for (x = xl; x <= xh; x++)
-    // P_BlockThingsIterator(x, y, PIT_RadiusAttack);
+    if thing.flags & MapObjectFlag::MF_SHOOTABLE as u32 == 0 {
+        return;
+    }
+
+    // Boss spider and cyborg
+    // take no damage from concussion.
+    if thing.kind == MapObjectType::MT_CYBORG as u16
+        || thing.kind == MapObjectType::MT_SPIDER as u16
+    {
+        return;
+    }
+
+    let spot = unsafe { bombspot.as_ref() };
+    let dx = (thing.xy.x() - spot.xy.x()).abs();
+    let dy = (thing.xy.y() - spot.xy.y()).abs();
+    let dist = (dx.max(dy) - thing.radius).max(0.0) as i32;
+
+    if dist >= bombdamage {
+        return; // out of range
+    }
+
+    // TODO: only if P_CheckSight(thing, bombspot), must be in direct path
+    thing.p_damage_mobj(Some(bombspot), bombsource, bombdamage - dist, level);
 }
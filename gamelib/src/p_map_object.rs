@@ -12,6 +12,7 @@ use crate::{
     doom_def::{MAXPLAYERS, MTF_AMBUSH, TICRATE},
     info::MapObjectInfo,
     p_local::FRACUNIT_DIV4,
+    p_local::MELEERANGE,
     p_local::ONCEILINGZ,
     DPtr,
 };
@@ -24,6 +25,7 @@ use crate::{info::states::State, p_local::p_random, sounds::SfxEnum};
 use crate::{
     info::{MapObjectType, SpriteNum},
     p_local::{ONFLOORZ, VIEWHEIGHT},
+    p_player_sprite::p_setup_psprites,
     player::{Player, PlayerState},
 };
 use std::f32::consts::PI;
@@ -140,11 +142,16 @@ pub(crate) struct MapObject {
     // More drawing info: to determine current sprite.
     /// orientation
     pub angle:        Angle,
+    /// Position and angle at the start of the tic, drawing interpolates
+    /// from these to the current
+    pub prev_xy:      Vec2,
+    pub prev_z:       f32,
+    pub prev_angle:   Angle,
     /// used to find patch_t and flip value
-    sprite:           SpriteNum,
+    pub sprite:       SpriteNum,
     /// might be ORed with FF_FULLBRIGHT
-    frame:            i32,
-    subsector:        DPtr<SubSector>,
+    pub frame:        i32,
+    pub subsector:    DPtr<SubSector>,
     /// The closest interval over all contacted Sectors.
     pub floorz:       f32,
     pub ceilingz:     f32,
@@ -156,9 +163,9 @@ pub(crate) struct MapObject {
     pub momz:         f32,
     /// If == validcount, already checked.
     validcount:       i32,
-    kind:             u16,
+    pub kind:         u16,
     /// &mobjinfo[mobj.type]
-    info:             MapObjectInfo,
+    pub info:         MapObjectInfo,
     pub tics:         i32,
     /// state tic counter
     // TODO: probably only needs to be an index to the array
@@ -168,9 +175,9 @@ pub(crate) struct MapObject {
     pub health:       i32,
     /// Movement direction, movement generation (zig-zagging).
     /// 0-7
-    movedir:          i32,
+    pub movedir:      i32,
     /// when 0, select a new dir
-    movecount:        i32,
+    pub movecount:    i32,
     // Thing being chased/attacked (or NULL),
     // also the originator for missiles.
     pub target:       Option<NonNull<MapObject>>,
@@ -184,13 +191,17 @@ pub(crate) struct MapObject {
     /// RUST: If this is not `None` then the `NonNull` pointer is guaranteed to point to a player
     pub player:       Option<NonNull<Player>>,
     /// Player number last looked for.
-    lastlook:         i32,
+    pub lastlook:     i32,
     /// For nightmare respawn.
-    spawn_point:      Option<WadThing>,
+    pub spawn_point:  Option<WadThing>,
     // Thing being chased/attacked for tracers.
     // struct mobj_s*	tracer;
 }
 
+/// P_MobjThinker. The work is done by `Think for MapObject`, this only marks
+/// the thinker as live so that it isn't cleaned from the thinker list.
+fn p_mobj_thinker(_: &mut MapObject, _: &mut Level) {}
+
 impl Think for MapObject {
     // TODO: P_MobjThinker
     fn think(&mut self, level: &mut Level) -> bool {
@@ -200,7 +211,7 @@ impl Think for MapObject {
         // {
         if self.momxy.x() != 0.0
             || self.momxy.y() != 0.0
-            || MapObjectFlag::MF_SKULLFLY as u32 != 0
+            || self.flags & MapObjectFlag::MF_SKULLFLY as u32 != 0
         {
             self.p_xy_movement(level);
         }
@@ -224,7 +235,8 @@ impl Think for MapObject {
             self.tics -= 1;
 
             // you can cycle through multiple states in a tic
-            if self.tics > 0 && !self.p_set_mobj_state(self.state.next_state) {
+            if self.tics == 0
+                && !self.p_set_mobj_state(self.state.next_state, level) {
                 return true;
             } // freed itself
         } else {
@@ -253,6 +265,13 @@ impl Think for MapObject {
 }
 
 impl MapObject {
+    /// Keep the position at the start of the tic for interpolation
+    pub fn store_previous(&mut self) {
+        self.prev_xy = self.xy;
+        self.prev_z = self.z;
+        self.prev_angle = self.angle;
+    }
+
     fn was_removed(&self) -> bool {
         if let Some(thinker) = self.thinker {
             if let ActionFunc::None = unsafe { &thinker.as_ref().function } {
@@ -262,11 +281,26 @@ impl MapObject {
         false
     }
 
+    /// P_RemoveMobj
+    ///
+    /// The thinker is removed from the level at the end of the tic
+    pub(crate) fn p_remove_mobj(&mut self, level: &mut Level) {
+        // TODO: item respawn queue for deathmatch
+        self.unset_thing_position(level);
+        // TODO: S_StopSound (mobj);
+        if let Some(mut thinker) = self.thinker {
+            unsafe { thinker.as_mut().function = ActionFunc::None };
+        }
+    }
+
     /// P_ExplodeMissile
-    pub(crate) fn p_explode_missile(&mut self) {
+    pub(crate) fn p_explode_missile(&mut self, level: &mut Level) {
         self.momxy = Vec2::default();
-        self.z = 0.0;
-        self.p_set_mobj_state(MOBJINFO[self.kind as usize].deathstate);
+        self.momz = 0.0;
+        self.p_set_mobj_state(
+            MOBJINFO[self.kind as usize].deathstate,
+            level,
+        );
 
         self.tics -= (p_random() & 3) as i32;
 
@@ -341,6 +375,13 @@ impl MapObject {
             }
 
             self.z = self.floorz;
+
+            if self.flags & MapObjectFlag::MF_MISSILE as u32 != 0
+                && self.flags & MapObjectFlag::MF_NOCLIP as u32 == 0
+            {
+                self.p_explode_missile(level);
+                return;
+            }
         } else if self.flags & MapObjectFlag::MF_NOGRAVITY as u32 == 0 {
             if self.momz == 0.0 {
                 self.momz = -1.0 * 2.0;
@@ -348,6 +389,25 @@ impl MapObject {
                 self.momz -= 1.0;
             }
         }
+
+        if self.z + self.height > self.ceilingz {
+            // hit the ceiling
+            if self.momz > 0.0 {
+                self.momz = 0.0;
+            }
+            self.z = self.ceilingz - self.height;
+
+            if self.flags & MapObjectFlag::MF_SKULLFLY as u32 != 0 {
+                // the skull slammed into something
+                self.momz = -self.momz;
+            }
+
+            if self.flags & MapObjectFlag::MF_MISSILE as u32 != 0
+                && self.flags & MapObjectFlag::MF_NOCLIP as u32 == 0
+            {
+                self.p_explode_missile(level);
+            }
+        }
     }
 
     /// P_XYMovement
@@ -357,7 +417,7 @@ impl MapObject {
                 self.flags &= !(MapObjectFlag::MF_SKULLFLY as u32);
                 self.momxy = Vec2::default();
                 self.z = 0.0;
-                self.p_set_mobj_state(self.info.spawnstate);
+                self.p_set_mobj_state(self.info.spawnstate, level);
             }
             return;
         }
@@ -509,10 +569,17 @@ impl MapObject {
 
         if let Some(ref mut think) = player.mobj {
             think.obj.player = Some(player_ptr);
+            // The player owns the thinker so link it here, rather than in
+            // `Level::add_thinker`
+            let mut thinker = NonNull::from(think);
+            unsafe {
+                thinker.as_mut().obj.thinker = Some(thinker);
+                thinker.as_mut().obj.set_thing_position(level);
+            }
         }
 
-        // // setup gun psprite
-        // TODO: P_SetupPsprites(p);
+        // setup gun psprite
+        p_setup_psprites(player, level);
 
         // // give all cards in death match mode
         // if deathmatch {
@@ -634,7 +701,7 @@ impl MapObject {
         }
 
         // P_AddThinker(&mobj->thinker);
-        if !level.add_thinker(thinker) {
+        if level.add_thinker(thinker).is_none() {
             panic!("P_SpawnMapThing: Could not spawn type {} at ({}, {}): out of memory",
             mthing.kind,
             mthing.x,
@@ -649,7 +716,7 @@ impl MapObject {
     ///
     // TODO: pass in a ref to the container so the obj can be added
     //  Doom calls an zmalloc function for this. Then pass a reference back for it
-    fn p_spawn_map_object(
+    pub(crate) fn p_spawn_map_object(
         x: f32,
         y: f32,
         mut z: i32,
@@ -690,6 +757,9 @@ impl MapObject {
             xy: Vec2::new(x, y),
             z: z as f32,
             angle: Angle::new(0.0),
+            prev_xy: Vec2::new(x, y),
+            prev_z: z as f32,
+            prev_angle: Angle::new(0.0),
             sprite: state.sprite,
             frame: state.frame,
             floorz: floorz as f32,
@@ -716,14 +786,157 @@ impl MapObject {
         };
 
         let mut thinker = Thinker::new(obj);
-        thinker.function = ActionFunc::None; //P_MobjThinker
+        thinker.function = ActionFunc::MapObject(&p_mobj_thinker);
 
         // P_AddThinker(&mobj->thinker);
         thinker
     }
 
+    /// P_SpawnPuff
+    ///
+    /// Spawns a bullet puff where a hitscan attack hit a wall or a thing that
+    /// doesn't bleed. Melee attacks get a smaller puff.
+    pub(crate) fn p_spawn_puff(
+        x: f32,
+        y: f32,
+        mut z: f32,
+        attack_range: f32,
+        level: &mut Level,
+    ) {
+        z += (p_random() as f32 - p_random() as f32) / 64.0;
+
+        let mut thinker = MapObject::p_spawn_map_object(
+            x,
+            y,
+            z as i32,
+            MapObjectType::MT_PUFF as u16,
+            level,
+        );
+        let th = &mut thinker.obj;
+        th.momz = 1.0;
+        th.tics -= (p_random() & 3) as i32;
+
+        if th.tics < 1 {
+            th.tics = 1;
+        }
+
+        // don't make punches spark on the wall
+        if attack_range == MELEERANGE {
+            th.p_set_mobj_state(StateNum::S_PUFF3, level);
+        }
+        if level.add_thinker(thinker).is_none() {
+            println!("P_SpawnPuff: no room for another thinker");
+        }
+    }
+
+    /// P_SpawnBlood
+    ///
+    /// Spawns blood where a hitscan attack hit a thing, less damage gives a
+    /// smaller splat.
+    pub(crate) fn p_spawn_blood(
+        x: f32,
+        y: f32,
+        mut z: f32,
+        damage: i32,
+        level: &mut Level,
+    ) {
+        z += (p_random() as f32 - p_random() as f32) / 64.0;
+
+        let mut thinker = MapObject::p_spawn_map_object(
+            x,
+            y,
+            z as i32,
+            MapObjectType::MT_BLOOD as u16,
+            level,
+        );
+        let th = &mut thinker.obj;
+        th.momz = 2.0;
+        th.tics -= (p_random() & 3) as i32;
+
+        if th.tics < 1 {
+            th.tics = 1;
+        }
+
+        if (9..=12).contains(&damage) {
+            th.p_set_mobj_state(StateNum::S_BLOOD2, level);
+        } else if damage < 9 {
+            th.p_set_mobj_state(StateNum::S_BLOOD3, level);
+        }
+        if level.add_thinker(thinker).is_none() {
+            println!("P_SpawnBlood: no room for another thinker");
+        }
+    }
+
+    /// P_CheckMissileSpawn
+    ///
+    /// Moves a new missile a little forward so an angle can be computed if it
+    /// immediately explodes, which it does if fired in to a wall or a thing.
+    fn p_check_missile_spawn(&mut self, level: &mut Level) {
+        self.tics -= (p_random() & 3) as i32;
+        if self.tics < 1 {
+            self.tics = 1;
+        }
+
+        let momxy = self.momxy;
+        self.momxy = momxy / 2.0;
+        self.z += self.momz / 2.0;
+        self.p_try_move(level);
+        if self.flags & MapObjectFlag::MF_MISSILE as u32 != 0 {
+            self.momxy = momxy;
+        }
+    }
+
+    /// P_SpawnPlayerMissile
+    ///
+    /// Fires a missile from the player's object, aimed up or down at the
+    /// first thing found straight ahead or to either side, as for bullets.
+    pub(crate) fn p_spawn_player_missile(
+        &mut self,
+        kind: MapObjectType,
+        level: &mut Level,
+    ) {
+        // see which target is to be aimed at, straight ahead then to either
+        // side by 1 << 26 in BAM
+        let mut angle = self.angle;
+        let mut slope = 0.0;
+        for offset in [0.0, PI / 32.0, -PI / 32.0].iter() {
+            if let Some((s, _)) =
+                self.p_aim_line_attack(self.angle + *offset, 16.0 * 64.0, level)
+            {
+                angle = self.angle + *offset;
+                slope = s;
+                break;
+            }
+        }
+
+        let mut thinker = MapObject::p_spawn_map_object(
+            self.xy.x(),
+            self.xy.y(),
+            (self.z + 32.0) as i32,
+            kind as u16,
+            level,
+        );
+        let th = &mut thinker.obj;
+        // TODO: S_StartSound (th, th->info->seesound);
+        th.target = Some(NonNull::from(&mut *self));
+        th.angle = angle;
+        th.momxy = angle.unit() * th.info.speed;
+        th.momz = th.info.speed * slope;
+        th.store_previous();
+
+        if let Some(mut th) = level.add_thinker(thinker) {
+            unsafe { th.as_mut().p_check_missile_spawn(level) };
+        } else {
+            println!("P_SpawnPlayerMissile: no room for another thinker");
+        }
+    }
+
     /// P_SetMobjState
-    pub fn p_set_mobj_state(&mut self, mut state: StateNum) -> bool {
+    pub fn p_set_mobj_state(
+        &mut self,
+        mut state: StateNum,
+        level: &mut Level,
+    ) -> bool {
         let mut cycle_counter = 0;
 
         loop {
@@ -737,15 +950,16 @@ impl MapObject {
                     let st = get_state(state as usize);
                     state = st.next_state;
 
-                    // Modified handling.
-                    // Call action functions when the state is set
-                    let func = self.state.action.mobj_func();
-                    unsafe { (*func)(self) }
-
                     self.tics = st.tics;
                     self.sprite = st.sprite;
                     self.frame = st.frame;
                     self.state = st;
+
+                    // Modified handling.
+                    // Call action functions when the state is set
+                    if let ActionFunc::MapObject(func) = self.state.action {
+                        unsafe { (*func)(self, level) }
+                    }
                 }
             }
 
@@ -754,7 +968,7 @@ impl MapObject {
                 println!("P_SetMobjState: Infinite state cycle detected!");
             }
 
-            if self.tics <= 0 {
+            if self.tics != 0 {
                 break;
             }
         }
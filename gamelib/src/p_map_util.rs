@@ -1,6 +1,10 @@
+use crate::level_data::level::Level;
 use crate::level_data::map_defs::{BBox, LineDef, SlopeType};
+use crate::p_map_object::{MapObject, MapObjectFlag};
+use crate::DPtr;
 use glam::Vec2;
 use std::f32::EPSILON;
+use std::ptr::NonNull;
 
 #[derive(Default)]
 pub(crate) struct PortalZ {
@@ -91,6 +95,8 @@ pub fn cross(lhs: &Vec2, rhs: &Vec2) -> f32 {
     lhs.x() * rhs.y() - lhs.y() * rhs.x()
 }
 
+/// The distance along the ray from `origin` at which it crosses the line
+/// from `point1` to `point2`
 #[inline]
 pub fn ray_to_line_intersect(
     origin: &Vec2,
@@ -106,7 +112,7 @@ pub fn ray_to_line_intersect(
     if dot.abs() < 0.000001 {
         return None;
     }
-    let t1 = dot / cross(&v2, &v1);
+    let t1 = cross(&v2, &v1) / dot;
     let t2 = v1.dot(v3) / dot;
     if t1 >= 0.0 && t2 >= 0.0 && t2 <= 1.0 {
         return Some(t1);
@@ -114,6 +120,123 @@ pub fn ray_to_line_intersect(
     None
 }
 
+/// The fraction along the trace from `origin` by `delta` at which it crosses
+/// the line from `point1` to `point2`. Both ends of the line count as a
+/// crossing, the trace must cross within its length.
+#[inline]
+pub fn trace_to_line_intersect(
+    origin: &Vec2,
+    delta: &Vec2,
+    point1: &Vec2,
+    point2: &Vec2,
+) -> Option<f32> {
+    let line = *point2 - *point1;
+    let den = cross(delta, &line);
+    if den.abs() < 0.000001 {
+        return None;
+    }
+    let to_line = *point1 - *origin;
+    let frac = cross(&to_line, &line) / den;
+    let along = cross(&to_line, delta) / den;
+    if (0.0..=1.0).contains(&frac) && (0.0..=1.0).contains(&along) {
+        return Some(frac);
+    }
+    None
+}
+
+/// PT_ADDLINES, `path_traverse` checks lines
+pub(crate) const PT_ADDLINES: i32 = 1;
+/// PT_ADDTHINGS, `path_traverse` checks things
+pub(crate) const PT_ADDTHINGS: i32 = 2;
+
+/// What a trace has crossed
+pub(crate) enum InterceptKind {
+    Line(DPtr<LineDef>),
+    Thing(NonNull<MapObject>),
+}
+
+/// intercept_t
+pub(crate) struct Intercept {
+    /// Fraction along the trace, 0.0 at the origin and 1.0 at the end
+    pub frac: f32,
+    pub kind: InterceptKind,
+}
+
+/// P_PathTraverse
+///
+/// Traces a line from `origin` to `endpoint`, calling `trav` for each line
+/// and/or thing crossed (depending on `flags`) in order of distance. The
+/// traverse stops if `trav` returns false, and this then returns false.
+///
+/// Lines and things are found through the blockmap. Things are crossed at the
+/// diagonal of their bounding box that is most perpendicular to the trace,
+/// the same as vanilla.
+pub(crate) fn path_traverse(
+    origin: Vec2,
+    endpoint: Vec2,
+    flags: i32,
+    level: &mut Level,
+    mut trav: impl FnMut(&Intercept, &mut Level) -> bool,
+) -> bool {
+    let delta = endpoint - origin;
+    let mut intercepts: Vec<Intercept> = Vec::new();
+    let mut checked: Vec<*const LineDef> = Vec::new();
+
+    for block in level.blockmap.blocks_on_line(origin, endpoint) {
+        // PIT_AddLineIntercepts
+        if flags & PT_ADDLINES != 0 {
+            for line in level.blockmap.lines_in(block) {
+                // validcount
+                let ptr = line.as_ptr() as *const LineDef;
+                if checked.contains(&ptr) {
+                    continue;
+                }
+                checked.push(ptr);
+                if let Some(frac) =
+                    trace_to_line_intersect(&origin, &delta, &line.v1, &line.v2)
+                {
+                    intercepts.push(Intercept {
+                        frac,
+                        kind: InterceptKind::Line(line.clone()),
+                    });
+                }
+            }
+        }
+
+        // PIT_AddThingIntercepts
+        if flags & PT_ADDTHINGS != 0 {
+            for thing in level.blockmap.things_in(block) {
+                let th = unsafe { thing.as_ref() };
+                let r = th.radius;
+                // check a corner to corner crossection for hit
+                let (point1, point2) = if delta.x() * delta.y() > 0.0 {
+                    (th.xy + Vec2::new(-r, r), th.xy + Vec2::new(r, -r))
+                } else {
+                    (th.xy + Vec2::new(-r, -r), th.xy + Vec2::new(r, r))
+                };
+                if let Some(frac) =
+                    trace_to_line_intersect(&origin, &delta, &point1, &point2)
+                {
+                    intercepts.push(Intercept {
+                        frac,
+                        kind: InterceptKind::Thing(*thing),
+                    });
+                }
+            }
+        }
+    }
+
+    // P_TraverseIntercepts
+    intercepts.sort_by(|a, b| a.frac.partial_cmp(&b.frac).unwrap());
+    for intercept in intercepts.iter() {
+        if !trav(intercept, level) {
+            // don't bother going farther
+            return false;
+        }
+    }
+    true
+}
+
 #[derive(Debug)]
 pub(crate) struct LineContact {
     pub penetration:     f32,
@@ -239,10 +362,131 @@ pub fn unit_vec_from(rotation: f32) -> Vec2 {
     Vec2::new(x, y)
 }
 
+impl MapObject {
+    /// P_UnsetThingPosition
+    ///
+    /// Unlinks a thing from the blockmap. This must be done before the thing
+    /// moves, then relinked with `set_thing_position` after.
+    pub(crate) fn unset_thing_position(&mut self, level: &mut Level) {
+        // TODO: unlink from the sector thing list
+        if self.flags & MapObjectFlag::MF_NOBLOCKMAP as u32 == 0 {
+            level.blockmap.unlink(self);
+        }
+    }
+
+    /// P_SetThingPosition
+    ///
+    /// Links a thing in to the subsector and blockmap at its position.
+    pub(crate) fn set_thing_position(&mut self, level: &mut Level) {
+        self.subsector = level.map_data.point_in_subsector(&self.xy);
+        // TODO: link in to the sector thing list
+        if self.flags & MapObjectFlag::MF_NOBLOCKMAP as u32 == 0 {
+            level.blockmap.link(self);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::d_main::Skill;
+    use crate::doom_def::{GameMode, MAXPLAYERS};
+    use crate::level_data::level::Level;
     use crate::p_map_util::*;
+    use crate::player::Player;
     use glam::Vec2;
+    use wad::WadData;
+
+    fn e1m1_level(wad: &WadData) -> Level {
+        let mut players: [Player; MAXPLAYERS] = Default::default();
+        Level::setup_level(
+            wad,
+            Skill::Medium,
+            1,
+            1,
+            GameMode::Shareware,
+            &mut players,
+            &[false; MAXPLAYERS],
+        )
+    }
+
+    /// A diagonal trace from the player start of E1M1
+    fn trace() -> (Vec2, Vec2) {
+        (Vec2::new(1056.0, -3616.0), Vec2::new(1756.0, -3116.0))
+    }
+
+    fn trace_lines(level: &mut Level) -> Vec<(f32, *const LineDef)> {
+        let (origin, end) = trace();
+        let mut crossed = Vec::new();
+        let finished =
+            path_traverse(origin, end, PT_ADDLINES, level, |intercept, _| {
+                if let InterceptKind::Line(ref line) = intercept.kind {
+                    crossed.push((intercept.frac, line.as_ptr() as *const _));
+                }
+                true
+            });
+        assert!(finished);
+        crossed
+    }
+
+    #[test]
+    fn e1m1_trace_sorted_by_frac() {
+        let wad = WadData::new("../doom1.wad".into());
+        let mut level = e1m1_level(&wad);
+
+        let crossed = trace_lines(&mut level);
+        assert!(crossed.len() > 1);
+        for (frac, _) in crossed.iter() {
+            assert!(*frac >= 0.0 && *frac <= 1.0);
+        }
+        for pair in crossed.windows(2) {
+            assert!(pair[0].0 <= pair[1].0);
+        }
+    }
+
+    #[test]
+    fn e1m1_trace_reports_lines_once() {
+        let wad = WadData::new("../doom1.wad".into());
+        let mut level = e1m1_level(&wad);
+
+        let crossed = trace_lines(&mut level);
+        for (i, (_, line)) in crossed.iter().enumerate() {
+            assert!(!crossed[i + 1..].iter().any(|(_, l)| l == line));
+        }
+
+        // At least one of the crossed lines is in more than one of the
+        // blocks visited, so it would be found twice without the check
+        let (origin, end) = trace();
+        let blocks = level.blockmap.blocks_on_line(origin, end);
+        let in_blocks = |line: *const LineDef| {
+            blocks
+                .iter()
+                .filter(|b| {
+                    level
+                        .blockmap
+                        .lines_in(**b)
+                        .iter()
+                        .any(|l| l.as_ptr() as *const LineDef == line)
+                })
+                .count()
+        };
+        assert!(crossed.iter().any(|(_, line)| in_blocks(*line) > 1));
+    }
+
+    #[test]
+    fn e1m1_trace_stops_early() {
+        let wad = WadData::new("../doom1.wad".into());
+        let mut level = e1m1_level(&wad);
+
+        let (origin, end) = trace();
+        let mut calls = 0;
+        let finished =
+            path_traverse(origin, end, PT_ADDLINES, &mut level, |_, _| {
+                calls += 1;
+                false
+            });
+        assert!(!finished);
+        assert_eq!(calls, 1);
+    }
 
     #[test]
     fn circle_vec2_intersect() {
@@ -279,4 +523,24 @@ mod tests {
         assert!(circle_point_intersect(origin, r, point2).is_some());
         assert!(circle_to_seg_intersect(origin, r, point1, point2).is_some());
     }
+
+    #[test]
+    fn trace_line_intersect() {
+        let origin = Vec2::new(0.0, 0.0);
+        let delta = Vec2::new(10.0, 0.0);
+        let point1 = Vec2::new(5.0, -1.0);
+        let point2 = Vec2::new(5.0, 1.0);
+        let frac = trace_to_line_intersect(&origin, &delta, &point1, &point2);
+        assert!((frac.unwrap() - 0.5).abs() < EPSILON);
+
+        // The trace is too short to reach the line
+        let delta = Vec2::new(4.0, 0.0);
+        assert!(trace_to_line_intersect(&origin, &delta, &point1, &point2)
+            .is_none());
+
+        // The trace passes beside the line
+        let delta = Vec2::new(10.0, 10.0);
+        assert!(trace_to_line_intersect(&origin, &delta, &point1, &point2)
+            .is_none());
+    }
 }
@@ -0,0 +1,217 @@
+//!	Plats (i.e. elevator platforms) code, raising/lowering.
+
+use std::ptr::NonNull;
+
+use crate::d_thinker::{Think, Thinker};
+use crate::doom_def::TICRATE;
+use crate::level_data::level::Level;
+use crate::level_data::map_defs::LineDef;
+use crate::p_floor::t_move_plane;
+use crate::p_local::p_random;
+use crate::p_spec::{
+    find_highest_floor_surrounding, find_lowest_floor_surrounding,
+    find_next_highest_floor, find_sectors_from_line_tag, PlaneResult, PlatEnum,
+    PlatType, Platform, SpecialData,
+};
+use crate::DPtr;
+
+const PLATWAIT: i32 = 3;
+const PLATSPEED: f32 = 1.0;
+
+/// T_PlatRaise
+///
+/// Move a plat up and down
+impl Think for Platform {
+    fn think(&mut self, level: &mut Level) -> bool {
+        match self.status {
+            PlatEnum::up => {
+                let res = t_move_plane(
+                    self.sector.clone(),
+                    self.speed,
+                    self.high,
+                    self.crush,
+                    0,
+                    1,
+                    level,
+                );
+
+                // TODO: if (!(leveltime&7)) S_StartSound(sfx_stnmov) for
+                //  raiseAndChange and raiseToNearestAndChange
+
+                if res == PlaneResult::Crushed && !self.crush {
+                    self.count = self.wait;
+                    self.status = PlatEnum::down;
+                    // TODO: S_StartSound(sfx_pstart);
+                } else if res == PlaneResult::PastDest {
+                    self.count = self.wait;
+                    self.status = PlatEnum::waiting;
+                    // TODO: S_StartSound(sfx_pstop);
+
+                    match self.plat_type {
+                        PlatType::blazeDWUS
+                        | PlatType::downWaitUpStay
+                        | PlatType::raiseAndChange
+                        | PlatType::raiseToNearestAndChange => {
+                            // P_RemoveActivePlat
+                            self.sector.specialdata = None;
+                            return true;
+                        }
+                        PlatType::perpetualRaise => {}
+                    }
+                }
+            }
+            PlatEnum::down => {
+                let res = t_move_plane(
+                    self.sector.clone(),
+                    self.speed,
+                    self.low,
+                    false,
+                    0,
+                    -1,
+                    level,
+                );
+
+                if res == PlaneResult::PastDest {
+                    self.count = self.wait;
+                    self.status = PlatEnum::waiting;
+                    // TODO: S_StartSound(sfx_pstop);
+                }
+            }
+            PlatEnum::waiting => {
+                self.count -= 1;
+                if self.count == 0 {
+                    #[allow(clippy::float_cmp)]
+                    if self.sector.floorheight == self.low {
+                        self.status = PlatEnum::up;
+                    } else {
+                        self.status = PlatEnum::down;
+                    }
+                    // TODO: S_StartSound(sfx_pstart);
+                }
+            }
+            PlatEnum::in_stasis => {}
+        }
+        false
+    }
+}
+
+/// EV_DoPlat
+///
+/// Do Platforms. `amount` is only used for SOME platforms.
+pub(crate) fn ev_do_plat(
+    line: DPtr<LineDef>,
+    kind: PlatType,
+    amount: i32,
+    level: &mut Level,
+) -> bool {
+    //	Activate all <type> plats that are in_stasis
+    if kind == PlatType::perpetualRaise {
+        activate_in_stasis(line.tag, level);
+    }
+
+    let mut rtn = false;
+    for mut sec in find_sectors_from_line_tag(&line, level) {
+        if sec.specialdata.is_some() {
+            continue;
+        }
+
+        // Find lowest & highest floors around sector
+        rtn = true;
+        let mut plat = Platform {
+            thinker:    None,
+            sector:     sec.clone(),
+            speed:      PLATSPEED,
+            low:        0.0,
+            high:       0.0,
+            wait:       0,
+            count:      0,
+            status:     PlatEnum::up,
+            old_status: PlatEnum::up,
+            crush:      false,
+            tag:        line.tag,
+            plat_type:  kind,
+        };
+
+        match kind {
+            PlatType::raiseToNearestAndChange => {
+                plat.speed = PLATSPEED / 2.0;
+                sec.floorpic = line.frontsector.floorpic;
+                plat.high = find_next_highest_floor(&sec, sec.floorheight);
+                plat.wait = 0;
+                plat.status = PlatEnum::up;
+                // NO MORE DAMAGE, IF APPLICABLE
+                sec.special = 0;
+                // TODO: S_StartSound(sfx_stnmov);
+            }
+            PlatType::raiseAndChange => {
+                plat.speed = PLATSPEED / 2.0;
+                sec.floorpic = line.frontsector.floorpic;
+                plat.high = sec.floorheight + amount as f32;
+                plat.wait = 0;
+                plat.status = PlatEnum::up;
+                // TODO: S_StartSound(sfx_stnmov);
+            }
+            PlatType::downWaitUpStay | PlatType::blazeDWUS => {
+                plat.speed = if kind == PlatType::blazeDWUS {
+                    PLATSPEED * 8.0
+                } else {
+                    PLATSPEED * 4.0
+                };
+                plat.low = find_lowest_floor_surrounding(&sec);
+                if plat.low > sec.floorheight {
+                    plat.low = sec.floorheight;
+                }
+                plat.high = sec.floorheight;
+                plat.wait = TICRATE * PLATWAIT;
+                plat.status = PlatEnum::down;
+                // TODO: S_StartSound(sfx_pstart);
+            }
+            PlatType::perpetualRaise => {
+                plat.low = find_lowest_floor_surrounding(&sec);
+                if plat.low > sec.floorheight {
+                    plat.low = sec.floorheight;
+                }
+                plat.high = find_highest_floor_surrounding(&sec);
+                if plat.high < sec.floorheight {
+                    plat.high = sec.floorheight;
+                }
+                plat.wait = TICRATE * PLATWAIT;
+                plat.status = if p_random() & 1 == 0 {
+                    PlatEnum::up
+                } else {
+                    PlatEnum::down
+                };
+                // TODO: S_StartSound(sfx_pstart);
+            }
+        }
+
+        // P_AddActivePlat
+        let mut thinker = Box::new(Thinker::new(plat));
+        let ptr = NonNull::from(&mut *thinker);
+        thinker.obj.thinker = Some(ptr);
+        sec.specialdata = Some(SpecialData::Platform(ptr));
+        level.platforms.push(thinker);
+    }
+    rtn
+}
+
+/// P_ActivateInStasis
+fn activate_in_stasis(tag: i16, level: &mut Level) {
+    for plat in level.platforms.iter_mut() {
+        let plat = &mut plat.obj;
+        if plat.tag == tag && plat.status == PlatEnum::in_stasis {
+            plat.status = plat.old_status;
+        }
+    }
+}
+
+/// EV_StopPlat
+pub(crate) fn ev_stop_plat(line: DPtr<LineDef>, level: &mut Level) {
+    for plat in level.platforms.iter_mut() {
+        let plat = &mut plat.obj;
+        if plat.status != PlatEnum::in_stasis && plat.tag == line.tag {
+            plat.old_status = plat.status;
+            plat.status = PlatEnum::in_stasis;
+        }
+    }
+}
@@ -1,4 +1,31 @@
-use crate::{info::states::State, player::Player};
+//!	Weapon sprite animation, weapon objects.
+//!	Action functions for weapons.
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use crate::{
+    d_items::WEAPON_INFO,
+    d_thinker::ActionFunc,
+    doom_def::{AmmoType, GameMode, PowerType, WeaponType},
+    info::{
+        states::{get_state, State},
+        MapObjectType, SpriteNum, StateNum,
+    },
+    level_data::level::Level,
+    p_local::{p_random, random_spread, MELEERANGE, MISSILERANGE},
+    p_map_object::{MapObject, MapObjectFlag},
+    player::{Player, PlayerState, PsprNum},
+    renderer::bsp::point_to_angle_2,
+    tic_cmd::TIC_CMD_BUTTONS,
+};
+
+const LOWERSPEED: f32 = 6.0;
+const RAISESPEED: f32 = 6.0;
+
+pub(crate) const WEAPONBOTTOM: f32 = 128.0;
+pub(crate) const WEAPONTOP: f32 = 32.0;
+
+/// The BFG uses this many cells per shot
+const BFGCELLS: u32 = 40;
 
 /// From P_PSPR
 #[derive(Debug)]
@@ -11,103 +38,701 @@ pub(crate) struct PspDef {
     pub sy:    f32,
 }
 
+/// P_SetPsprite
+pub(crate) fn p_set_psprite(
+    player: &mut Player,
+    position: usize,
+    mut stnum: StateNum,
+    level: &mut Level,
+) {
+    loop {
+        if stnum as usize == StateNum::S_NULL as usize {
+            // object removed itself
+            player.psprites[position].state = None;
+            break;
+        }
+
+        let state = get_state(stnum as usize);
+        let action = state.action.clone();
+        let psp = &mut player.psprites[position];
+        // could be 0
+        psp.tics = state.tics;
+
+        if state.misc1 != 0 {
+            // coordinate set
+            psp.sx = state.misc1 as f32;
+            psp.sy = state.misc2 as f32;
+        }
+        psp.state = Some(state);
+
+        // Call action routine.
+        // Modified handling.
+        if let ActionFunc::Player(func) = action {
+            // The action is given the psprite along with the player that owns
+            // it, same as vanilla
+            let psp: *mut PspDef = &mut player.psprites[position];
+            unsafe { (*func)(player, &mut *psp, level) };
+            if player.psprites[position].state.is_none() {
+                break;
+            }
+        }
+
+        let psp = &player.psprites[position];
+        stnum = psp.state.as_ref().unwrap().next_state;
+        if psp.tics != 0 {
+            break;
+        }
+    }
+}
+
+/// P_BringUpWeapon
+///
+/// Starts bringing the pending weapon up from the bottom of the screen.
+/// Uses player
+fn p_bring_up_weapon(player: &mut Player, level: &mut Level) {
+    if player.pendingweapon as usize == WeaponType::wp_nochange as usize {
+        player.pendingweapon = player.readyweapon;
+    }
+
+    if player.pendingweapon as usize == WeaponType::wp_chainsaw as usize {
+        // TODO: S_StartSound (player->mo, sfx_sawup);
+    }
+
+    let newstate = WEAPON_INFO[player.pendingweapon as usize].upstate;
+
+    player.pendingweapon = WeaponType::wp_nochange;
+    player.psprites[PsprNum::ps_weapon as usize].sy = WEAPONBOTTOM;
+
+    p_set_psprite(player, PsprNum::ps_weapon as usize, newstate, level);
+}
+
+/// P_CheckAmmo
+///
+/// Returns true if there is enough ammo to shoot. If not, selects the next
+/// weapon to use.
+fn p_check_ammo(player: &mut Player, level: &mut Level) -> bool {
+    let ammo = WEAPON_INFO[player.readyweapon as usize].ammo;
+
+    // Minimal amount for one shot varies.
+    let count = match player.readyweapon {
+        WeaponType::wp_bfg => BFGCELLS,
+        WeaponType::wp_supershotgun => 2,
+        // Regular.
+        _ => 1,
+    };
+
+    // Some do not need ammunition anyway.
+    // Return if current ammunition sufficient.
+    if ammo as usize == AmmoType::am_noammo as usize
+        || player.ammo[ammo as usize] >= count
+    {
+        return true;
+    }
+
+    // Out of ammo, pick a weapon to change to.
+    // Preferences are set here.
+    let owned = |weapon: WeaponType| player.weaponowned[weapon as usize];
+    let has = |ammo: AmmoType| player.ammo[ammo as usize];
+    player.pendingweapon = if owned(WeaponType::wp_plasma)
+        && has(AmmoType::am_cell) != 0
+        && level.game_mode != GameMode::Shareware
+    {
+        WeaponType::wp_plasma
+    } else if owned(WeaponType::wp_supershotgun)
+        && has(AmmoType::am_shell) > 2
+        && level.game_mode == GameMode::Commercial
+    {
+        WeaponType::wp_supershotgun
+    } else if owned(WeaponType::wp_chaingun) && has(AmmoType::am_clip) != 0 {
+        WeaponType::wp_chaingun
+    } else if owned(WeaponType::wp_shotgun) && has(AmmoType::am_shell) != 0 {
+        WeaponType::wp_shotgun
+    } else if has(AmmoType::am_clip) != 0 {
+        WeaponType::wp_pistol
+    } else if owned(WeaponType::wp_chainsaw) {
+        WeaponType::wp_chainsaw
+    } else if owned(WeaponType::wp_missile) && has(AmmoType::am_misl) != 0 {
+        WeaponType::wp_missile
+    } else if owned(WeaponType::wp_bfg)
+        && has(AmmoType::am_cell) > BFGCELLS
+        && level.game_mode != GameMode::Shareware
+    {
+        WeaponType::wp_bfg
+    } else {
+        // If everything fails.
+        WeaponType::wp_fist
+    };
+
+    // Now set appropriate weapon overlay.
+    let newstate = WEAPON_INFO[player.readyweapon as usize].downstate;
+    p_set_psprite(player, PsprNum::ps_weapon as usize, newstate, level);
+
+    false
+}
+
+/// Takes the ammo for a shot of the ready weapon
+fn decrease_ammo(player: &mut Player, amount: u32) {
+    let ammo = WEAPON_INFO[player.readyweapon as usize].ammo as usize;
+    player.ammo[ammo] = player.ammo[ammo].saturating_sub(amount);
+}
+
+/// P_FireWeapon.
+fn p_fire_weapon(player: &mut Player, level: &mut Level) {
+    if !p_check_ammo(player, level) {
+        return;
+    }
+
+    if let Some(ref mut mo) = player.mobj {
+        mo.obj.p_set_mobj_state(StateNum::S_PLAY_ATK1, level);
+    }
+    let newstate = WEAPON_INFO[player.readyweapon as usize].atkstate;
+    p_set_psprite(player, PsprNum::ps_weapon as usize, newstate, level);
+    // TODO: P_NoiseAlert (player->mo, player->mo);
+}
+
+/// P_DropWeapon
+///
+/// Player died, so put the weapon away.
+pub(crate) fn p_drop_weapon(player: &mut Player, level: &mut Level) {
+    let newstate = WEAPON_INFO[player.readyweapon as usize].downstate;
+    p_set_psprite(player, PsprNum::ps_weapon as usize, newstate, level);
+}
+
+/// P_SetupPsprites
+///
+/// Called at start of level for each player.
+pub(crate) fn p_setup_psprites(player: &mut Player, level: &mut Level) {
+    // remove all psprites
+    for psp in player.psprites.iter_mut() {
+        psp.state = None;
+    }
+
+    // spawn the gun
+    player.pendingweapon = player.readyweapon;
+    p_bring_up_weapon(player, level);
+}
+
+/// P_MovePsprites
+///
+/// Called every tic by player thinking routine.
+pub(crate) fn p_move_psprites(player: &mut Player, level: &mut Level) {
+    for i in 0..player.psprites.len() {
+        let psp = &mut player.psprites[i];
+        // a null state means not active
+        if let Some(ref state) = psp.state {
+            // drop tic count and possibly change state
+            // a -1 tic count never changes
+            if psp.tics != -1 {
+                psp.tics -= 1;
+                if psp.tics == 0 {
+                    let next = state.next_state;
+                    p_set_psprite(player, i, next, level);
+                }
+            }
+        }
+    }
+
+    let weapon = &player.psprites[PsprNum::ps_weapon as usize];
+    let (sx, sy) = (weapon.sx, weapon.sy);
+    let flash = &mut player.psprites[PsprNum::ps_flash as usize];
+    flash.sx = sx;
+    flash.sy = sy;
+}
+
+/// P_BulletSlope
+///
+/// Sets a slope so a near miss is at aproximately
+/// the height of the intended target
+fn p_bullet_slope(player: &mut Player, level: &mut Level) -> f32 {
+    if let Some(ref mut mo) = player.mobj {
+        // see which target is to be aimed at, straight ahead then to either
+        // side by 1 << 26 in BAM
+        for offset in [0.0, PI / 32.0, -PI / 32.0].iter() {
+            let angle = mo.obj.angle + *offset;
+            if let Some((slope, _)) =
+                mo.obj.p_aim_line_attack(angle, 16.0 * 64.0, level)
+            {
+                return slope;
+            }
+        }
+    }
+    0.0
+}
+
+/// P_GunShot
+fn p_gun_shot(
+    player: &mut Player,
+    accurate: bool,
+    slope: f32,
+    level: &mut Level,
+) {
+    let damage = 5 * (p_random() as i32 % 3 + 1);
+    if let Some(ref mut mo) = player.mobj {
+        let mut angle = mo.obj.angle;
+        if !accurate {
+            angle += random_spread(18);
+        }
+        mo.obj
+            .p_line_attack(angle, MISSILERANGE, slope, damage, level);
+    }
+}
+
+/// The player can fire the weapon
+/// or change to another weapon at this time.
+/// Follows after getting weapon up,
+/// or after previous attack/fire sequence.
+pub(crate) fn a_weaponready(
+    actor: &mut Player,
+    pspr: &mut PspDef,
+    level: &mut Level,
+) {
+    // get out of attack state
+    if let Some(ref mut mo) = actor.mobj {
+        // The states are copies so compare what they are made of
+        let state = &mo.obj.state;
+        if state.sprite as usize == SpriteNum::SPR_PLAY as usize
+            && (state.frame == get_state(StateNum::S_PLAY_ATK1 as usize).frame
+                || state.frame
+                    == get_state(StateNum::S_PLAY_ATK2 as usize).frame)
+        {
+            mo.obj.p_set_mobj_state(StateNum::S_PLAY, level);
+        }
+    }
+
+    if actor.readyweapon as usize == WeaponType::wp_chainsaw as usize {
+        // TODO: if psp->state == &states[S_SAW] S_StartSound (player->mo, sfx_sawidl);
+    }
+
+    // check for change
+    //  if player is dead, put the weapon away
+    if actor.pendingweapon as usize != WeaponType::wp_nochange as usize
+        || actor.health == 0
+    {
+        // change weapon
+        //  (pending weapon should allready be validated)
+        let newstate = WEAPON_INFO[actor.readyweapon as usize].downstate;
+        p_set_psprite(actor, PsprNum::ps_weapon as usize, newstate, level);
+        return;
+    }
+
+    // check for fire
+    //  the missile launcher and bfg do not auto fire
+    if actor.cmd.buttons & TIC_CMD_BUTTONS.bt_attack != 0 {
+        if !actor.attackdown
+            || (actor.readyweapon as usize != WeaponType::wp_missile as usize
+                && actor.readyweapon as usize != WeaponType::wp_bfg as usize)
+        {
+            actor.attackdown = true;
+            p_fire_weapon(actor, level);
+            return;
+        }
+    } else {
+        actor.attackdown = false;
+    }
+
+    // bob the weapon based on movement speed
+    let angle =
+        (level.level_time.wrapping_mul(128) & 8191) as f32 * PI / 4096.0;
+    pspr.sx = 1.0 + actor.bob * angle.cos();
+    let angle =
+        (level.level_time.wrapping_mul(128) & 4095) as f32 * PI / 4096.0;
+    pspr.sy = WEAPONTOP + actor.bob * angle.sin();
+}
+
 /// The player can re-fire the weapon
 /// without lowering it entirely.
-pub(crate) fn a_refire(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
-    // // check for fire
-    // //  (if a weaponchange is pending, let it go through instead)
-    //         if ((player -> cmd.buttons & BT_ATTACK) &&player -> pendingweapon == wp_nochange && player -> health)
-    //         {
-    //             player -> refire + +;
-    //             P_FireWeapon(player);
-    //         }
-    //         else
-    //         {
-    //             player -> refire = 0;
-    //             P_CheckAmmo(player);
-    //         }
-    //     }
+pub(crate) fn a_refire(
+    actor: &mut Player,
+    _pspr: &mut PspDef,
+    level: &mut Level,
+) {
+    // check for fire
+    //  (if a weaponchange is pending, let it go through instead)
+    if actor.cmd.buttons & TIC_CMD_BUTTONS.bt_attack != 0
+        && actor.pendingweapon as usize == WeaponType::wp_nochange as usize
+        && actor.health != 0
+    {
+        actor.refire += 1;
+        p_fire_weapon(actor, level);
+    } else {
+        actor.refire = 0;
+        p_check_ammo(actor, level);
+    }
 }
 
-pub(crate) fn a_weaponready(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+pub(crate) fn a_checkreload(
+    actor: &mut Player,
+    _pspr: &mut PspDef,
+    level: &mut Level,
+) {
+    p_check_ammo(actor, level);
 }
 
-pub(crate) fn a_lower(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+/// Lowers current weapon,
+///  and changes weapon at bottom.
+pub(crate) fn a_lower(
+    actor: &mut Player,
+    pspr: &mut PspDef,
+    level: &mut Level,
+) {
+    pspr.sy += LOWERSPEED;
+
+    // Is already down.
+    if pspr.sy < WEAPONBOTTOM {
+        return;
+    }
+
+    // Player is dead.
+    if actor.player_state == PlayerState::PstDead {
+        pspr.sy = WEAPONBOTTOM;
+        // don't bring weapon back up
+        return;
+    }
+
+    // The old weapon has been lowered off the screen,
+    // so change the weapon and start raising it
+    if actor.health == 0 {
+        // Player is dead, so keep the weapon off screen.
+        p_set_psprite(
+            actor,
+            PsprNum::ps_weapon as usize,
+            StateNum::S_NULL,
+            level,
+        );
+        return;
+    }
+
+    actor.readyweapon = actor.pendingweapon;
+
+    p_bring_up_weapon(actor, level);
+}
+
+pub(crate) fn a_raise(
+    actor: &mut Player,
+    pspr: &mut PspDef,
+    level: &mut Level,
+) {
+    pspr.sy -= RAISESPEED;
+
+    if pspr.sy > WEAPONTOP {
+        return;
+    }
+
+    pspr.sy = WEAPONTOP;
+
+    // The weapon has been raised all the way,
+    //  so change to the ready state.
+    let newstate = WEAPON_INFO[actor.readyweapon as usize].readystate;
+
+    p_set_psprite(actor, PsprNum::ps_weapon as usize, newstate, level);
 }
 
-pub(crate) fn a_raise(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+pub(crate) fn a_gunflash(
+    actor: &mut Player,
+    _pspr: &mut PspDef,
+    level: &mut Level,
+) {
+    if let Some(ref mut mo) = actor.mobj {
+        mo.obj.p_set_mobj_state(StateNum::S_PLAY_ATK2, level);
+    }
+    let newstate = WEAPON_INFO[actor.readyweapon as usize].flashstate;
+    p_set_psprite(actor, PsprNum::ps_flash as usize, newstate, level);
 }
 
-pub(crate) fn a_firepistol(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+/// Sets the player mobj in to the firing state, uses `amount` of ammo and
+/// starts the weapon's flash. The start of most weapon fire actions.
+fn fire_weapon_start(actor: &mut Player, amount: u32, level: &mut Level) {
+    if let Some(ref mut mo) = actor.mobj {
+        mo.obj.p_set_mobj_state(StateNum::S_PLAY_ATK2, level);
+    }
+    decrease_ammo(actor, amount);
+    let newstate = WEAPON_INFO[actor.readyweapon as usize].flashstate;
+    p_set_psprite(actor, PsprNum::ps_flash as usize, newstate, level);
 }
 
-pub(crate) fn a_fireshotgun(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+pub(crate) fn a_punch(
+    actor: &mut Player,
+    _pspr: &mut PspDef,
+    level: &mut Level,
+) {
+    let mut damage = (p_random() as i32 % 10 + 1) << 1;
+
+    if actor.powers[PowerType::pw_strength as usize] != 0 {
+        damage *= 10;
+    }
+
+    if let Some(ref mut mo) = actor.mobj {
+        let angle = mo.obj.angle + random_spread(18);
+        let target = mo.obj.p_aim_line_attack(angle, MELEERANGE, level);
+        let slope = target.map_or(0.0, |(slope, _)| slope);
+        mo.obj
+            .p_line_attack(angle, MELEERANGE, slope, damage, level);
+
+        // turn to face target
+        if let Some((_, target)) = target {
+            // TODO: S_StartSound (player->mo, sfx_punch);
+            let target = unsafe { target.as_ref() };
+            mo.obj.angle = point_to_angle_2(&target.xy, &mo.obj.xy);
+        }
+    }
 }
 
-pub(crate) fn a_fireshotgun2(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+pub(crate) fn a_saw(actor: &mut Player, _pspr: &mut PspDef, level: &mut Level) {
+    let damage = 2 * (p_random() as i32 % 10 + 1);
+
+    if let Some(ref mut mo) = actor.mobj {
+        let angle = mo.obj.angle + random_spread(18);
+
+        // use meleerange + 1 se the puff doesn't skip the flash
+        let target = mo.obj.p_aim_line_attack(angle, MELEERANGE + 1.0, level);
+        let slope = target.map_or(0.0, |(slope, _)| slope);
+        mo.obj
+            .p_line_attack(angle, MELEERANGE + 1.0, slope, damage, level);
+
+        let target = match target {
+            Some((_, target)) => unsafe { target.as_ref() },
+            None => {
+                // TODO: S_StartSound (player->mo, sfx_sawful);
+                return;
+            }
+        };
+        // TODO: S_StartSound (player->mo, sfx_sawhit);
+
+        // turn to face target
+        let angle = point_to_angle_2(&target.xy, &mo.obj.xy);
+        let delta = (angle - mo.obj.angle).rad();
+        if delta > PI {
+            if delta - 2.0 * PI < -FRAC_PI_2 / 20.0 {
+                mo.obj.angle = angle + FRAC_PI_2 / 21.0;
+            } else {
+                mo.obj.angle -= FRAC_PI_2 / 20.0;
+            }
+        } else if delta > FRAC_PI_2 / 20.0 {
+            mo.obj.angle = angle - FRAC_PI_2 / 21.0;
+        } else {
+            mo.obj.angle += FRAC_PI_2 / 20.0;
+        }
+        mo.obj.flags |= MapObjectFlag::MF_JUSTATTACKED as u32;
+    }
 }
 
-pub(crate) fn a_firecgun(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+pub(crate) fn a_firepistol(
+    actor: &mut Player,
+    _pspr: &mut PspDef,
+    level: &mut Level,
+) {
+    // TODO: S_StartSound (player->mo, sfx_pistol);
+    fire_weapon_start(actor, 1, level);
+
+    let slope = p_bullet_slope(actor, level);
+    let accurate = actor.refire == 0;
+    p_gun_shot(actor, accurate, slope, level);
 }
 
-pub(crate) fn a_fireplasma(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+pub(crate) fn a_fireshotgun(
+    actor: &mut Player,
+    _pspr: &mut PspDef,
+    level: &mut Level,
+) {
+    // TODO: S_StartSound (player->mo, sfx_shotgn);
+    fire_weapon_start(actor, 1, level);
+
+    let slope = p_bullet_slope(actor, level);
+    for _ in 0..7 {
+        p_gun_shot(actor, false, slope, level);
+    }
 }
 
-pub(crate) fn a_firemissile(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+pub(crate) fn a_fireshotgun2(
+    actor: &mut Player,
+    _pspr: &mut PspDef,
+    level: &mut Level,
+) {
+    // TODO: S_StartSound (player->mo, sfx_dshtgn);
+    fire_weapon_start(actor, 2, level);
+
+    let slope = p_bullet_slope(actor, level);
+    if let Some(ref mut mo) = actor.mobj {
+        for _ in 0..20 {
+            let damage = 5 * (p_random() as i32 % 3 + 1);
+            let angle = mo.obj.angle + random_spread(19);
+            // (P_Random() - P_Random()) << 5 as a fixed point slope
+            let spread = (p_random() as f32 - p_random() as f32) / 2048.0;
+            mo.obj.p_line_attack(
+                angle,
+                MISSILERANGE,
+                slope + spread,
+                damage,
+                level,
+            );
+        }
+    }
 }
 
-pub(crate) fn a_firebfg(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+pub(crate) fn a_firecgun(
+    actor: &mut Player,
+    pspr: &mut PspDef,
+    level: &mut Level,
+) {
+    // TODO: S_StartSound (player->mo, sfx_pistol);
+    let ammo = WEAPON_INFO[actor.readyweapon as usize].ammo as usize;
+    if actor.ammo[ammo] == 0 {
+        return;
+    }
+
+    if let Some(ref mut mo) = actor.mobj {
+        mo.obj.p_set_mobj_state(StateNum::S_PLAY_ATK2, level);
+    }
+    decrease_ammo(actor, 1);
+
+    // The flash matches which of S_CHAIN1 and S_CHAIN2 fired
+    let newstate = match pspr.state {
+        Some(ref state) if state.frame != 0 => StateNum::S_CHAINFLASH2,
+        _ => StateNum::S_CHAINFLASH1,
+    };
+    p_set_psprite(actor, PsprNum::ps_flash as usize, newstate, level);
+
+    let slope = p_bullet_slope(actor, level);
+    let accurate = actor.refire == 0;
+    p_gun_shot(actor, accurate, slope, level);
 }
 
-pub(crate) fn a_bfgsound(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+pub(crate) fn a_firemissile(
+    actor: &mut Player,
+    _pspr: &mut PspDef,
+    level: &mut Level,
+) {
+    decrease_ammo(actor, 1);
+    if let Some(ref mut mo) = actor.mobj {
+        mo.obj
+            .p_spawn_player_missile(MapObjectType::MT_ROCKET, level);
+    }
 }
 
-pub(crate) fn a_gunflash(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+pub(crate) fn a_fireplasma(
+    actor: &mut Player,
+    _pspr: &mut PspDef,
+    level: &mut Level,
+) {
+    decrease_ammo(actor, 1);
+
+    let newstate = if p_random() & 1 == 0 {
+        StateNum::S_PLASMAFLASH1
+    } else {
+        StateNum::S_PLASMAFLASH2
+    };
+    p_set_psprite(actor, PsprNum::ps_flash as usize, newstate, level);
+
+    if let Some(ref mut mo) = actor.mobj {
+        mo.obj
+            .p_spawn_player_missile(MapObjectType::MT_PLASMA, level);
+    }
 }
 
-pub(crate) fn a_punch(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+pub(crate) fn a_firebfg(
+    actor: &mut Player,
+    _pspr: &mut PspDef,
+    level: &mut Level,
+) {
+    decrease_ammo(actor, BFGCELLS);
+    if let Some(ref mut mo) = actor.mobj {
+        mo.obj.p_spawn_player_missile(MapObjectType::MT_BFG, level);
+    }
 }
 
-pub(crate) fn a_checkreload(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+pub(crate) fn a_bfgsound(
+    _actor: &mut Player,
+    _pspr: &mut PspDef,
+    _level: &mut Level,
+) {
+    // TODO: S_StartSound (player->mo, sfx_bfg), this action only plays a sound
 }
 
-pub(crate) fn a_openshotgun2(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+/// A_BFGSpray, run by the BFG ball when it lands. Traces 40 lines over a 90
+/// degree arc from the player that fired it, damaging the first thing hit by
+/// each.
+pub(crate) fn a_bfgspray(mo: &mut MapObject, level: &mut Level) {
+    // mo.target is the originator (player) of the missile
+    let mut source = match mo.target {
+        Some(source) => source,
+        None => return,
+    };
+
+    // offset angles from its attack angle
+    for i in 0..40 {
+        let angle = mo.angle - FRAC_PI_2 / 2.0 + FRAC_PI_2 / 40.0 * i as f32;
+        let source_obj = unsafe { source.as_mut() };
+        let mut target =
+            match source_obj.p_aim_line_attack(angle, 16.0 * 64.0, level) {
+                Some((_, target)) => target,
+                None => continue,
+            };
+        let target = unsafe { target.as_mut() };
+
+        let extra = MapObject::p_spawn_map_object(
+            target.xy.x(),
+            target.xy.y(),
+            (target.z + target.height / 4.0) as i32,
+            MapObjectType::MT_EXTRABFG as u16,
+            level,
+        );
+        if level.add_thinker(extra).is_none() {
+            println!("A_BFGSpray: no room for another thinker");
+        }
+
+        let mut damage = 0;
+        for _ in 0..15 {
+            damage += (p_random() & 7) as i32 + 1;
+        }
+        target.p_damage_mobj(Some(source), Some(source), damage, level);
+    }
 }
 
-pub(crate) fn a_loadshotgun2(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+pub(crate) fn a_openshotgun2(
+    _actor: &mut Player,
+    _pspr: &mut PspDef,
+    _level: &mut Level,
+) {
+    // TODO: S_StartSound (player->mo, sfx_dbopn), this action only plays a sound
 }
 
-pub(crate) fn a_closeshotgun2(actor: &mut Player, _pspr: &mut PspDef) {
-    unimplemented!()
+pub(crate) fn a_loadshotgun2(
+    _actor: &mut Player,
+    _pspr: &mut PspDef,
+    _level: &mut Level,
+) {
+    // TODO: S_StartSound (player->mo, sfx_dbload), this action only plays a sound
 }
 
-pub(crate) fn a_saw(actor: &mut Player, _pspr: &mut PspDef) { unimplemented!() }
+pub(crate) fn a_closeshotgun2(
+    actor: &mut Player,
+    pspr: &mut PspDef,
+    level: &mut Level,
+) {
+    // TODO: S_StartSound (player->mo, sfx_dbcls);
+    a_refire(actor, pspr, level);
+}
 
-pub(crate) fn a_light0(actor: &mut Player, _pspr: &mut PspDef) {
+pub(crate) fn a_light0(
+    actor: &mut Player,
+    _pspr: &mut PspDef,
+    _level: &mut Level,
+) {
     actor.extralight = 0;
 }
 
-pub(crate) fn a_light1(actor: &mut Player, _pspr: &mut PspDef) {
+pub(crate) fn a_light1(
+    actor: &mut Player,
+    _pspr: &mut PspDef,
+    _level: &mut Level,
+) {
     actor.extralight = 1;
 }
 
-pub(crate) fn a_light2(actor: &mut Player, _pspr: &mut PspDef) {
+pub(crate) fn a_light2(
+    actor: &mut Player,
+    _pspr: &mut PspDef,
+    _level: &mut Level,
+) {
     actor.extralight = 2;
 }
@@ -0,0 +1,944 @@
+//! Saving and loading of games, and the archiving of the players and world
+//! state that goes in to a save.
+//!
+//! The file layout follows vanilla for the header:
+//!
+//! | Field Size | Content                                 |
+//! |------------|-----------------------------------------|
+//! | 24 bytes   | Description as typed in the save menu   |
+//! | 16 bytes   | Version string, "version 109"           |
+//! | 1 byte     | Skill                                   |
+//! | 1 byte     | Episode                                 |
+//! | 1 byte     | Map                                     |
+//! | 4 bytes    | Players in game                         |
+//! | 3 bytes    | Level time, big endian                  |
+//!
+//! Following this is the archived players, world, thinkers and specials.
+//! Vanilla writes the C structs directly which we can't do, so these are our
+//! own format and not compatible with vanilla saves. Pointers are saved as
+//! the index of what they point to, or not at all where vanilla also drops
+//! them, such as the targets of monsters.
+
+use std::{
+    fs::File,
+    io::prelude::*,
+    path::{Path, PathBuf},
+};
+
+use glam::Vec2;
+
+use std::ptr::NonNull;
+
+use wad::lumps::WadThing;
+
+use crate::{
+    angle::Angle,
+    d_thinker::{ActionFunc, Thinker},
+    doom_def::DOOM_VERSION,
+    info::{
+        states::{get_state, State, STATES},
+        MapObjectType, StateNum,
+    },
+    level_data::{level::Level, map_defs::Sector},
+    p_map_object::MapObject,
+    p_player_sprite::p_setup_psprites,
+    p_spec::{
+        Button, ButtonWhere, CeilingMove, FireFlicker, FloorMove, Glow,
+        LightFlash, Platform, SpecialData, Strobe, VerticalDoor,
+    },
+    player::Player,
+    DPtr,
+};
+
+pub(crate) const SAVESTRINGSIZE: usize = 24;
+const VERSIONSIZE: usize = 16;
+/// Marks the end of a save game
+const SAVE_END: u8 = 0x1d;
+
+/// The save file for a menu slot, `doomsav<slot>.dsg` in the working dir
+pub(crate) fn save_game_file(slot: usize) -> PathBuf {
+    format!("doomsav{}.dsg", slot).into()
+}
+
+fn version_string() -> [u8; VERSIONSIZE] {
+    let mut buf = [0u8; VERSIONSIZE];
+    let version = format!("version {}", DOOM_VERSION);
+    buf[..version.len()].copy_from_slice(version.as_bytes());
+    buf
+}
+
+/// M_ReadSaveStrings, for a single save file
+pub(crate) fn read_description(path: &Path) -> Option<String> {
+    let mut file = File::open(path).ok()?;
+    let mut buf = [0u8; SAVESTRINGSIZE];
+    file.read_exact(&mut buf).ok()?;
+    Some(
+        String::from_utf8_lossy(&buf)
+            .trim_end_matches('\u{0}')
+            .to_owned(),
+    )
+}
+
+/// The header data read from a save game
+pub(crate) struct SaveHeader {
+    pub skill:          u8,
+    pub episode:        u8,
+    pub map:            u8,
+    pub player_in_game: [bool; 4],
+    pub level_time:     u32,
+}
+
+/// Writes the save game to a byte buffer which can then be written out
+pub(crate) struct SaveWriter {
+    buf: Vec<u8>,
+}
+
+impl SaveWriter {
+    pub fn new(description: &str, header: SaveHeader) -> Self {
+        let mut buf = Vec::with_capacity(4096);
+        let mut desc = [0u8; SAVESTRINGSIZE];
+        let len = description.len().min(SAVESTRINGSIZE);
+        desc[..len].copy_from_slice(&description.as_bytes()[..len]);
+        buf.extend_from_slice(&desc);
+        buf.extend_from_slice(&version_string());
+        buf.push(header.skill);
+        buf.push(header.episode);
+        buf.push(header.map);
+        for p in header.player_in_game.iter() {
+            buf.push(*p as u8);
+        }
+        buf.push((header.level_time >> 16) as u8);
+        buf.push((header.level_time >> 8) as u8);
+        buf.push(header.level_time as u8);
+
+        Self { buf }
+    }
+
+    fn write_i32(&mut self, v: i32) {
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn write_f32(&mut self, v: f32) {
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn write_bool(&mut self, v: bool) { self.buf.push(v as u8); }
+
+    /// Finish the save and write it to file
+    pub fn write_file(mut self, path: &Path) -> std::io::Result<()> {
+        self.buf.push(SAVE_END);
+        let mut file = File::create(path)?;
+        file.write_all(&self.buf)
+    }
+}
+
+/// Reads a save game from a file. Reads past the end of the data produce
+/// zeroes rather than panicking, the end marker is checked at the end.
+pub(crate) struct SaveReader {
+    buf: Vec<u8>,
+    pos: usize,
+}
+
+impl SaveReader {
+    pub fn new(path: &Path) -> std::io::Result<Self> {
+        let mut file = File::open(path)?;
+        let mut buf = Vec::new();
+        file.read_to_end(&mut buf)?;
+        Ok(Self { buf, pos: 0 })
+    }
+
+    /// Read the header, `None` if the version is not the same
+    pub fn read_header(&mut self) -> Option<SaveHeader> {
+        self.pos = SAVESTRINGSIZE;
+        if self.buf.len() < SAVESTRINGSIZE + VERSIONSIZE + 10
+            || self.buf[self.pos..self.pos + VERSIONSIZE] != version_string()
+        {
+            return None;
+        }
+        self.pos += VERSIONSIZE;
+
+        let skill = self.read_u8();
+        let episode = self.read_u8();
+        let map = self.read_u8();
+        let mut player_in_game = [false; 4];
+        for p in player_in_game.iter_mut() {
+            *p = self.read_u8() != 0;
+        }
+        let level_time = (self.read_u8() as u32) << 16
+            | (self.read_u8() as u32) << 8
+            | self.read_u8() as u32;
+
+        Some(SaveHeader {
+            skill,
+            episode,
+            map,
+            player_in_game,
+            level_time,
+        })
+    }
+
+    fn read_u8(&mut self) -> u8 {
+        let v = self.buf.get(self.pos).copied().unwrap_or(0);
+        self.pos += 1;
+        v
+    }
+
+    fn read_4(&mut self) -> [u8; 4] {
+        let mut b = [0u8; 4];
+        for byte in b.iter_mut() {
+            *byte = self.read_u8();
+        }
+        b
+    }
+
+    fn read_i32(&mut self) -> i32 { i32::from_le_bytes(self.read_4()) }
+
+    fn read_f32(&mut self) -> f32 { f32::from_le_bytes(self.read_4()) }
+
+    fn read_bool(&mut self) -> bool { self.read_u8() != 0 }
+
+    /// Check the end marker is where it should be
+    pub fn is_complete(&mut self) -> bool { self.read_u8() == SAVE_END }
+}
+
+/// P_ArchivePlayers
+pub(crate) fn archive_players(
+    players: &[Player],
+    player_in_game: &[bool],
+    save: &mut SaveWriter,
+) {
+    for (i, player) in players.iter().enumerate() {
+        if !player_in_game[i] {
+            continue;
+        }
+
+        save.write_i32(player.health);
+        save.write_i32(player.armorpoints);
+        save.write_i32(player.armortype);
+        for p in player.powers.iter() {
+            save.write_i32(*p);
+        }
+        for c in player.cards.iter() {
+            save.write_bool(*c);
+        }
+        save.write_bool(player.backpack);
+        for w in player.weaponowned.iter() {
+            save.write_bool(*w);
+        }
+        for a in player.ammo.iter() {
+            save.write_i32(*a as i32);
+        }
+        for a in player.maxammo.iter() {
+            save.write_i32(*a as i32);
+        }
+        save.write_i32(player.readyweapon as i32);
+        save.write_i32(player.killcount);
+        save.write_i32(player.itemcount);
+        save.write_i32(player.secretcount);
+        save.write_bool(player.didsecret);
+
+        if let Some(ref mobj) = player.mobj {
+            save.write_f32(mobj.obj.xy.x());
+            save.write_f32(mobj.obj.xy.y());
+            save.write_f32(mobj.obj.z);
+            save.write_f32(mobj.obj.angle.rad());
+            save.write_f32(mobj.obj.floorz);
+            save.write_f32(mobj.obj.ceilingz);
+        } else {
+            for _ in 0..6 {
+                save.write_f32(0.0);
+            }
+        }
+    }
+}
+
+/// P_UnArchivePlayers
+///
+/// The level must be loaded and the players spawned before this is called
+pub(crate) fn unarchive_players(
+    players: &mut [Player],
+    player_in_game: &[bool],
+    level: &mut Level,
+    save: &mut SaveReader,
+) {
+    for (i, player) in players.iter_mut().enumerate() {
+        if !player_in_game[i] {
+            continue;
+        }
+
+        player.health = save.read_i32();
+        player.armorpoints = save.read_i32();
+        player.armortype = save.read_i32();
+        for p in player.powers.iter_mut() {
+            *p = save.read_i32();
+        }
+        for c in player.cards.iter_mut() {
+            *c = save.read_bool();
+        }
+        player.backpack = save.read_bool();
+        for w in player.weaponowned.iter_mut() {
+            *w = save.read_bool();
+        }
+        for a in player.ammo.iter_mut() {
+            *a = save.read_i32() as u32;
+        }
+        for a in player.maxammo.iter_mut() {
+            *a = save.read_i32() as u32;
+        }
+        player.readyweapon = save.read_i32().into();
+        player.pendingweapon = player.readyweapon;
+        player.killcount = save.read_i32();
+        player.itemcount = save.read_i32();
+        player.secretcount = save.read_i32();
+        player.didsecret = save.read_bool();
+
+        let xy = Vec2::new(save.read_f32(), save.read_f32());
+        let z = save.read_f32();
+        let angle = save.read_f32();
+        let floorz = save.read_f32();
+        let ceilingz = save.read_f32();
+        if let Some(ref mut mobj) = player.mobj {
+            let mobj = &mut mobj.obj;
+            mobj.health = player.health;
+            mobj.unset_thing_position(level);
+            mobj.xy = xy;
+            mobj.angle = Angle::new(angle);
+            mobj.set_thing_position(level);
+            // The sectors aren't restored yet so these are saved
+            mobj.floorz = floorz;
+            mobj.ceilingz = ceilingz;
+            mobj.z = z;
+            mobj.store_previous();
+        }
+
+        // The psprites aren't saved, bring the ready weapon up
+        p_setup_psprites(player, level);
+    }
+}
+
+/// P_ArchiveWorld
+pub(crate) fn archive_world(level: &Level, save: &mut SaveWriter) {
+    for sector in level.map_data.get_sectors() {
+        save.write_f32(sector.floorheight);
+        save.write_f32(sector.ceilingheight);
+        save.write_i32(sector.floorpic as i32);
+        save.write_i32(sector.ceilingpic as i32);
+        save.write_i32(sector.lightlevel as i32);
+        save.write_i32(sector.special as i32);
+        save.write_i32(sector.tag as i32);
+    }
+
+    for line in level.map_data.get_linedefs() {
+        save.write_i32(line.flags as i32);
+        save.write_i32(line.special as i32);
+        save.write_i32(line.tag as i32);
+    }
+
+    for side in level.map_data.get_sidedefs() {
+        save.write_f32(side.textureoffset);
+        save.write_f32(side.rowoffset);
+        save.write_i32(side.toptexture as i32);
+        save.write_i32(side.bottomtexture as i32);
+        save.write_i32(side.midtexture as i32);
+    }
+
+    // The switches still waiting to pop out, by line number
+    save.write_i32(level.buttons.len() as i32);
+    for button in level.buttons.iter() {
+        let line = level
+            .map_data
+            .get_linedefs()
+            .iter()
+            .position(|l| std::ptr::eq(l, button.line.as_ptr()))
+            .expect("P_ArchiveWorld: button line not in level");
+        save.write_i32(line as i32);
+        save.write_i32(button.bwhere as i32);
+        save.write_i32(button.btexture as i32);
+        save.write_i32(button.btimer);
+    }
+
+    // The animated textures and flats at the time of the save
+    for t in level.tex_translation.iter() {
+        save.write_i32(*t as i32);
+    }
+    for f in level.flat_translation.iter() {
+        save.write_i32(*f as i32);
+    }
+}
+
+/// P_UnArchiveWorld
+pub(crate) fn unarchive_world(level: &mut Level, save: &mut SaveReader) {
+    for sector in level.map_data.get_sectors_mut() {
+        sector.floorheight = save.read_f32();
+        sector.ceilingheight = save.read_f32();
+        sector.floorpic = save.read_i32() as i16;
+        sector.ceilingpic = save.read_i32() as i16;
+        sector.lightlevel = save.read_i32() as i16;
+        sector.special = save.read_i32() as i16;
+        sector.tag = save.read_i32() as i16;
+    }
+
+    for line in level.map_data.get_linedefs_mut() {
+        line.flags = save.read_i32() as i16;
+        line.special = save.read_i32() as i16;
+        line.tag = save.read_i32() as i16;
+    }
+
+    for side in level.map_data.get_sidedefs_mut() {
+        side.textureoffset = save.read_f32();
+        side.rowoffset = save.read_f32();
+        side.toptexture = save.read_i32() as i16;
+        side.bottomtexture = save.read_i32() as i16;
+        side.midtexture = save.read_i32() as i16;
+    }
+
+    level.buttons.clear();
+    for _ in 0..save.read_i32() {
+        let line = save.read_i32() as usize;
+        let bwhere = match save.read_i32() {
+            0 => ButtonWhere::top,
+            1 => ButtonWhere::middle,
+            _ => ButtonWhere::bottom,
+        };
+        let btexture = save.read_i32() as i16;
+        let btimer = save.read_i32();
+        if let Some(line) = level.map_data.get_linedefs().get(line) {
+            level.buttons.push(Button {
+                line: DPtr::new(line),
+                bwhere,
+                btexture,
+                btimer,
+            });
+        }
+    }
+
+    for t in level.tex_translation.iter_mut() {
+        *t = save.read_i32() as i16;
+    }
+    for f in level.flat_translation.iter_mut() {
+        *f = save.read_i32() as i16;
+    }
+}
+
+/// The number of a map object's state. A `State` doesn't keep its number, so
+/// it is found by what it draws and where it goes next. States matching in
+/// these behave the same once entered.
+fn state_number(state: &State) -> i32 {
+    STATES
+        .iter()
+        .position(|s| {
+            s.sprite as i32 == state.sprite as i32
+                && s.frame == state.frame
+                && s.tics == state.tics
+                && s.next_state as i32 == state.next_state as i32
+        })
+        .unwrap_or(StateNum::S_NULL as usize) as i32
+}
+
+/// P_ArchiveThinkers
+///
+/// Only map objects are in the thinker list, the sector thinkers are saved
+/// by `archive_specials` and the players' objects with the players.
+pub(crate) fn archive_thinkers(level: &Level, save: &mut SaveWriter) {
+    let mobjs: Vec<&MapObject> = level
+        .thinkers
+        .iter()
+        .flatten()
+        .filter(|t| matches!(t.function, ActionFunc::MapObject(_)))
+        .map(|t| &t.obj)
+        .collect();
+
+    save.write_i32(mobjs.len() as i32);
+    for mobj in mobjs {
+        save.write_i32(mobj.kind as i32);
+        save.write_f32(mobj.xy.x());
+        save.write_f32(mobj.xy.y());
+        save.write_f32(mobj.z);
+        save.write_f32(mobj.angle.rad());
+        save.write_f32(mobj.floorz);
+        save.write_f32(mobj.ceilingz);
+        save.write_f32(mobj.height);
+        save.write_f32(mobj.momxy.x());
+        save.write_f32(mobj.momxy.y());
+        save.write_f32(mobj.momz);
+        save.write_i32(state_number(&mobj.state));
+        save.write_i32(mobj.tics);
+        save.write_i32(mobj.flags as i32);
+        save.write_i32(mobj.health);
+        save.write_i32(mobj.movedir);
+        save.write_i32(mobj.movecount);
+        save.write_i32(mobj.reactiontime);
+        save.write_i32(mobj.threshold);
+        save.write_i32(mobj.lastlook);
+        save.write_bool(mobj.spawn_point.is_some());
+        let spawn = mobj
+            .spawn_point
+            .unwrap_or_else(|| WadThing::new(0, 0, 0, 0, 0));
+        save.write_i32(spawn.x as i32);
+        save.write_i32(spawn.y as i32);
+        save.write_i32(spawn.angle as i32);
+        save.write_i32(spawn.kind as i32);
+        save.write_i32(spawn.flags as i32);
+    }
+}
+
+/// P_UnArchiveThinkers
+///
+/// The map objects spawned with the level are removed and replaced by those
+/// saved. As in vanilla the targets of monsters and missiles aren't kept,
+/// monsters look for a new target.
+pub(crate) fn unarchive_thinkers(level: &mut Level, save: &mut SaveReader) {
+    // remove all the current thinkers
+    for i in 0..level.thinkers.len() {
+        if let Some(ref mut thinker) = level.thinkers[i] {
+            let mut mobj = NonNull::from(&mut thinker.obj);
+            unsafe { mobj.as_mut().unset_thing_position(level) };
+        }
+        level.thinkers[i] = None;
+    }
+
+    for _ in 0..save.read_i32() {
+        let kind = save.read_i32() as u16;
+        let xy = Vec2::new(save.read_f32(), save.read_f32());
+        let z = save.read_f32();
+        let angle = save.read_f32();
+        let floorz = save.read_f32();
+        let ceilingz = save.read_f32();
+        let height = save.read_f32();
+        let momxy = Vec2::new(save.read_f32(), save.read_f32());
+        let momz = save.read_f32();
+        let state = get_state(save.read_i32() as usize);
+        let tics = save.read_i32();
+        let flags = save.read_i32() as u32;
+        let health = save.read_i32();
+        let movedir = save.read_i32();
+        let movecount = save.read_i32();
+        let reactiontime = save.read_i32();
+        let threshold = save.read_i32();
+        let lastlook = save.read_i32();
+        let has_spawn = save.read_bool();
+        let spawn = WadThing::new(
+            save.read_i32() as i16,
+            save.read_i32() as i16,
+            save.read_i32() as i16,
+            save.read_i32() as i16,
+            save.read_i32() as i16,
+        );
+
+        if kind >= MapObjectType::NUMMOBJTYPES as u16 {
+            println!("P_UnArchiveThinkers: Unknown type {}", kind);
+            continue;
+        }
+
+        let mut thinker = MapObject::p_spawn_map_object(
+            xy.x(),
+            xy.y(),
+            z as i32,
+            kind,
+            level,
+        );
+        let mobj = &mut thinker.obj;
+        mobj.z = z;
+        mobj.angle = Angle::new(angle);
+        mobj.floorz = floorz;
+        mobj.ceilingz = ceilingz;
+        mobj.height = height;
+        mobj.momxy = momxy;
+        mobj.momz = momz;
+        mobj.sprite = state.sprite;
+        mobj.frame = state.frame;
+        mobj.state = state;
+        mobj.tics = tics;
+        mobj.flags = flags;
+        mobj.health = health;
+        mobj.movedir = movedir;
+        mobj.movecount = movecount;
+        mobj.reactiontime = reactiontime;
+        mobj.threshold = threshold;
+        mobj.lastlook = lastlook;
+        mobj.spawn_point = if has_spawn { Some(spawn) } else { None };
+        mobj.store_previous();
+
+        if level.add_thinker(thinker).is_none() {
+            println!("P_UnArchiveThinkers: no room for another thinker");
+        }
+    }
+}
+
+/// The number of a sector, for linking a special to it again on load
+fn sector_number(sector: &Sector, level: &Level) -> i32 {
+    level
+        .map_data
+        .get_sectors()
+        .iter()
+        .position(|s| std::ptr::eq(s, sector))
+        .expect("P_ArchiveSpecials: sector not in level") as i32
+}
+
+fn sector_from_number(number: i32, level: &Level) -> DPtr<Sector> {
+    match level.map_data.get_sectors().get(number as usize) {
+        Some(sector) => DPtr::new(sector),
+        None => {
+            panic!("P_UnArchiveSpecials: Bad sector {} in savegame", number)
+        }
+    }
+}
+
+/// P_ArchiveSpecials
+///
+/// The sector movers, including those in stasis, and the lighting effects.
+pub(crate) fn archive_specials(level: &Level, save: &mut SaveWriter) {
+    save.write_i32(level.ceilings.len() as i32);
+    for ceiling in level.ceilings.iter() {
+        let ceiling = &ceiling.obj;
+        save.write_i32(sector_number(&ceiling.sector, level));
+        save.write_i32(ceiling.kind as i32);
+        save.write_f32(ceiling.bottomheight);
+        save.write_f32(ceiling.topheight);
+        save.write_f32(ceiling.speed);
+        save.write_bool(ceiling.crush);
+        save.write_i32(ceiling.direction);
+        save.write_i32(ceiling.tag as i32);
+        save.write_i32(ceiling.olddirection);
+    }
+
+    save.write_i32(level.doors.len() as i32);
+    for door in level.doors.iter() {
+        let door = &door.obj;
+        save.write_i32(sector_number(&door.sector, level));
+        save.write_i32(door.kind as i32);
+        save.write_f32(door.topheight);
+        save.write_f32(door.speed);
+        save.write_i32(door.direction);
+        save.write_i32(door.topwait);
+        save.write_i32(door.topcountdown);
+    }
+
+    save.write_i32(level.floors.len() as i32);
+    for floor in level.floors.iter() {
+        let floor = &floor.obj;
+        save.write_i32(sector_number(&floor.sector, level));
+        save.write_i32(floor.kind as i32);
+        save.write_f32(floor.speed);
+        save.write_bool(floor.crush);
+        save.write_i32(floor.direction);
+        save.write_i32(floor.newspecial as i32);
+        save.write_i32(floor.texture as i32);
+        save.write_f32(floor.floordestheight);
+    }
+
+    save.write_i32(level.platforms.len() as i32);
+    for plat in level.platforms.iter() {
+        let plat = &plat.obj;
+        save.write_i32(sector_number(&plat.sector, level));
+        save.write_f32(plat.speed);
+        save.write_f32(plat.low);
+        save.write_f32(plat.high);
+        save.write_i32(plat.wait);
+        save.write_i32(plat.count);
+        save.write_i32(plat.status as i32);
+        save.write_i32(plat.old_status as i32);
+        save.write_bool(plat.crush);
+        save.write_i32(plat.tag as i32);
+        save.write_i32(plat.plat_type as i32);
+    }
+
+    save.write_i32(level.fire_flickers.len() as i32);
+    for flicker in level.fire_flickers.iter() {
+        let flicker = &flicker.obj;
+        save.write_i32(sector_number(&flicker.sector, level));
+        save.write_i32(flicker.count);
+        save.write_i32(flicker.max_light);
+        save.write_i32(flicker.min_light);
+    }
+
+    save.write_i32(level.light_flashes.len() as i32);
+    for flash in level.light_flashes.iter() {
+        let flash = &flash.obj;
+        save.write_i32(sector_number(&flash.sector, level));
+        save.write_i32(flash.count);
+        save.write_i32(flash.max_light);
+        save.write_i32(flash.min_light);
+        save.write_i32(flash.max_time);
+        save.write_i32(flash.min_time);
+    }
+
+    save.write_i32(level.strobes.len() as i32);
+    for strobe in level.strobes.iter() {
+        let strobe = &strobe.obj;
+        save.write_i32(sector_number(&strobe.sector, level));
+        save.write_i32(strobe.count);
+        save.write_i32(strobe.min_light);
+        save.write_i32(strobe.max_light);
+        save.write_i32(strobe.dark_time);
+        save.write_i32(strobe.bright_time);
+    }
+
+    save.write_i32(level.glows.len() as i32);
+    for glow in level.glows.iter() {
+        let glow = &glow.obj;
+        save.write_i32(sector_number(&glow.sector, level));
+        save.write_i32(glow.min_light);
+        save.write_i32(glow.max_light);
+        save.write_i32(glow.direction);
+    }
+}
+
+/// P_UnArchiveSpecials
+///
+/// Replaces the specials spawned with the level. Each mover is set as its
+/// sector's `specialdata`, as P_AddThinker does when one starts.
+pub(crate) fn unarchive_specials(level: &mut Level, save: &mut SaveReader) {
+    for sector in level.map_data.get_sectors_mut() {
+        sector.specialdata = None;
+    }
+    level.ceilings.clear();
+    level.doors.clear();
+    level.floors.clear();
+    level.platforms.clear();
+    level.fire_flickers.clear();
+    level.light_flashes.clear();
+    level.strobes.clear();
+    level.glows.clear();
+
+    for _ in 0..save.read_i32() {
+        let mut sector = sector_from_number(save.read_i32(), level);
+        let mut thinker = Box::new(Thinker::new(CeilingMove {
+            thinker:      None,
+            sector:       sector.clone(),
+            kind:         save.read_i32().into(),
+            bottomheight: save.read_f32(),
+            topheight:    save.read_f32(),
+            speed:        save.read_f32(),
+            crush:        save.read_bool(),
+            direction:    save.read_i32(),
+            tag:          save.read_i32() as i16,
+            olddirection: save.read_i32(),
+        }));
+        let ptr = NonNull::from(&mut *thinker);
+        thinker.obj.thinker = Some(ptr);
+        sector.specialdata = Some(SpecialData::Ceiling(ptr));
+        level.ceilings.push(thinker);
+    }
+
+    for _ in 0..save.read_i32() {
+        let mut sector = sector_from_number(save.read_i32(), level);
+        let mut thinker = Box::new(Thinker::new(VerticalDoor {
+            thinker:      None,
+            sector:       sector.clone(),
+            kind:         save.read_i32().into(),
+            topheight:    save.read_f32(),
+            speed:        save.read_f32(),
+            direction:    save.read_i32(),
+            topwait:      save.read_i32(),
+            topcountdown: save.read_i32(),
+        }));
+        let ptr = NonNull::from(&mut *thinker);
+        thinker.obj.thinker = Some(ptr);
+        sector.specialdata = Some(SpecialData::Door(ptr));
+        level.doors.push(thinker);
+    }
+
+    for _ in 0..save.read_i32() {
+        let mut sector = sector_from_number(save.read_i32(), level);
+        let mut thinker = Box::new(Thinker::new(FloorMove {
+            thinker:         None,
+            sector:          sector.clone(),
+            kind:            save.read_i32().into(),
+            speed:           save.read_f32(),
+            crush:           save.read_bool(),
+            direction:       save.read_i32(),
+            newspecial:      save.read_i32() as i16,
+            texture:         save.read_i32() as i16,
+            floordestheight: save.read_f32(),
+        }));
+        let ptr = NonNull::from(&mut *thinker);
+        thinker.obj.thinker = Some(ptr);
+        sector.specialdata = Some(SpecialData::Floor(ptr));
+        level.floors.push(thinker);
+    }
+
+    for _ in 0..save.read_i32() {
+        let mut sector = sector_from_number(save.read_i32(), level);
+        let mut thinker = Box::new(Thinker::new(Platform {
+            thinker:    None,
+            sector:     sector.clone(),
+            speed:      save.read_f32(),
+            low:        save.read_f32(),
+            high:       save.read_f32(),
+            wait:       save.read_i32(),
+            count:      save.read_i32(),
+            status:     save.read_i32().into(),
+            old_status: save.read_i32().into(),
+            crush:      save.read_bool(),
+            tag:        save.read_i32() as i16,
+            plat_type:  save.read_i32().into(),
+        }));
+        let ptr = NonNull::from(&mut *thinker);
+        thinker.obj.thinker = Some(ptr);
+        sector.specialdata = Some(SpecialData::Platform(ptr));
+        level.platforms.push(thinker);
+    }
+
+    for _ in 0..save.read_i32() {
+        let mut thinker = Box::new(Thinker::new(FireFlicker {
+            thinker:   None,
+            sector:    sector_from_number(save.read_i32(), level),
+            count:     save.read_i32(),
+            max_light: save.read_i32(),
+            min_light: save.read_i32(),
+        }));
+        thinker.obj.thinker = Some(NonNull::from(&mut *thinker));
+        level.fire_flickers.push(thinker);
+    }
+
+    for _ in 0..save.read_i32() {
+        let mut thinker = Box::new(Thinker::new(LightFlash {
+            thinker:   None,
+            sector:    sector_from_number(save.read_i32(), level),
+            count:     save.read_i32(),
+            max_light: save.read_i32(),
+            min_light: save.read_i32(),
+            max_time:  save.read_i32(),
+            min_time:  save.read_i32(),
+        }));
+        thinker.obj.thinker = Some(NonNull::from(&mut *thinker));
+        level.light_flashes.push(thinker);
+    }
+
+    for _ in 0..save.read_i32() {
+        let mut thinker = Box::new(Thinker::new(Strobe {
+            thinker:     None,
+            sector:      sector_from_number(save.read_i32(), level),
+            count:       save.read_i32(),
+            min_light:   save.read_i32(),
+            max_light:   save.read_i32(),
+            dark_time:   save.read_i32(),
+            bright_time: save.read_i32(),
+        }));
+        thinker.obj.thinker = Some(NonNull::from(&mut *thinker));
+        level.strobes.push(thinker);
+    }
+
+    for _ in 0..save.read_i32() {
+        let mut thinker = Box::new(Thinker::new(Glow {
+            thinker:   None,
+            sector:    sector_from_number(save.read_i32(), level),
+            min_light: save.read_i32(),
+            max_light: save.read_i32(),
+            direction: save.read_i32(),
+        }));
+        thinker.obj.thinker = Some(NonNull::from(&mut *thinker));
+        level.glows.push(thinker);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::d_main::Skill;
+    use crate::doom_def::{GameMode, MAXPLAYERS};
+    use crate::p_floor::add_floor;
+    use crate::p_spec::FloorEnum;
+    use wad::WadData;
+
+    fn e1m1_level(wad: &WadData) -> Level {
+        let mut players: [Player; MAXPLAYERS] = Default::default();
+        Level::setup_level(
+            wad,
+            Skill::Medium,
+            1,
+            1,
+            GameMode::Shareware,
+            &mut players,
+            &[false; MAXPLAYERS],
+        )
+    }
+
+    fn live_things(level: &Level) -> Vec<(u16, Vec2, i32)> {
+        level
+            .thinkers
+            .iter()
+            .flatten()
+            .filter(|t| matches!(t.function, ActionFunc::MapObject(_)))
+            .map(|t| (t.obj.kind, t.obj.xy, t.obj.health))
+            .collect()
+    }
+
+    #[test]
+    fn thinkers_and_specials_round_trip() {
+        let wad = WadData::new("../doom1.wad".into());
+        let mut level = e1m1_level(&wad);
+
+        // Take a thing out, move and hurt another, and start a floor moving
+        let mut first =
+            NonNull::from(&mut level.thinkers[0].as_mut().unwrap().obj);
+        unsafe { first.as_mut().p_remove_mobj(&mut level) };
+        level.clean_thinker_list();
+        let moved = level.thinkers[1].as_mut().unwrap();
+        moved.obj.xy += Vec2::new(8.0, -8.0);
+        moved.obj.health -= 1;
+        let sector = DPtr::new(&level.map_data.get_sectors()[3]);
+        add_floor(sector, FloorEnum::raiseFloor24, &mut level)
+            .floordestheight = 24.0;
+
+        let path = std::env::temp_dir().join("diirdoom_save_thinkers_test.dsg");
+        let header = SaveHeader {
+            skill:          2,
+            episode:        1,
+            map:            1,
+            player_in_game: [false; 4],
+            level_time:     0,
+        };
+        let mut save = SaveWriter::new("THINKERS", header);
+        archive_world(&level, &mut save);
+        archive_thinkers(&level, &mut save);
+        archive_specials(&level, &mut save);
+        save.write_file(&path).unwrap();
+
+        let mut loaded = e1m1_level(&wad);
+        let mut save = SaveReader::new(&path).unwrap();
+        save.read_header().unwrap();
+        unarchive_world(&mut loaded, &mut save);
+        unarchive_thinkers(&mut loaded, &mut save);
+        unarchive_specials(&mut loaded, &mut save);
+        std::fs::remove_file(&path).unwrap();
+        assert!(save.is_complete());
+
+        assert_eq!(live_things(&loaded), live_things(&level));
+        assert_eq!(loaded.floors.len(), 1);
+        assert_eq!(loaded.floors[0].obj.floordestheight, 24.0);
+        assert!(matches!(
+            loaded.map_data.get_sectors()[3].specialdata,
+            Some(SpecialData::Floor(_))
+        ));
+        assert_eq!(loaded.glows.len(), level.glows.len());
+    }
+
+    #[test]
+    fn save_header_round_trip() {
+        let path = std::env::temp_dir().join("diirdoom_save_header_test.dsg");
+        let header = SaveHeader {
+            skill:          3,
+            episode:        2,
+            map:            7,
+            player_in_game: [true, false, true, false],
+            level_time:     0x012345,
+        };
+        SaveWriter::new("TEST SAVE", header)
+            .write_file(&path)
+            .unwrap();
+
+        assert_eq!(read_description(&path).unwrap(), "TEST SAVE");
+        let mut save = SaveReader::new(&path).unwrap();
+        let header = save.read_header().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(header.skill, 3);
+        assert_eq!(header.episode, 2);
+        assert_eq!(header.map, 7);
+        assert_eq!(header.player_in_game, [true, false, true, false]);
+        assert_eq!(header.level_time, 0x012345);
+        assert!(save.is_complete());
+    }
+}
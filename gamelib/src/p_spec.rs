@@ -1,15 +1,81 @@
 ///	Implements special effects:
 ///	Texture animation, height or lighting changes according to adjacent sectors,
 /// respective utility functions, etc.
-use crate::angle::Angle;
 use crate::d_thinker::Thinker;
+use crate::doom_def::{PowerType, TICRATE};
+use crate::flags::LineDefFlags;
+use crate::info::MapObjectType;
+use crate::level_data::level::Level;
+use crate::level_data::map_data::MapData;
+use crate::level_data::map_defs::{LineDef, Sector};
+use crate::p_ceiling::{ev_ceiling_crush_stop, ev_do_ceiling};
+use crate::p_doors::{
+    ev_do_door, p_spawn_door_close_in_30, p_spawn_door_raise_in_5_mins,
+};
+use crate::p_floor::{add_floor, ev_build_stairs, ev_do_floor, FLOORSPEED};
+use crate::p_lights::{
+    ev_light_turn_on, ev_start_light_strobing, ev_turn_tag_lights_off,
+    p_spawn_fire_flicker, p_spawn_glowing_light, p_spawn_light_flash,
+    p_spawn_strobe_flash, FASTDARK, SLOWDARK,
+};
+use crate::p_local::p_random;
+use crate::p_map_object::MapObject;
+use crate::p_plats::{ev_do_plat, ev_stop_plat};
+use crate::p_switch::p_change_switch_texture;
+use crate::p_telept::ev_teleport;
+use crate::player::{Cheat, Player};
+use crate::DPtr;
 use std::ptr::NonNull;
-use wad::lumps::WadSector;
+use wad::WadData;
+
+/// Animating textures and planes
+///
+/// There is another anim_t used in wi_stuff, unrelated.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Anim {
+    pub istexture: bool,
+    pub picnum:    i16,
+    pub basepic:   i16,
+    pub numpics:   i16,
+    pub speed:     i32,
+}
+
+/// Floor/ceiling animation sequences, defined by first and last frame,
+/// i.e. the flat (64x64 tile) name to be used. The full animation sequence
+/// is given using all the flats between the start and end entry, in the
+/// order found in the WAD file.
+///
+/// `(istexture, endname, startname, speed)`
+const ANIMDEFS: [(bool, &str, &str, i32); 22] = [
+    (false, "NUKAGE3", "NUKAGE1", 8),
+    (false, "FWATER4", "FWATER1", 8),
+    (false, "SWATER4", "SWATER1", 8),
+    (false, "LAVA4", "LAVA1", 8),
+    (false, "BLOOD3", "BLOOD1", 8),
+    // DOOM II flat animations.
+    (false, "RROCK08", "RROCK05", 8),
+    (false, "SLIME04", "SLIME01", 8),
+    (false, "SLIME08", "SLIME05", 8),
+    (false, "SLIME12", "SLIME09", 8),
+    (true, "BLODGR4", "BLODGR1", 8),
+    (true, "SLADRIP3", "SLADRIP1", 8),
+    (true, "BLODRIP4", "BLODRIP1", 8),
+    (true, "FIREWALL", "FIREWALA", 8),
+    (true, "GSTFONT3", "GSTFONT1", 8),
+    (true, "FIRELAVA", "FIRELAV3", 8),
+    (true, "FIREMAG3", "FIREMAG1", 8),
+    (true, "FIREBLU2", "FIREBLU1", 8),
+    (true, "ROCKRED3", "ROCKRED1", 8),
+    (true, "BFALL4", "BFALL1", 8),
+    (true, "SFALL4", "SFALL1", 8),
+    (true, "WFALL4", "WFALL1", 8),
+    (true, "DBRAIN4", "DBRAIN1", 8),
+];
 
 // P_LIGHTS
 pub(crate) struct FireFlicker {
     pub thinker:   Option<NonNull<Thinker<FireFlicker>>>,
-    pub sector:    NonNull<WadSector>,
+    pub sector:    DPtr<Sector>,
     pub count:     i32,
     pub max_light: i32,
     pub min_light: i32,
@@ -17,7 +83,7 @@ pub(crate) struct FireFlicker {
 
 pub(crate) struct LightFlash {
     pub thinker:   Option<NonNull<Thinker<LightFlash>>>,
-    pub sector:    NonNull<WadSector>,
+    pub sector:    DPtr<Sector>,
     pub count:     i32,
     pub max_light: i32,
     pub min_light: i32,
@@ -27,7 +93,7 @@ pub(crate) struct LightFlash {
 
 pub(crate) struct Strobe {
     pub thinker:     Option<NonNull<Thinker<Strobe>>>,
-    pub sector:      NonNull<WadSector>,
+    pub sector:      DPtr<Sector>,
     pub count:       i32,
     pub min_light:   i32,
     pub max_light:   i32,
@@ -37,13 +103,97 @@ pub(crate) struct Strobe {
 
 pub(crate) struct Glow {
     pub thinker:   Option<NonNull<Thinker<Glow>>>,
-    pub sector:    NonNull<WadSector>,
+    pub sector:    DPtr<Sector>,
     pub min_light: i32,
     pub max_light: i32,
-    pub direction: Angle,
+    /// 1 = up, -1 = down
+    pub direction: i32,
+}
+
+/// What is moving a sector, vanilla's `sector->specialdata`. Only one
+/// mover may be active in a sector at a time.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum SpecialData {
+    Door(NonNull<Thinker<VerticalDoor>>),
+    Platform(NonNull<Thinker<Platform>>),
+    Floor(NonNull<Thinker<FloorMove>>),
+    Ceiling(NonNull<Thinker<CeilingMove>>),
+}
+
+// P_SWITCH
+/// 1 second, in ticks.
+pub(crate) const BUTTONTIME: i32 = TICRATE;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ButtonWhere {
+    top,
+    middle,
+    bottom,
+}
+
+/// A switch that is waiting to be swapped back to its off texture
+pub(crate) struct Button {
+    pub line:     DPtr<LineDef>,
+    pub bwhere:   ButtonWhere,
+    pub btexture: i16,
+    pub btimer:   i32,
+    // TODO: soundorg, for S_StartSound when the button pops out
+}
+
+// P_FLOOR
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum PlaneResult {
+    Ok,
+    Crushed,
+    PastDest,
+}
+
+// P_DOORS
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DoorKind {
+    normal,
+    close30ThenOpen,
+    close,
+    open,
+    raiseIn5Mins,
+    blazeRaise,
+    blazeOpen,
+    blazeClose,
+}
+
+/// For loading from a save game
+impl From<i32> for DoorKind {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => DoorKind::normal,
+            1 => DoorKind::close30ThenOpen,
+            2 => DoorKind::close,
+            3 => DoorKind::open,
+            4 => DoorKind::raiseIn5Mins,
+            5 => DoorKind::blazeRaise,
+            6 => DoorKind::blazeOpen,
+            _ => DoorKind::blazeClose,
+        }
+    }
+}
+
+pub(crate) struct VerticalDoor {
+    pub thinker:      Option<NonNull<Thinker<VerticalDoor>>>,
+    pub sector:       DPtr<Sector>,
+    pub kind:         DoorKind,
+    pub topheight:    f32,
+    pub speed:        f32,
+    /// 1 = up, 0 = waiting at top, -1 = down, 2 = initial wait
+    pub direction:    i32,
+    /// tics to wait at the top
+    pub topwait:      i32,
+    /// (keep in case a door going down is reset)
+    /// when it reaches 0, start going down
+    pub topcountdown: i32,
 }
 
 // P_PLATS
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum PlatEnum {
     up,
     down,
@@ -51,6 +201,19 @@ pub(crate) enum PlatEnum {
     in_stasis,
 }
 
+/// For loading from a save game
+impl From<i32> for PlatEnum {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => PlatEnum::up,
+            1 => PlatEnum::down,
+            2 => PlatEnum::waiting,
+            _ => PlatEnum::in_stasis,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum PlatType {
     perpetualRaise,
     downWaitUpStay,
@@ -59,9 +222,22 @@ pub(crate) enum PlatType {
     blazeDWUS,
 }
 
+/// For loading from a save game
+impl From<i32> for PlatType {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => PlatType::perpetualRaise,
+            1 => PlatType::downWaitUpStay,
+            2 => PlatType::raiseAndChange,
+            3 => PlatType::raiseToNearestAndChange,
+            _ => PlatType::blazeDWUS,
+        }
+    }
+}
+
 pub(crate) struct Platform {
     pub thinker:    Option<NonNull<Thinker<Platform>>>,
-    pub sector:     NonNull<WadSector>,
+    pub sector:     DPtr<Sector>,
     pub speed:      f32,
     pub low:        f32,
     pub high:       f32,
@@ -70,12 +246,13 @@ pub(crate) struct Platform {
     pub status:     PlatEnum,
     pub old_status: PlatEnum,
     pub crush:      bool,
-    pub tag:        i32,
+    pub tag:        i16,
     pub plat_type:  PlatType,
 }
 
 // P_FLOOR
 //
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum FloorEnum {
     /// lower floor to highest surrounding floor
     lowerFloor,
@@ -101,6 +278,28 @@ pub(crate) enum FloorEnum {
     raiseFloor512,
 }
 
+/// For loading from a save game
+impl From<i32> for FloorEnum {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => FloorEnum::lowerFloor,
+            1 => FloorEnum::lowerFloorToLowest,
+            2 => FloorEnum::turboLower,
+            3 => FloorEnum::raiseFloor,
+            4 => FloorEnum::raiseFloorToNearest,
+            5 => FloorEnum::raiseToTexture,
+            6 => FloorEnum::lowerAndChange,
+            7 => FloorEnum::raiseFloor24,
+            8 => FloorEnum::raiseFloor24AndChange,
+            9 => FloorEnum::raiseFloorCrush,
+            10 => FloorEnum::raiseFloorTurbo,
+            11 => FloorEnum::donutRaise,
+            _ => FloorEnum::raiseFloor512,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum StairEnum {
     /// slowly build by 8
     build8,
@@ -110,18 +309,19 @@ pub(crate) enum StairEnum {
 
 pub(crate) struct FloorMove {
     pub thinker:         Option<NonNull<Thinker<FloorMove>>>,
-    pub sector:          NonNull<WadSector>,
-    kind:                FloorEnum,
+    pub sector:          DPtr<Sector>,
+    pub kind:            FloorEnum,
     pub speed:           f32,
     pub crush:           bool,
     pub direction:       i32,
-    pub newspecial:      i32,
-    pub texture:         u8,
+    pub newspecial:      i16,
+    pub texture:         i16,
     pub floordestheight: f32,
 }
 
 // P_CEILNG
 //
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum CeilingKind {
     lowerToFloor,
     raiseToHighest,
@@ -131,9 +331,23 @@ pub(crate) enum CeilingKind {
     silentCrushAndRaise,
 }
 
+/// For loading from a save game
+impl From<i32> for CeilingKind {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => CeilingKind::lowerToFloor,
+            1 => CeilingKind::raiseToHighest,
+            2 => CeilingKind::lowerAndCrush,
+            3 => CeilingKind::crushAndRaise,
+            4 => CeilingKind::fastCrushAndRaise,
+            _ => CeilingKind::silentCrushAndRaise,
+        }
+    }
+}
+
 pub(crate) struct CeilingMove {
-    pub thinker:      Option<NonNull<Thinker<FloorMove>>>,
-    pub sector:       NonNull<WadSector>,
+    pub thinker:      Option<NonNull<Thinker<CeilingMove>>>,
+    pub sector:       DPtr<Sector>,
     pub kind:         CeilingKind,
     pub bottomheight: f32,
     pub topheight:    f32,
@@ -142,6 +356,796 @@ pub(crate) struct CeilingMove {
     // 1 = up, 0 = waiting, -1 = down
     pub direction:    i32,
     // ID
-    pub tag:          i32,
+    pub tag:          i16,
     pub olddirection: i32,
 }
+
+/// P_FindSectorFromLineTag
+///
+/// Every sector with the same tag as `line`
+pub(crate) fn find_sectors_from_line_tag(
+    line: &LineDef,
+    level: &Level,
+) -> Vec<DPtr<Sector>> {
+    level
+        .map_data
+        .get_sectors()
+        .iter()
+        .filter(|sector| sector.tag == line.tag)
+        .map(DPtr::new)
+        .collect()
+}
+
+/// getNextSector
+/// Return sector_t * of sector next to current.
+/// NULL if not two-sided line
+pub(crate) fn get_next_sector(
+    line: &LineDef,
+    sector: &Sector,
+) -> Option<DPtr<Sector>> {
+    if line.flags & LineDefFlags::TwoSided as i16 == 0 {
+        return None;
+    }
+
+    if std::ptr::eq(line.frontsector.as_ptr(), sector) {
+        return line.backsector.clone();
+    }
+    Some(line.frontsector.clone())
+}
+
+/// P_FindLowestFloorSurrounding
+pub(crate) fn find_lowest_floor_surrounding(sector: &Sector) -> f32 {
+    let mut floor = sector.floorheight;
+    for line in &sector.lines {
+        if let Some(other) = get_next_sector(line, sector) {
+            if other.floorheight < floor {
+                floor = other.floorheight;
+            }
+        }
+    }
+    floor
+}
+
+/// P_FindHighestFloorSurrounding
+pub(crate) fn find_highest_floor_surrounding(sector: &Sector) -> f32 {
+    let mut floor = -500.0;
+    for line in &sector.lines {
+        if let Some(other) = get_next_sector(line, sector) {
+            if other.floorheight > floor {
+                floor = other.floorheight;
+            }
+        }
+    }
+    floor
+}
+
+/// P_FindNextHighestFloor
+///
+/// The lowest surrounding floor above `current`, or `current` if there is
+/// none
+pub(crate) fn find_next_highest_floor(sector: &Sector, current: f32) -> f32 {
+    let mut height = current;
+    for line in &sector.lines {
+        if let Some(other) = get_next_sector(line, sector) {
+            if other.floorheight > current
+                && (height == current || other.floorheight < height)
+            {
+                height = other.floorheight;
+            }
+        }
+    }
+    height
+}
+
+/// P_FindLowestCeilingSurrounding
+pub(crate) fn find_lowest_ceiling_surrounding(sector: &Sector) -> f32 {
+    let mut height = f32::MAX;
+    for line in &sector.lines {
+        if let Some(other) = get_next_sector(line, sector) {
+            if other.ceilingheight < height {
+                height = other.ceilingheight;
+            }
+        }
+    }
+    height
+}
+
+/// P_FindHighestCeilingSurrounding
+pub(crate) fn find_highest_ceiling_surrounding(sector: &Sector) -> f32 {
+    let mut height = 0.0;
+    for line in &sector.lines {
+        if let Some(other) = get_next_sector(line, sector) {
+            if other.ceilingheight > height {
+                height = other.ceilingheight;
+            }
+        }
+    }
+    height
+}
+
+/// EV_DoDonut
+/// Special Stuff that can not be categorized
+pub(crate) fn ev_do_donut(line: DPtr<LineDef>, level: &mut Level) -> bool {
+    let mut rtn = false;
+    for s1 in find_sectors_from_line_tag(&line, level) {
+        // ALREADY MOVING?  IF SO, KEEP GOING...
+        if s1.specialdata.is_some() {
+            continue;
+        }
+        rtn = true;
+
+        // Vanilla Doom does not check if the linedef is one sided
+        let s2 = match s1.lines.first().and_then(|l| get_next_sector(l, &s1)) {
+            Some(s2) => s2,
+            None => {
+                println!("EV_DoDonut: linedef had no second sidedef");
+                break;
+            }
+        };
+
+        for line in s2.lines.iter() {
+            let s3 = match line.backsector {
+                Some(ref s3) => s3.clone(),
+                None => break,
+            };
+            if s3.as_ptr() == s1.as_ptr() {
+                continue;
+            }
+
+            //	Spawn rising slime
+            let floor = add_floor(s2.clone(), FloorEnum::donutRaise, level);
+            floor.speed = FLOORSPEED / 2.0;
+            floor.texture = s3.floorpic;
+            floor.newspecial = 0;
+            floor.floordestheight = s3.floorheight;
+
+            //	Spawn lowering donut-hole
+            let floor = add_floor(s1.clone(), FloorEnum::lowerFloor, level);
+            floor.direction = -1;
+            floor.speed = FLOORSPEED / 2.0;
+            floor.floordestheight = s3.floorheight;
+            break;
+        }
+    }
+    rtn
+}
+
+/// P_CrossSpecialLine - TRIGGER
+/// Called every time a thing origin is about
+///  to cross a line with a non 0 special.
+pub(crate) fn p_cross_special_line(
+    side: usize,
+    mut line: DPtr<LineDef>,
+    thing: &mut MapObject,
+    level: &mut Level,
+) {
+    //	Triggers that other things can activate
+    if thing.player.is_none() {
+        // Things that should NOT trigger specials...
+        if thing.kind == MapObjectType::MT_ROCKET as u16
+            || thing.kind == MapObjectType::MT_PLASMA as u16
+            || thing.kind == MapObjectType::MT_BFG as u16
+            || thing.kind == MapObjectType::MT_TROOPSHOT as u16
+            || thing.kind == MapObjectType::MT_HEADSHOT as u16
+            || thing.kind == MapObjectType::MT_BRUISERSHOT as u16
+        {
+            return;
+        }
+
+        match line.special {
+            // TELEPORT TRIGGER, TELEPORT RETRIGGER, RAISE DOOR, PLAT DOWN-WAIT-UP-STAY
+            // TRIGGER, PLAT DOWN-WAIT-UP-STAY RETRIGGER, and the monster only teleports
+            39 | 97 | 125 | 126 | 4 | 10 | 88 => {}
+            _ => return,
+        }
+    }
+
+    // Note: could use some const's here.
+    match line.special {
+        // TRIGGERS.
+        // All from here to RETRIGGERS.
+        2 => {
+            // Open Door
+            ev_do_door(line.clone(), DoorKind::open, level);
+            line.special = 0;
+        }
+        3 => {
+            // Close Door
+            ev_do_door(line.clone(), DoorKind::close, level);
+            line.special = 0;
+        }
+        4 => {
+            // Raise Door
+            ev_do_door(line.clone(), DoorKind::normal, level);
+            line.special = 0;
+        }
+        5 => {
+            // Raise Floor
+            ev_do_floor(line.clone(), FloorEnum::raiseFloor, level);
+            line.special = 0;
+        }
+        6 => {
+            // Fast Ceiling Crush & Raise
+            ev_do_ceiling(line.clone(), CeilingKind::fastCrushAndRaise, level);
+            line.special = 0;
+        }
+        8 => {
+            // Build Stairs
+            ev_build_stairs(line.clone(), StairEnum::build8, level);
+            line.special = 0;
+        }
+        10 => {
+            // PlatDownWaitUp
+            ev_do_plat(line.clone(), PlatType::downWaitUpStay, 0, level);
+            line.special = 0;
+        }
+        12 => {
+            // Light Turn On - brightest near
+            ev_light_turn_on(line.clone(), 0, level);
+            line.special = 0;
+        }
+        13 => {
+            // Light Turn On 255
+            ev_light_turn_on(line.clone(), 255, level);
+            line.special = 0;
+        }
+        16 => {
+            // Close Door 30
+            ev_do_door(line.clone(), DoorKind::close30ThenOpen, level);
+            line.special = 0;
+        }
+        17 => {
+            // Start Light Strobing
+            ev_start_light_strobing(line.clone(), level);
+            line.special = 0;
+        }
+        19 => {
+            // Lower Floor
+            ev_do_floor(line.clone(), FloorEnum::lowerFloor, level);
+            line.special = 0;
+        }
+        22 => {
+            // Raise floor to nearest height and change texture
+            ev_do_plat(
+                line.clone(),
+                PlatType::raiseToNearestAndChange,
+                0,
+                level,
+            );
+            line.special = 0;
+        }
+        25 => {
+            // Ceiling Crush and Raise
+            ev_do_ceiling(line.clone(), CeilingKind::crushAndRaise, level);
+            line.special = 0;
+        }
+        30 => {
+            // Raise floor to shortest texture height
+            //  on either side of lines.
+            ev_do_floor(line.clone(), FloorEnum::raiseToTexture, level);
+            line.special = 0;
+        }
+        35 => {
+            // Lights Very Dark
+            ev_light_turn_on(line.clone(), 35, level);
+            line.special = 0;
+        }
+        36 => {
+            // Lower Floor (TURBO)
+            ev_do_floor(line.clone(), FloorEnum::turboLower, level);
+            line.special = 0;
+        }
+        37 => {
+            // LowerAndChange
+            ev_do_floor(line.clone(), FloorEnum::lowerAndChange, level);
+            line.special = 0;
+        }
+        38 => {
+            // Lower Floor To Lowest
+            ev_do_floor(line.clone(), FloorEnum::lowerFloorToLowest, level);
+            line.special = 0;
+        }
+        39 => {
+            // TELEPORT!
+            ev_teleport(line.clone(), side, thing, level);
+            line.special = 0;
+        }
+        40 => {
+            // RaiseCeilingLowerFloor
+            ev_do_ceiling(line.clone(), CeilingKind::raiseToHighest, level);
+            ev_do_floor(line.clone(), FloorEnum::lowerFloorToLowest, level);
+            line.special = 0;
+        }
+        44 => {
+            // Ceiling Crush
+            ev_do_ceiling(line.clone(), CeilingKind::lowerAndCrush, level);
+            line.special = 0;
+        }
+        52 => {
+            // EXIT!
+            level.do_exit_level();
+        }
+        53 => {
+            // Perpetual Platform Raise
+            ev_do_plat(line.clone(), PlatType::perpetualRaise, 0, level);
+            line.special = 0;
+        }
+        54 => {
+            // Platform Stop
+            ev_stop_plat(line.clone(), level);
+            line.special = 0;
+        }
+        56 => {
+            // Raise Floor Crush
+            ev_do_floor(line.clone(), FloorEnum::raiseFloorCrush, level);
+            line.special = 0;
+        }
+        57 => {
+            // Ceiling Crush Stop
+            ev_ceiling_crush_stop(line.clone(), level);
+            line.special = 0;
+        }
+        58 => {
+            // Raise Floor 24
+            ev_do_floor(line.clone(), FloorEnum::raiseFloor24, level);
+            line.special = 0;
+        }
+        59 => {
+            // Raise Floor 24 And Change
+            ev_do_floor(line.clone(), FloorEnum::raiseFloor24AndChange, level);
+            line.special = 0;
+        }
+        104 => {
+            // Turn lights off in sector(tag)
+            ev_turn_tag_lights_off(line.clone(), level);
+            line.special = 0;
+        }
+        108 => {
+            // Blazing Door Raise (faster than TURBO!)
+            ev_do_door(line.clone(), DoorKind::blazeRaise, level);
+            line.special = 0;
+        }
+        109 => {
+            // Blazing Door Open (faster than TURBO!)
+            ev_do_door(line.clone(), DoorKind::blazeOpen, level);
+            line.special = 0;
+        }
+        100 => {
+            // Build Stairs Turbo 16
+            ev_build_stairs(line.clone(), StairEnum::turbo16, level);
+            line.special = 0;
+        }
+        110 => {
+            // Blazing Door Close (faster than TURBO!)
+            ev_do_door(line.clone(), DoorKind::blazeClose, level);
+            line.special = 0;
+        }
+        119 => {
+            // Raise floor to nearest surr. floor
+            ev_do_floor(line.clone(), FloorEnum::raiseFloorToNearest, level);
+            line.special = 0;
+        }
+        121 => {
+            // Blazing PlatDownWaitUpStay
+            ev_do_plat(line.clone(), PlatType::blazeDWUS, 0, level);
+            line.special = 0;
+        }
+        124 => {
+            // Secret EXIT
+            level.do_secret_exit_level();
+        }
+        125 => {
+            // TELEPORT MonsterONLY
+            if thing.player.is_none() {
+                ev_teleport(line.clone(), side, thing, level);
+                line.special = 0;
+            }
+        }
+        130 => {
+            // Raise Floor Turbo
+            ev_do_floor(line.clone(), FloorEnum::raiseFloorTurbo, level);
+            line.special = 0;
+        }
+        141 => {
+            // Silent Ceiling Crush & Raise
+            ev_do_ceiling(
+                line.clone(),
+                CeilingKind::silentCrushAndRaise,
+                level,
+            );
+            line.special = 0;
+        }
+
+        // RETRIGGERS.  All from here till end.
+        72 => {
+            // Ceiling Crush
+            ev_do_ceiling(line, CeilingKind::lowerAndCrush, level);
+        }
+        73 => {
+            // Ceiling Crush and Raise
+            ev_do_ceiling(line, CeilingKind::crushAndRaise, level);
+        }
+        74 => {
+            // Ceiling Crush Stop
+            ev_ceiling_crush_stop(line, level);
+        }
+        75 => {
+            // Close Door
+            ev_do_door(line, DoorKind::close, level);
+        }
+        76 => {
+            // Close Door 30
+            ev_do_door(line, DoorKind::close30ThenOpen, level);
+        }
+        77 => {
+            // Fast Ceiling Crush & Raise
+            ev_do_ceiling(line, CeilingKind::fastCrushAndRaise, level);
+        }
+        79 => {
+            // Lights Very Dark
+            ev_light_turn_on(line, 35, level);
+        }
+        80 => {
+            // Light Turn On - brightest near
+            ev_light_turn_on(line, 0, level);
+        }
+        81 => {
+            // Light Turn On 255
+            ev_light_turn_on(line, 255, level);
+        }
+        82 => {
+            // Lower Floor To Lowest
+            ev_do_floor(line, FloorEnum::lowerFloorToLowest, level);
+        }
+        83 => {
+            // Lower Floor
+            ev_do_floor(line, FloorEnum::lowerFloor, level);
+        }
+        84 => {
+            // LowerAndChange
+            ev_do_floor(line, FloorEnum::lowerAndChange, level);
+        }
+        86 => {
+            // Open Door
+            ev_do_door(line, DoorKind::open, level);
+        }
+        87 => {
+            // Perpetual Platform Raise
+            ev_do_plat(line, PlatType::perpetualRaise, 0, level);
+        }
+        88 => {
+            // PlatDownWaitUp
+            ev_do_plat(line, PlatType::downWaitUpStay, 0, level);
+        }
+        89 => {
+            // Platform Stop
+            ev_stop_plat(line, level);
+        }
+        90 => {
+            // Raise Door
+            ev_do_door(line, DoorKind::normal, level);
+        }
+        91 => {
+            // Raise Floor
+            ev_do_floor(line, FloorEnum::raiseFloor, level);
+        }
+        92 => {
+            // Raise Floor 24
+            ev_do_floor(line, FloorEnum::raiseFloor24, level);
+        }
+        93 => {
+            // Raise Floor 24 And Change
+            ev_do_floor(line, FloorEnum::raiseFloor24AndChange, level);
+        }
+        94 => {
+            // Raise Floor Crush
+            ev_do_floor(line, FloorEnum::raiseFloorCrush, level);
+        }
+        95 => {
+            // Raise floor to nearest height
+            // and change texture.
+            ev_do_plat(line, PlatType::raiseToNearestAndChange, 0, level);
+        }
+        96 => {
+            // Raise floor to shortest texture height
+            // on either side of lines.
+            ev_do_floor(line, FloorEnum::raiseToTexture, level);
+        }
+        97 => {
+            // TELEPORT!
+            ev_teleport(line, side, thing, level);
+        }
+        98 => {
+            // Lower Floor (TURBO)
+            ev_do_floor(line, FloorEnum::turboLower, level);
+        }
+        105 => {
+            // Blazing Door Raise (faster than TURBO!)
+            ev_do_door(line, DoorKind::blazeRaise, level);
+        }
+        106 => {
+            // Blazing Door Open (faster than TURBO!)
+            ev_do_door(line, DoorKind::blazeOpen, level);
+        }
+        107 => {
+            // Blazing Door Close (faster than TURBO!)
+            ev_do_door(line, DoorKind::blazeClose, level);
+        }
+        120 => {
+            // Blazing PlatDownWaitUpStay.
+            ev_do_plat(line, PlatType::blazeDWUS, 0, level);
+        }
+        126 => {
+            // TELEPORT MonsterONLY.
+            if thing.player.is_none() {
+                ev_teleport(line, side, thing, level);
+            }
+        }
+        128 => {
+            // Raise To Nearest Floor
+            ev_do_floor(line, FloorEnum::raiseFloorToNearest, level);
+        }
+        129 => {
+            // Raise Floor Turbo
+            ev_do_floor(line, FloorEnum::raiseFloorTurbo, level);
+        }
+        _ => {}
+    }
+}
+
+/// P_ShootSpecialLine - IMPACT SPECIALS
+/// Called when a thing shoots a special line.
+pub(crate) fn p_shoot_special_line(
+    line: DPtr<LineDef>,
+    thing: &MapObject,
+    level: &mut Level,
+) {
+    //	Impacts that other things can activate.
+    if thing.player.is_none() && line.special != 46 {
+        // OPEN DOOR IMPACT is the only one
+        return;
+    }
+
+    match line.special {
+        24 => {
+            // RAISE FLOOR
+            ev_do_floor(line.clone(), FloorEnum::raiseFloor, level);
+            p_change_switch_texture(line, false, level);
+        }
+        46 => {
+            // OPEN DOOR
+            ev_do_door(line.clone(), DoorKind::open, level);
+            p_change_switch_texture(line, true, level);
+        }
+        47 => {
+            // RAISE FLOOR NEAR AND CHANGE
+            ev_do_plat(
+                line.clone(),
+                PlatType::raiseToNearestAndChange,
+                0,
+                level,
+            );
+            p_change_switch_texture(line, false, level);
+        }
+        _ => {}
+    }
+}
+
+/// P_PlayerInSpecialSector
+///
+/// Called every tic frame that the player origin is in a special sector
+pub(crate) fn p_player_in_special_sector(
+    player: &mut Player,
+    level: &mut Level,
+) {
+    let ironfeet = player.powers[PowerType::pw_ironfeet as usize] != 0;
+    let mo = match player.mobj {
+        Some(ref mut mo) => &mut mo.obj,
+        None => return,
+    };
+    let mut sector = mo.subsector.sector.clone();
+
+    // Falling, not all the way down yet?
+    #[allow(clippy::float_cmp)]
+    if mo.z != sector.floorheight {
+        return;
+    }
+
+    let hurt = level.level_time & 0x1f == 0;
+    // Has hitten ground.
+    match sector.special {
+        5 => {
+            // HELLSLIME DAMAGE
+            if !ironfeet && hurt {
+                mo.p_damage_mobj(None, None, 10, level);
+            }
+        }
+        7 => {
+            // NUKAGE DAMAGE
+            if !ironfeet && hurt {
+                mo.p_damage_mobj(None, None, 5, level);
+            }
+        }
+        16 | 4 => {
+            // SUPER HELLSLIME DAMAGE, STROBE HURT
+            if (!ironfeet || p_random() < 5) && hurt {
+                mo.p_damage_mobj(None, None, 20, level);
+            }
+        }
+        9 => {
+            // SECRET SECTOR
+            player.secretcount += 1;
+            sector.special = 0;
+        }
+        11 => {
+            // EXIT SUPER DAMAGE! (for E1M8 finale)
+            player.cheats &= !(Cheat::Godmode as i32);
+            if hurt {
+                mo.p_damage_mobj(None, None, 20, level);
+            }
+            // The damage changes the player through the map object, so read
+            // the health from there rather than `player`
+            if mo.health <= 10 {
+                level.do_exit_level();
+            }
+        }
+        // Vanilla errors on an unknown special. The light specials are
+        // cleared at level start, but ports may use others, ignore them
+        _ => {}
+    }
+}
+
+/// P_SpawnSpecials
+///
+/// After the map has been loaded, scan for specials that spawn thinkers
+pub(crate) fn p_spawn_specials(level: &mut Level) {
+    //	Init special SECTORs.
+    let sectors: Vec<DPtr<Sector>> =
+        level.map_data.get_sectors().iter().map(DPtr::new).collect();
+    for mut sector in sectors {
+        match sector.special {
+            // FLICKERING LIGHTS
+            1 => p_spawn_light_flash(sector, level),
+            // STROBE FAST
+            2 => p_spawn_strobe_flash(sector, FASTDARK, false, level),
+            // STROBE SLOW
+            3 => p_spawn_strobe_flash(sector, SLOWDARK, false, level),
+            // STROBE FAST/DEATH SLIME
+            4 => {
+                p_spawn_strobe_flash(sector.clone(), FASTDARK, false, level);
+                sector.special = 4;
+            }
+            // GLOWING LIGHT
+            8 => p_spawn_glowing_light(sector, level),
+            // SECRET SECTOR
+            9 => level.totalsecret += 1,
+            // DOOR CLOSE IN 30 SECONDS
+            10 => p_spawn_door_close_in_30(sector, level),
+            // SYNC STROBE SLOW
+            12 => p_spawn_strobe_flash(sector, SLOWDARK, true, level),
+            // SYNC STROBE FAST
+            13 => p_spawn_strobe_flash(sector, FASTDARK, true, level),
+            // DOOR RAISE IN 5 MINUTES
+            14 => p_spawn_door_raise_in_5_mins(sector, level),
+            17 => p_spawn_fire_flicker(sector, level),
+            _ => {}
+        }
+    }
+
+    //	Init line EFFECTs
+    level.line_special_list = level
+        .map_data
+        .get_linedefs()
+        .iter()
+        .filter(|line| line.special == 48)
+        .map(DPtr::new)
+        .collect();
+}
+
+/// P_InitPicAnims
+///
+/// The Boom `ANIMATED` lump, if there is one, replaces the vanilla table.
+/// Sequences that aren't in the WAD are skipped.
+pub(crate) fn p_init_pic_anims(wad: &WadData, map_data: &MapData) -> Vec<Anim> {
+    let defs: Vec<(bool, String, String, i32)> = match wad.get_animated() {
+        Some(animated) => animated
+            .into_iter()
+            .map(|a| (a.is_texture, a.end_name, a.start_name, a.speed))
+            .collect(),
+        None => ANIMDEFS
+            .iter()
+            .map(|(t, end, start, speed)| {
+                (*t, end.to_string(), start.to_string(), *speed)
+            })
+            .collect(),
+    };
+
+    let mut anims = Vec::with_capacity(defs.len());
+    for (istexture, endname, startname, speed) in defs {
+        let (picnum, basepic) = if istexture {
+            // different episode ?
+            match (
+                map_data.check_texture_num_for_name(&endname),
+                map_data.check_texture_num_for_name(&startname),
+            ) {
+                (Some(end), Some(start)) => (end, start),
+                _ => continue,
+            }
+        } else {
+            match (
+                map_data.check_flat_num_for_name(&endname),
+                map_data.check_flat_num_for_name(&startname),
+            ) {
+                (Some(end), Some(start)) => (end, start),
+                _ => continue,
+            }
+        };
+
+        let numpics = picnum - basepic + 1;
+        if numpics < 2 {
+            println!(
+                "P_InitPicAnims: bad cycle from {} to {}",
+                startname, endname
+            );
+            continue;
+        }
+
+        anims.push(Anim {
+            istexture,
+            picnum,
+            basepic,
+            numpics,
+            speed,
+        });
+    }
+    anims
+}
+
+/// P_UpdateSpecials
+///
+/// Animate planes, scroll walls, etc.
+pub(crate) fn p_update_specials(level: &mut Level) {
+    // TODO: LEVEL TIMER, G_ExitLevel when the -timer deathmatch time is up
+
+    //	ANIMATE FLATS AND TEXTURES GLOBALLY
+    for anim in &level.anims {
+        for i in anim.basepic..anim.basepic + anim.numpics {
+            let pic = anim.basepic
+                + ((level.level_time as i32 / anim.speed + i as i32)
+                    % anim.numpics as i32) as i16;
+            if anim.istexture {
+                level.tex_translation[i as usize] = pic;
+            } else {
+                level.flat_translation[i as usize] = pic;
+            }
+        }
+    }
+
+    //	ANIMATE LINE SPECIALS
+    for line in level.line_special_list.iter_mut() {
+        if line.special == 48 {
+            // EFFECT FIRSTCOL SCROLL +
+            line.front_sidedef.textureoffset += 1.0;
+        }
+    }
+
+    //	DO BUTTONS
+    let mut i = 0;
+    while i < level.buttons.len() {
+        let button = &mut level.buttons[i];
+        button.btimer -= 1;
+        if button.btimer > 0 {
+            i += 1;
+            continue;
+        }
+
+        let mut side = button.line.front_sidedef.clone();
+        match button.bwhere {
+            ButtonWhere::top => side.toptexture = button.btexture,
+            ButtonWhere::middle => side.midtexture = button.btexture,
+            ButtonWhere::bottom => side.bottomtexture = button.btexture,
+        }
+        // TODO: S_StartSound(&buttonlist[i].soundorg,sfx_swtchn);
+        level.buttons.remove(i);
+    }
+}
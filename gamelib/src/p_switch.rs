@@ -0,0 +1,543 @@
+//!	Switches, buttons. Two-state animation. Exits.
+
+use crate::doom_def::GameMode;
+use crate::flags::LineDefFlags;
+use crate::level_data::level::Level;
+use crate::level_data::map_data::MapData;
+use crate::level_data::map_defs::LineDef;
+use crate::p_ceiling::ev_do_ceiling;
+use crate::p_doors::{ev_do_door, ev_do_locked_door, ev_vertical_door};
+use crate::p_floor::{ev_build_stairs, ev_do_floor};
+use crate::p_lights::ev_light_turn_on;
+use crate::p_map_object::MapObject;
+use crate::p_plats::ev_do_plat;
+use crate::p_spec::{
+    ev_do_donut, Button, ButtonWhere, CeilingKind, DoorKind, FloorEnum,
+    PlatType, StairEnum, BUTTONTIME,
+};
+use crate::DPtr;
+use wad::WadData;
+
+/// CHANGE THE TEXTURE OF A WALL SWITCH TO ITS OPPOSITE
+///
+/// `(name1, name2, episode)`
+const ALPH_SWITCH_LIST: [(&str, &str, i16); 40] = [
+    // Doom shareware episode 1 switches
+    ("SW1BRCOM", "SW2BRCOM", 1),
+    ("SW1BRN1", "SW2BRN1", 1),
+    ("SW1BRN2", "SW2BRN2", 1),
+    ("SW1BRNGN", "SW2BRNGN", 1),
+    ("SW1BROWN", "SW2BROWN", 1),
+    ("SW1COMM", "SW2COMM", 1),
+    ("SW1COMP", "SW2COMP", 1),
+    ("SW1DIRT", "SW2DIRT", 1),
+    ("SW1EXIT", "SW2EXIT", 1),
+    ("SW1GRAY", "SW2GRAY", 1),
+    ("SW1GRAY1", "SW2GRAY1", 1),
+    ("SW1METAL", "SW2METAL", 1),
+    ("SW1PIPE", "SW2PIPE", 1),
+    ("SW1SLAD", "SW2SLAD", 1),
+    ("SW1STARG", "SW2STARG", 1),
+    ("SW1STON1", "SW2STON1", 1),
+    ("SW1STON2", "SW2STON2", 1),
+    ("SW1STONE", "SW2STONE", 1),
+    ("SW1STRTN", "SW2STRTN", 1),
+    // Doom registered episodes 2&3 switches
+    ("SW1BLUE", "SW2BLUE", 2),
+    ("SW1CMT", "SW2CMT", 2),
+    ("SW1GARG", "SW2GARG", 2),
+    ("SW1GSTON", "SW2GSTON", 2),
+    ("SW1HOT", "SW2HOT", 2),
+    ("SW1LION", "SW2LION", 2),
+    ("SW1SATYR", "SW2SATYR", 2),
+    ("SW1SKIN", "SW2SKIN", 2),
+    ("SW1VINE", "SW2VINE", 2),
+    ("SW1WOOD", "SW2WOOD", 2),
+    // Doom II switches
+    ("SW1PANEL", "SW2PANEL", 3),
+    ("SW1ROCK", "SW2ROCK", 3),
+    ("SW1MET2", "SW2MET2", 3),
+    ("SW1WDMET", "SW2WDMET", 3),
+    ("SW1BRIK", "SW2BRIK", 3),
+    ("SW1MOD1", "SW2MOD1", 3),
+    ("SW1ZIM", "SW2ZIM", 3),
+    ("SW1STON6", "SW2STON6", 3),
+    ("SW1TEK", "SW2TEK", 3),
+    ("SW1MARB", "SW2MARB", 3),
+    ("SW1SKULL", "SW2SKULL", 3),
+];
+
+/// P_InitSwitchList
+///
+/// Only called at game initialization in vanilla. The texture numbers are
+/// stored in off/on pairs, so `switchlist[i ^ 1]` is the other state of
+/// `switchlist[i]`. The Boom `SWITCHES` lump, if there is one, replaces the
+/// vanilla table, and pairs with a texture not in the WAD are skipped.
+pub(crate) fn p_init_switch_list(
+    game_mode: GameMode,
+    wad: &WadData,
+    map_data: &MapData,
+) -> Vec<i16> {
+    let episode = match game_mode {
+        GameMode::Registered | GameMode::Retail => 2,
+        GameMode::Commercial => 3,
+        _ => 1,
+    };
+
+    let switches: Vec<(String, String, i16)> = match wad.get_switches() {
+        Some(switches) => switches
+            .into_iter()
+            .map(|s| (s.off_name, s.on_name, s.episode))
+            .collect(),
+        None => ALPH_SWITCH_LIST
+            .iter()
+            .map(|(off, on, ep)| (off.to_string(), on.to_string(), *ep))
+            .collect(),
+    };
+
+    let mut switch_list = Vec::with_capacity(switches.len() * 2);
+    for (name1, name2, ep) in switches {
+        if ep > episode {
+            continue;
+        }
+        if let (Some(off), Some(on)) = (
+            map_data.check_texture_num_for_name(&name1),
+            map_data.check_texture_num_for_name(&name2),
+        ) {
+            switch_list.push(off);
+            switch_list.push(on);
+        }
+    }
+    switch_list
+}
+
+/// P_StartButton
+///
+/// Start a button counting down till it turns off.
+fn p_start_button(
+    line: DPtr<LineDef>,
+    bwhere: ButtonWhere,
+    btexture: i16,
+    btimer: i32,
+    level: &mut Level,
+) {
+    // See if button is already pressed
+    if level
+        .buttons
+        .iter()
+        .any(|b| b.btimer != 0 && b.line.as_ptr() == line.as_ptr())
+    {
+        return;
+    }
+
+    level.buttons.push(Button {
+        line,
+        bwhere,
+        btexture,
+        btimer,
+    });
+}
+
+/// P_ChangeSwitchTexture
+///
+/// Function that changes wall texture.
+/// Tell it if switch is ok to use again (true=yes, it's a button).
+pub(crate) fn p_change_switch_texture(
+    mut line: DPtr<LineDef>,
+    use_again: bool,
+    level: &mut Level,
+) {
+    if !use_again {
+        line.special = 0;
+    }
+
+    let mut side = line.front_sidedef.clone();
+    let tex_top = side.toptexture;
+    let tex_mid = side.midtexture;
+    let tex_bot = side.bottomtexture;
+
+    // TODO: sound = sfx_swtchn, or sfx_swtchx if line.special == 11 (EXIT
+    //  SWITCH?)
+
+    for i in 0..level.switch_list.len() {
+        let (bwhere, texture) = if level.switch_list[i] == tex_top {
+            side.toptexture = level.switch_list[i ^ 1];
+            (ButtonWhere::top, level.switch_list[i])
+        } else if level.switch_list[i] == tex_mid {
+            side.midtexture = level.switch_list[i ^ 1];
+            (ButtonWhere::middle, level.switch_list[i])
+        } else if level.switch_list[i] == tex_bot {
+            side.bottomtexture = level.switch_list[i ^ 1];
+            (ButtonWhere::bottom, level.switch_list[i])
+        } else {
+            continue;
+        };
+
+        // TODO: S_StartSound(buttonlist->soundorg,sound);
+        if use_again {
+            p_start_button(line, bwhere, texture, BUTTONTIME, level);
+        }
+        return;
+    }
+}
+
+/// P_UseSpecialLine
+///
+/// Called when a thing uses a special line.
+/// Only the front sides of lines are usable.
+pub(crate) fn p_use_special_line(
+    side: usize,
+    line: DPtr<LineDef>,
+    thing: &mut MapObject,
+    level: &mut Level,
+) -> bool {
+    // Err...
+    // Use the back sides of VERY SPECIAL lines...
+    if side != 0 {
+        // Sliding door open&close is UNUSED
+        return false;
+    }
+
+    // Switches that other things can activate.
+    if thing.player.is_none() {
+        // never open secret doors
+        if line.flags & LineDefFlags::Secret as i16 != 0 {
+            return false;
+        }
+
+        match line.special {
+            // MANUAL DOOR RAISE, MANUAL BLUE, MANUAL RED, MANUAL YELLOW
+            1 | 32 | 33 | 34 => {}
+            _ => return false,
+        }
+    }
+
+    // do something
+    match line.special {
+        // MANUALS
+        1 | 26 | 27 | 28 | 31 | 32 | 33 | 34 | 117 | 118 => {
+            // Vertical Door, Blue/Yellow/Red Door Locked, Manual door open,
+            // Blue/Red/Yellow Lock open, Blazing door raise/open
+            ev_vertical_door(line, thing, level);
+        }
+
+        // SWITCHES
+        7 => {
+            // Build Stairs
+            if ev_build_stairs(line.clone(), StairEnum::build8, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        9 => {
+            // Change Donut
+            if ev_do_donut(line.clone(), level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        11 => {
+            // Exit level
+            p_change_switch_texture(line, false, level);
+            level.do_exit_level();
+        }
+        14 => {
+            // Raise Floor 32 and change texture
+            if ev_do_plat(line.clone(), PlatType::raiseAndChange, 32, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        15 => {
+            // Raise Floor 24 and change texture
+            if ev_do_plat(line.clone(), PlatType::raiseAndChange, 24, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        18 => {
+            // Raise Floor to next highest floor
+            if ev_do_floor(line.clone(), FloorEnum::raiseFloorToNearest, level)
+            {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        20 => {
+            // Raise Plat next highest floor and change texture
+            if ev_do_plat(
+                line.clone(),
+                PlatType::raiseToNearestAndChange,
+                0,
+                level,
+            ) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        21 => {
+            // PlatDownWaitUpStay
+            if ev_do_plat(line.clone(), PlatType::downWaitUpStay, 0, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        23 => {
+            // Lower Floor to Lowest
+            if ev_do_floor(line.clone(), FloorEnum::lowerFloorToLowest, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        29 => {
+            // Raise Door
+            if ev_do_door(line.clone(), DoorKind::normal, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        41 => {
+            // Lower Ceiling to Floor
+            if ev_do_ceiling(line.clone(), CeilingKind::lowerToFloor, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        71 => {
+            // Turbo Lower Floor
+            if ev_do_floor(line.clone(), FloorEnum::turboLower, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        49 => {
+            // Ceiling Crush And Raise
+            if ev_do_ceiling(line.clone(), CeilingKind::crushAndRaise, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        50 => {
+            // Close Door
+            if ev_do_door(line.clone(), DoorKind::close, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        51 => {
+            // Secret EXIT
+            p_change_switch_texture(line, false, level);
+            level.do_secret_exit_level();
+        }
+        55 => {
+            // Raise Floor Crush
+            if ev_do_floor(line.clone(), FloorEnum::raiseFloorCrush, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        101 => {
+            // Raise Floor
+            if ev_do_floor(line.clone(), FloorEnum::raiseFloor, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        102 => {
+            // Lower Floor to Surrounding floor height
+            if ev_do_floor(line.clone(), FloorEnum::lowerFloor, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        103 => {
+            // Open Door
+            if ev_do_door(line.clone(), DoorKind::open, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        111 => {
+            // Blazing Door Raise (faster than TURBO!)
+            if ev_do_door(line.clone(), DoorKind::blazeRaise, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        112 => {
+            // Blazing Door Open (faster than TURBO!)
+            if ev_do_door(line.clone(), DoorKind::blazeOpen, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        113 => {
+            // Blazing Door Close (faster than TURBO!)
+            if ev_do_door(line.clone(), DoorKind::blazeClose, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        122 => {
+            // Blazing PlatDownWaitUpStay
+            if ev_do_plat(line.clone(), PlatType::blazeDWUS, 0, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        127 => {
+            // Build Stairs Turbo 16
+            if ev_build_stairs(line.clone(), StairEnum::turbo16, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        131 => {
+            // Raise Floor Turbo
+            if ev_do_floor(line.clone(), FloorEnum::raiseFloorTurbo, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        133 | 135 | 137 => {
+            // BlzOpenDoor BLUE, RED, YELLOW
+            if ev_do_locked_door(
+                line.clone(),
+                DoorKind::blazeOpen,
+                thing,
+                level,
+            ) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+        140 => {
+            // Raise Floor 512
+            if ev_do_floor(line.clone(), FloorEnum::raiseFloor512, level) {
+                p_change_switch_texture(line, false, level);
+            }
+        }
+
+        // BUTTONS
+        42 => {
+            // Close Door
+            if ev_do_door(line.clone(), DoorKind::close, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        43 => {
+            // Lower Ceiling to Floor
+            if ev_do_ceiling(line.clone(), CeilingKind::lowerToFloor, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        45 => {
+            // Lower Floor to Surrounding floor height
+            if ev_do_floor(line.clone(), FloorEnum::lowerFloor, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        60 => {
+            // Lower Floor to Lowest
+            if ev_do_floor(line.clone(), FloorEnum::lowerFloorToLowest, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        61 => {
+            // Open Door
+            if ev_do_door(line.clone(), DoorKind::open, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        62 => {
+            // PlatDownWaitUpStay
+            if ev_do_plat(line.clone(), PlatType::downWaitUpStay, 1, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        63 => {
+            // Raise Door
+            if ev_do_door(line.clone(), DoorKind::normal, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        64 => {
+            // Raise Floor to ceiling
+            if ev_do_floor(line.clone(), FloorEnum::raiseFloor, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        66 => {
+            // Raise Floor 24 and change texture
+            if ev_do_plat(line.clone(), PlatType::raiseAndChange, 24, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        67 => {
+            // Raise Floor 32 and change texture
+            if ev_do_plat(line.clone(), PlatType::raiseAndChange, 32, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        65 => {
+            // Raise Floor Crush
+            if ev_do_floor(line.clone(), FloorEnum::raiseFloorCrush, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        68 => {
+            // Raise Plat to next highest floor and change texture
+            if ev_do_plat(
+                line.clone(),
+                PlatType::raiseToNearestAndChange,
+                0,
+                level,
+            ) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        69 => {
+            // Raise Floor to next highest floor
+            if ev_do_floor(line.clone(), FloorEnum::raiseFloorToNearest, level)
+            {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        70 => {
+            // Turbo Lower Floor
+            if ev_do_floor(line.clone(), FloorEnum::turboLower, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        114 => {
+            // Blazing Door Raise (faster than TURBO!)
+            if ev_do_door(line.clone(), DoorKind::blazeRaise, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        115 => {
+            // Blazing Door Open (faster than TURBO!)
+            if ev_do_door(line.clone(), DoorKind::blazeOpen, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        116 => {
+            // Blazing Door Close (faster than TURBO!)
+            if ev_do_door(line.clone(), DoorKind::blazeClose, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        123 => {
+            // Blazing PlatDownWaitUpStay
+            if ev_do_plat(line.clone(), PlatType::blazeDWUS, 0, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        132 => {
+            // Raise Floor Turbo
+            if ev_do_floor(line.clone(), FloorEnum::raiseFloorTurbo, level) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        99 | 134 | 136 => {
+            // BlzOpenDoor BLUE, RED, YELLOW
+            if ev_do_locked_door(
+                line.clone(),
+                DoorKind::blazeOpen,
+                thing,
+                level,
+            ) {
+                p_change_switch_texture(line, true, level);
+            }
+        }
+        138 => {
+            // Light Turn On
+            ev_light_turn_on(line.clone(), 255, level);
+            p_change_switch_texture(line, true, level);
+        }
+        139 => {
+            // Light Turn Off
+            ev_light_turn_on(line.clone(), 35, level);
+            p_change_switch_texture(line, true, level);
+        }
+        _ => {}
+    }
+
+    true
+}
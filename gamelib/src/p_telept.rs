@@ -0,0 +1,93 @@
+//!	Teleportation.
+
+use glam::Vec2;
+
+use crate::info::MapObjectType;
+use crate::level_data::level::Level;
+use crate::level_data::map_defs::LineDef;
+use crate::p_map_object::{MapObject, MapObjectFlag};
+use crate::p_spec::find_sectors_from_line_tag;
+use crate::DPtr;
+
+/// EV_Teleport
+pub(crate) fn ev_teleport(
+    line: DPtr<LineDef>,
+    side: usize,
+    thing: &mut MapObject,
+    level: &mut Level,
+) -> bool {
+    // don't teleport missiles
+    if thing.flags & MapObjectFlag::MF_MISSILE as u32 != 0 {
+        return false;
+    }
+
+    // Don't teleport if hit back of line,
+    //  so you can get out of teleporter.
+    if side == 1 {
+        return false;
+    }
+
+    for sector in find_sectors_from_line_tag(&line, level) {
+        let dest = level.thinkers.iter().flatten().map(|t| &t.obj).find(|m| {
+            m.kind == MapObjectType::MT_TELEPORTMAN as u16
+                && m.subsector.sector.as_ptr() == sector.as_ptr()
+        });
+        let (dest_xy, dest_angle) = match dest {
+            Some(m) => (m.xy, m.angle),
+            None => continue,
+        };
+
+        let old_xy = thing.xy;
+        let old_z = thing.z;
+
+        if !thing.p_teleport_move(dest_xy, level) {
+            return false;
+        }
+
+        thing.z = thing.floorz;
+        if let Some(mut player) = thing.player {
+            let player = unsafe { player.as_mut() };
+            player.viewz = thing.z + player.viewheight;
+            // Don't interpolate the view across the map
+            player.prev_viewz = player.viewz;
+        }
+
+        // spawn teleport fog at source and destination
+        let fog = MapObject::p_spawn_map_object(
+            old_xy.x(),
+            old_xy.y(),
+            old_z as i32,
+            MapObjectType::MT_TFOG as u16,
+            level,
+        );
+        if level.add_thinker(fog).is_none() {
+            println!("EV_Teleport: no room for another thinker");
+        }
+        // TODO: S_StartSound (fog, sfx_telept);
+
+        let fog_xy = dest_xy + dest_angle.unit() * 20.0;
+        let fog = MapObject::p_spawn_map_object(
+            fog_xy.x(),
+            fog_xy.y(),
+            thing.z as i32,
+            MapObjectType::MT_TFOG as u16,
+            level,
+        );
+        if level.add_thinker(fog).is_none() {
+            println!("EV_Teleport: no room for another thinker");
+        }
+        // TODO: S_StartSound (fog, sfx_telept);
+
+        // don't move for a bit
+        if thing.player.is_some() {
+            thing.reactiontime = 18;
+        }
+
+        thing.angle = dest_angle;
+        thing.momxy = Vec2::default();
+        thing.momz = 0.0;
+        thing.store_previous();
+        return true;
+    }
+    false
+}
@@ -1,14 +1,27 @@
-use std::f32::consts::FRAC_PI_2;
+use std::f32::consts::{FRAC_PI_2, PI};
 
 use glam::Vec2;
 
 /// 16 pixels of bob
 const MAXBOB: f32 = 16.0; // 0x100000;
+/// How far free-look can look up or down, in radians
+pub(crate) const MAX_PITCH: f32 = 0.5;
+
+/// The first of the red damage palettes in PLAYPAL
+const STARTREDPALS: usize = 1;
+const NUMREDPALS: usize = 8;
+/// The first of the yellow pickup palettes in PLAYPAL
+const STARTBONUSPALS: usize = 9;
+const NUMBONUSPALS: usize = 4;
+/// Radiation suit, green shift.
+const RADIATIONPAL: usize = 13;
 
 use crate::level_data::level::Level;
-use crate::p_player_sprite::PspDef;
+use crate::p_player_sprite::{p_move_psprites, PspDef};
+use crate::p_spec::p_player_in_special_sector;
 use crate::{
     angle::Angle,
+    doom_def::GameMode,
     doom_def::MAX_AMMO,
     doom_def::{AmmoType, Card, PowerType, WeaponType, MAXPLAYERS},
     p_local::MAXHEALTH,
@@ -19,8 +32,8 @@ use crate::{
     info::SpriteNum,
     p_local::bam_to_radian,
     p_local::fixed_to_float,
-    p_map_object::MapObject,
-    tic_cmd::TicCmd,
+    p_map_object::{MapObject, MapObjectFlag},
+    tic_cmd::{TicCmd, TIC_CMD_BUTTONS},
 };
 
 /// Overlay psprites are scaled shapes
@@ -49,7 +62,7 @@ pub(crate) enum PlayerState {
 
 //// Player internal flags, for cheats and debug.
 #[derive(Debug)]
-enum Cheat {
+pub(crate) enum Cheat {
     /// No clipping, walk through barriers.
     Noclip     = 1,
     /// No damage, no health loss.
@@ -60,7 +73,7 @@ enum Cheat {
 
 /// INTERMISSION
 /// Structure passed e.g. to WI_Start(wb)
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Copy, Clone)]
 pub(crate) struct WBPlayerStruct {
     /// whether the player is in game
     pub inn:     bool,
@@ -75,7 +88,7 @@ pub(crate) struct WBPlayerStruct {
 }
 
 /// parms for world level / intermission
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Copy, Clone)]
 pub(crate) struct WBStartStruct {
     /// episode # (0-2)
     pub epsd:      i32,
@@ -101,6 +114,15 @@ const NUM_WEAPONS: usize = WeaponType::NUMWEAPONS as usize;
 const NUM_AMMO: usize = AmmoType::NUMAMMO as usize;
 const NUM_SPRITES: usize = PsprNum::NUMPSPRITES as usize;
 
+/// The parts of the player used to set up the view, as saved by
+/// `Player::interpolate_view`
+pub(crate) struct PlayerView {
+    xy:    Vec2,
+    angle: Angle,
+    viewz: f32,
+    pitch: f32,
+}
+
 /// player_t
 #[derive(Debug)]
 pub(crate) struct Player {
@@ -112,6 +134,12 @@ pub(crate) struct Player {
     ///  including viewpoint bobbing during movement.
     /// Focal origin above r.z
     pub viewz:           f32,
+    /// `viewz` at the start of the tic, for interpolation
+    pub prev_viewz:      f32,
+    /// Free-look view pitch in radians, positive is up
+    pub pitch:           f32,
+    /// `pitch` at the start of the tic, for interpolation
+    pub prev_pitch:      f32,
     /// Base height above floor for viewz.
     pub viewheight:      f32,
     /// Bob/squat speed.
@@ -133,15 +161,15 @@ pub(crate) struct Player {
     pub backpack: bool,
 
     /// Frags, kills of other players.
-    pub frags:   [i32; MAXPLAYERS as usize],
-    readyweapon: WeaponType,
+    pub frags:       [i32; MAXPLAYERS as usize],
+    pub readyweapon: WeaponType,
 
     /// Is wp_nochange if not changing.
-    pendingweapon: WeaponType,
+    pub pendingweapon: WeaponType,
 
-    weaponowned: [bool; NUM_WEAPONS],
-    ammo:        [u32; NUM_AMMO],
-    maxammo:     [u32; NUM_AMMO],
+    pub weaponowned: [bool; NUM_WEAPONS],
+    pub ammo:        [u32; NUM_AMMO],
+    pub maxammo:     [u32; NUM_AMMO],
 
     /// True if button down last tic.
     pub attackdown: bool,
@@ -149,7 +177,7 @@ pub(crate) struct Player {
 
     /// Bit flags, for cheats and debug.
     /// See cheat_t, above.
-    cheats: i32,
+    pub cheats: i32,
 
     /// Refired shots are less accurate.
     pub refire: i32,
@@ -180,10 +208,10 @@ pub(crate) struct Player {
     colormap: i32,
 
     /// Overlay view sprites (gun, etc).
-    psprites: [PspDef; NUM_SPRITES],
+    pub psprites: [PspDef; NUM_SPRITES],
 
     /// True if secret level has been done.
-    didsecret: bool,
+    pub didsecret: bool,
 
     // Custom option
     pub head_bob: bool,
@@ -199,6 +227,9 @@ impl Player {
     ) -> Player {
         Player {
             viewz: 0.0,
+            prev_viewz: 0.0,
+            pitch: 0.0,
+            prev_pitch: 0.0,
             mobj,
 
             viewheight: 41.0,
@@ -257,6 +288,45 @@ impl Player {
             ],
         }
     }
+
+    /// Keep the view at the start of the tic for interpolation
+    pub(crate) fn store_previous(&mut self) {
+        self.prev_viewz = self.viewz;
+        self.prev_pitch = self.pitch;
+        if let Some(ref mut mobj) = self.mobj {
+            mobj.obj.store_previous();
+        }
+    }
+
+    /// Set the view to between the start of the tic and now for drawing.
+    /// Returns the real view to put back with `restore_view`.
+    pub(crate) fn interpolate_view(&mut self, frac: f32) -> PlayerView {
+        let mut real = PlayerView {
+            xy:    Vec2::default(),
+            angle: Angle::default(),
+            viewz: self.viewz,
+            pitch: self.pitch,
+        };
+        self.viewz = self.prev_viewz + (real.viewz - self.prev_viewz) * frac;
+        self.pitch = self.prev_pitch + (real.pitch - self.prev_pitch) * frac;
+        if let Some(ref mut mobj) = self.mobj {
+            let obj = &mut mobj.obj;
+            real.xy = obj.xy;
+            real.angle = obj.angle;
+            obj.xy = obj.prev_xy + (obj.xy - obj.prev_xy) * frac;
+            obj.angle = obj.prev_angle.lerp(obj.angle, frac);
+        }
+        real
+    }
+
+    pub(crate) fn restore_view(&mut self, view: PlayerView) {
+        self.viewz = view.viewz;
+        self.pitch = view.pitch;
+        if let Some(ref mut mobj) = self.mobj {
+            mobj.obj.xy = view.xy;
+            mobj.obj.angle = view.angle;
+        }
+    }
     // TODO: needs p_pspr.c, p_inter.c
 
     pub fn player_reborn(&mut self) {
@@ -284,6 +354,28 @@ impl Player {
         }
     }
 
+    /// G_PlayerFinishLevel
+    /// Called when a player completes a level
+    pub fn finish_level(&mut self) {
+        for p in self.powers.iter_mut() {
+            *p = 0;
+        }
+        for c in self.cards.iter_mut() {
+            *c = false;
+        }
+        if let Some(ref mut mobj) = self.mobj {
+            // cancel invisibility
+            mobj.obj.flags &= !(MapObjectFlag::MF_SHADOW as u32);
+        }
+        // cancel gun flashes
+        self.extralight = 0;
+        // cancel ir goggles
+        self.fixedcolormap = 0;
+        // no palette changes
+        self.damagecount = 0;
+        self.bonuscount = 0;
+    }
+
     fn thrust(&mut self, angle: Angle, mv: i32) {
         // mv is in a fixed float format, we need to convert it
         // TODO: make some of this constant later
@@ -371,6 +463,106 @@ impl Player {
         }
     }
 
+    /// The palette part of ST_doPaletteStuff, which PLAYPAL palette the
+    /// screen should be drawn with
+    pub(crate) fn palette(&self) -> usize {
+        let mut cnt = self.damagecount;
+
+        let strength = self.powers[PowerType::pw_strength as usize];
+        if strength != 0 {
+            // slowly fade the berzerk out
+            let bzc = 12 - (strength >> 6);
+            if bzc > cnt {
+                cnt = bzc;
+            }
+        }
+
+        let ironfeet = self.powers[PowerType::pw_ironfeet as usize];
+        if cnt > 0 {
+            let palette = ((cnt + 7) >> 3) as usize;
+            palette.min(NUMREDPALS - 1) + STARTREDPALS
+        } else if self.bonuscount > 0 {
+            let palette = ((self.bonuscount + 7) >> 3) as usize;
+            palette.min(NUMBONUSPALS - 1) + STARTBONUSPALS
+        } else if ironfeet > 4 * 32 || ironfeet & 8 != 0 {
+            RADIATIONPAL
+        } else {
+            0
+        }
+    }
+
+    /// The power up and screen flash counting of P_PlayerThink
+    fn count_down_powers(&mut self) {
+        // Strength counts up to diminish fade.
+        let strength = &mut self.powers[PowerType::pw_strength as usize];
+        if *strength != 0 {
+            *strength += 1;
+        }
+
+        for power in [
+            PowerType::pw_invulnerability,
+            PowerType::pw_infrared,
+            PowerType::pw_ironfeet,
+        ]
+        .iter()
+        {
+            let power = &mut self.powers[*power as usize];
+            if *power != 0 {
+                *power -= 1;
+            }
+        }
+
+        let invisibility =
+            &mut self.powers[PowerType::pw_invisibility as usize];
+        if *invisibility != 0 {
+            *invisibility -= 1;
+            if *invisibility == 0 {
+                if let Some(ref mut mobj) = self.mobj {
+                    mobj.obj.flags &= !(MapObjectFlag::MF_SHADOW as u32);
+                }
+            }
+        }
+
+        if self.damagecount != 0 {
+            self.damagecount -= 1;
+        }
+        if self.bonuscount != 0 {
+            self.bonuscount -= 1;
+        }
+        // TODO: fixedcolormap for invulnerability and light amp once the
+        //  renderer has colormaps
+    }
+
+    /// P_DeathThink
+    ///
+    /// Fall on your face when dying.
+    /// Decrease POV height to floor height.
+    fn death_think(&mut self, level: &mut Level) {
+        p_move_psprites(self, level);
+
+        // fall to the ground
+        if self.viewheight > 6.0 {
+            self.viewheight -= 1.0;
+        }
+        if self.viewheight < 6.0 {
+            self.viewheight = 6.0;
+        }
+        self.deltaviewheight = 0.0;
+        if let Some(ref mo) = self.mobj {
+            self.onground = mo.obj.z <= mo.obj.floorz;
+        }
+        self.calculate_height(level.level_time);
+
+        // TODO: turn to face the attacker, player->attacker isn't kept yet
+        if self.damagecount > 0 {
+            self.damagecount -= 1;
+        }
+
+        if self.cmd.buttons & TIC_CMD_BUTTONS.bt_use != 0 {
+            self.player_state = PlayerState::PstReborn;
+        }
+    }
+
     fn move_player(&mut self) {
         // TODO: Fix adjustments after fixing the tic timestep
         if self.cmd.angleturn != 0 {
@@ -378,6 +570,12 @@ impl Player {
             self.mobj.as_mut().unwrap().obj.angle += a;
         }
 
+        // Free-look, only set in the cmd if enabled
+        if self.cmd.lookpitch != 0 {
+            self.pitch += self.cmd.lookpitch as f32 * PI / 32768.0;
+            self.pitch = self.pitch.max(-MAX_PITCH).min(MAX_PITCH);
+        }
+
         self.onground = if let Some(think) = self.mobj.as_ref() {
             think.obj.z <= think.obj.floorz
         } else {
@@ -407,9 +605,97 @@ impl Player {
 
 impl Think for Player {
     fn think(&mut self, level: &mut Level) -> bool {
-        self.move_player();
+        if self.player_state == PlayerState::PstDead {
+            self.death_think(level);
+            if let Some(ref mut mo) = self.mobj {
+                mo.think(level);
+            }
+            return false;
+        }
+
+        // Move around.
+        // Reactiontime is used to prevent movement
+        //  for a bit after a teleport.
+        let frozen = match self.mobj {
+            Some(ref mut mo) if mo.obj.reactiontime > 0 => {
+                mo.obj.reactiontime -= 1;
+                true
+            }
+            _ => false,
+        };
+        if !frozen {
+            self.move_player();
+        }
         self.calculate_height(level.level_time);
 
+        let special = self
+            .mobj
+            .as_ref()
+            .map_or(0, |mo| mo.obj.subsector.sector.special);
+        if special != 0 {
+            p_player_in_special_sector(self, level);
+        }
+
+        // Check for weapon change.
+        if self.cmd.buttons & TIC_CMD_BUTTONS.bt_change != 0 {
+            // The actual changing of the weapon is done
+            //  when the weapon psprite can do it
+            //  (read: not in the middle of an attack).
+            let mut newweapon = WeaponType::from(
+                ((self.cmd.buttons & TIC_CMD_BUTTONS.bt_weaponmask)
+                    >> TIC_CMD_BUTTONS.bt_weaponshift) as i32,
+            );
+
+            if newweapon as usize == WeaponType::wp_fist as usize
+                && self.weaponowned[WeaponType::wp_chainsaw as usize]
+                && !(self.readyweapon as usize
+                    == WeaponType::wp_chainsaw as usize
+                    && self.powers[PowerType::pw_strength as usize] != 0)
+            {
+                newweapon = WeaponType::wp_chainsaw;
+            }
+
+            if level.game_mode == GameMode::Commercial
+                && newweapon as usize == WeaponType::wp_shotgun as usize
+                && self.weaponowned[WeaponType::wp_supershotgun as usize]
+                && self.readyweapon as usize
+                    != WeaponType::wp_supershotgun as usize
+            {
+                newweapon = WeaponType::wp_supershotgun;
+            }
+
+            if (newweapon as usize) < WeaponType::NUMWEAPONS as usize
+                && self.weaponowned[newweapon as usize]
+                && newweapon as usize != self.readyweapon as usize
+            {
+                // Do not go to plasma or BFG in shareware,
+                //  even if cheated.
+                if (newweapon as usize != WeaponType::wp_plasma as usize
+                    && newweapon as usize != WeaponType::wp_bfg as usize)
+                    || level.game_mode != GameMode::Shareware
+                {
+                    self.pendingweapon = newweapon;
+                }
+            }
+        }
+
+        // check for use
+        if self.cmd.buttons & TIC_CMD_BUTTONS.bt_use != 0 {
+            if !self.usedown {
+                if let Some(ref mut mo) = self.mobj {
+                    mo.obj.p_use_lines(level);
+                }
+                self.usedown = true;
+            }
+        } else {
+            self.usedown = false;
+        }
+
+        // cycle psprites
+        p_move_psprites(self, level);
+
+        self.count_down_powers();
+
         if let Some(ref mut mo) = self.mobj {
             mo.think(level); // Player own the thinker, so make it think here
         }
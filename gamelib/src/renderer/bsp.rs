@@ -7,7 +7,6 @@ use crate::renderer::defs::{ClipRange, DrawSeg};
 use crate::renderer::segs::SegRender;
 use crate::renderer::RenderData;
 use glam::Vec2;
-use sdl2::{render::Canvas, surface::Surface};
 use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
 
 const MAX_SEGS: usize = 32;
@@ -53,13 +52,15 @@ impl BspRenderer {
         player: &Player,
         seg: &'a Segment,
         r_data: &mut RenderData,
-        canvas: &mut Canvas<Surface>,
     ) {
+        r_data.stats.segs += 1;
+
         // reject orthogonal back sides
         let xy = player.mobj.as_ref().unwrap().obj.xy;
         let angle = player.mobj.as_ref().unwrap().obj.angle;
 
         if !seg.is_facing_point(&xy) {
+            r_data.stats.segs_rejected += 1;
             return;
         }
 
@@ -73,6 +74,7 @@ impl BspRenderer {
         let span = angle1 - angle2;
 
         if span.rad() >= PI {
+            r_data.stats.segs_rejected += 1;
             return;
         }
 
@@ -88,6 +90,7 @@ impl BspRenderer {
 
             // Totally off the left edge?
             if tspan.rad() >= span.rad() {
+                r_data.stats.segs_rejected += 1;
                 return;
             }
             angle1 = clipangle;
@@ -98,6 +101,7 @@ impl BspRenderer {
 
             // Totally off the left edge?
             if tspan.rad() >= span.rad() {
+                r_data.stats.segs_rejected += 1;
                 return;
             }
             angle2 = -clipangle;
@@ -110,6 +114,7 @@ impl BspRenderer {
 
         // Does not cross a pixel?
         if x1 == x2 {
+            r_data.stats.segs_rejected += 1;
             return;
         }
 
@@ -121,7 +126,7 @@ impl BspRenderer {
             if back_sector.ceilingheight <= front_sector.floorheight
                 || back_sector.floorheight >= front_sector.ceilingheight
             {
-                self.clip_solid_seg(x1, x2 - 1, seg, player, r_data, canvas);
+                self.clip_solid_seg(x1, x2 - 1, seg, player, r_data);
                 return;
             }
 
@@ -130,7 +135,7 @@ impl BspRenderer {
             if back_sector.ceilingheight != front_sector.ceilingheight
                 || back_sector.floorheight != front_sector.floorheight
             {
-                self.clip_portal_seg(x1, x2 - 1, seg, player, r_data, canvas);
+                self.clip_portal_seg(x1, x2 - 1, seg, player, r_data);
                 return;
             }
 
@@ -142,10 +147,11 @@ impl BspRenderer {
                 && back_sector.lightlevel == front_sector.lightlevel
                 && seg.linedef.front_sidedef.midtexture == 0
             {
+                r_data.stats.segs_rejected += 1;
                 return;
             }
         } else {
-            self.clip_solid_seg(x1, x2 - 1, seg, player, r_data, canvas);
+            self.clip_solid_seg(x1, x2 - 1, seg, player, r_data);
         }
     }
 
@@ -156,12 +162,13 @@ impl BspRenderer {
         object: &Player,
         subsect: &SubSector,
         r_data: &mut RenderData,
-        canvas: &mut Canvas<Surface>,
     ) {
         // TODO: planes for floor & ceiling
+        // R_AddSprites, the things are projected once the walk is done
+        r_data.sprite_sectors.insert(&*subsect.sector);
         for i in subsect.start_seg..subsect.start_seg + subsect.seg_count {
             let seg = &map.get_segments()[i as usize];
-            self.add_line(object, &seg, r_data, canvas);
+            self.add_line(object, &seg, r_data);
         }
     }
 
@@ -189,7 +196,6 @@ impl BspRenderer {
         seg: &Segment,
         object: &Player,
         r_data: &mut RenderData,
-        canvas: &mut Canvas<Surface>,
     ) {
         let mut r_segs = SegRender::default();
         let mut next;
@@ -207,8 +213,7 @@ impl BspRenderer {
             if last < self.solidsegs[start].first - 1 {
                 // Post is entirely visible (above start),
                 // so insert a new clippost.
-                r_segs
-                    .store_wall_range(first, last, seg, object, r_data, canvas);
+                r_segs.store_wall_range(first, last, seg, object, r_data);
 
                 next = self.new_end;
                 self.new_end += 1;
@@ -224,7 +229,7 @@ impl BspRenderer {
             }
 
             // There is a fragment above *start.
-            r_segs.store_wall_range(first, last, seg, object, r_data, canvas);
+            r_segs.store_wall_range(first, last, seg, object, r_data);
             // Now adjust the clip size.
             self.solidsegs[start].first = first;
         }
@@ -238,7 +243,7 @@ impl BspRenderer {
         while last >= self.solidsegs[next + 1].first - 1
             && next + 1 < self.solidsegs.len() - 1
         {
-            r_segs.store_wall_range(first, last, seg, object, r_data, canvas);
+            r_segs.store_wall_range(first, last, seg, object, r_data);
 
             next += 1;
 
@@ -249,7 +254,7 @@ impl BspRenderer {
         }
 
         // There is a fragment after *next.
-        r_segs.store_wall_range(first, last, seg, object, r_data, canvas);
+        r_segs.store_wall_range(first, last, seg, object, r_data);
         // Adjust the clip size.
         self.solidsegs[start].last = last;
 
@@ -267,7 +272,6 @@ impl BspRenderer {
         seg: &Segment,
         object: &Player,
         r_data: &mut RenderData,
-        canvas: &mut Canvas<Surface>,
     ) {
         let mut r_segs = SegRender::default();
         let mut next;
@@ -282,13 +286,12 @@ impl BspRenderer {
         if first < self.solidsegs[start].first {
             if last < self.solidsegs[start].first - 1 {
                 // Post is entirely visible (above start),
-                r_segs
-                    .store_wall_range(first, last, seg, object, r_data, canvas);
+                r_segs.store_wall_range(first, last, seg, object, r_data);
                 return;
             }
 
             // There is a fragment above *start.
-            r_segs.store_wall_range(first, last, seg, object, r_data, canvas);
+            r_segs.store_wall_range(first, last, seg, object, r_data);
         }
 
         // Bottom contained in start?
@@ -300,7 +303,7 @@ impl BspRenderer {
         while last >= self.solidsegs[next + 1].first - 1
             && next + 1 < self.solidsegs.len() - 1
         {
-            r_segs.store_wall_range(first, last, seg, object, r_data, canvas);
+            r_segs.store_wall_range(first, last, seg, object, r_data);
 
             next += 1;
 
@@ -310,7 +313,7 @@ impl BspRenderer {
         }
 
         // There is a fragment after *next.
-        r_segs.store_wall_range(first, last, seg, object, r_data, canvas);
+        r_segs.store_wall_range(first, last, seg, object, r_data);
     }
 
     fn crunch(&mut self, mut start: usize, mut next: usize) {
@@ -336,14 +339,18 @@ impl BspRenderer {
         player: &Player,
         node_id: u16,
         r_data: &mut RenderData,
-        canvas: &mut Canvas<Surface>,
     ) {
+        r_data.stats.nodes += 1;
         if node_id & IS_SSECTOR_MASK == IS_SSECTOR_MASK {
             // It's a leaf node and is the index to a subsector
-            let subsect =
-                &map.get_subsectors()[(node_id ^ IS_SSECTOR_MASK) as usize];
+            let index = (node_id ^ IS_SSECTOR_MASK) as usize;
+            let subsect = &map.get_subsectors()[index];
             // Check if it should be drawn, then draw
-            self.draw_subsector(map, player, &subsect, r_data, canvas);
+            if r_data.debug.draw_next(r_data.stats.subsectors) {
+                r_data.debug.subsector = index;
+                r_data.stats.subsectors += 1;
+                self.draw_subsector(map, player, &subsect, r_data);
+            }
             return;
         }
 
@@ -352,14 +359,9 @@ impl BspRenderer {
         let node = &map.get_nodes()[node_id as usize];
         // find which side the point is on
         let side = node.point_on_side(&mobj.xy);
+        r_data.debug.depth += 1;
         // Recursively divide front space.
-        self.render_bsp_node(
-            map,
-            player,
-            node.child_index[side],
-            r_data,
-            canvas,
-        );
+        self.render_bsp_node(map, player, node.child_index[side], r_data);
 
         // Possibly divide back space.
         // check if each corner of the BB is in the FOV
@@ -375,10 +377,13 @@ impl BspRenderer {
                 player,
                 node.child_index[side ^ 1],
                 r_data,
-                canvas,
             );
         }
+        r_data.debug.depth -= 1;
     }
+
+    /// The solidsegs in use, for the debug overlay
+    pub fn solid_segs(&self) -> &[ClipRange] { &self.solidsegs[..self.new_end] }
 }
 
 pub(crate) fn angle_to_screen(mut radian: f32) -> i32 {
@@ -0,0 +1,153 @@
+//! Renderer debug overlays, only available with `-devparm`:
+//!
+//! - `` ` `` cycles colouring each seg by its subsector, by the depth of its
+//!   node in the BSP, or off. While on the solidsegs clip list is drawn as a
+//!   strip along the top of the view, red where solid, and the `RenderStats`
+//!   for the frame are printed whenever the counts change.
+//! - `N` steps the BSP walk on by one subsector, `Backspace` goes back to
+//!   walking all of it.
+
+use sdl2::{
+    keyboard::Scancode, pixels::Color, rect::Rect, render::Canvas,
+    surface::Surface,
+};
+
+use crate::renderer::{
+    defs::{ClipRange, SCREENWIDTH},
+    stats::RenderStats,
+};
+
+/// How high the solidsegs strip is
+const CLIP_STRIP_HEIGHT: u32 = 4;
+
+/// What the segs are coloured by
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DebugMode {
+    Off,
+    SubSector,
+    NodeDepth,
+}
+
+impl Default for DebugMode {
+    fn default() -> Self { DebugMode::Off }
+}
+
+/// The overlay state for the frame being drawn, kept in `RenderData` so
+/// the BSP walk and seg drawing can use it
+#[derive(Debug, Default)]
+pub(crate) struct DebugFrame {
+    pub mode:      DebugMode,
+    /// Only draw this many subsectors
+    pub step:      Option<usize>,
+    /// The subsector being drawn
+    pub subsector: usize,
+    /// How deep the walk is in the BSP
+    pub depth:     usize,
+}
+
+impl DebugFrame {
+    /// Should the walk draw another subsector, `drawn` is how many have been
+    pub fn draw_next(&self, drawn: usize) -> bool {
+        self.step.map_or(true, |step| drawn < step)
+    }
+
+    /// The colour to draw a seg with for the current mode
+    pub fn seg_colour(&self, colour: Color) -> Color {
+        match self.mode {
+            DebugMode::Off => colour,
+            DebugMode::SubSector => debug_colour(self.subsector),
+            DebugMode::NodeDepth => debug_colour(self.depth),
+        }
+    }
+}
+
+/// The overlay settings, these persist between levels
+#[derive(Debug, Default)]
+pub(crate) struct RenderDebug {
+    mode:        DebugMode,
+    step:        Option<usize>,
+    last_counts: [usize; 8],
+}
+
+impl RenderDebug {
+    /// Returns true if the key was used
+    pub(crate) fn responder(&mut self, key: Scancode) -> bool {
+        match key {
+            Scancode::Grave => {
+                self.mode = match self.mode {
+                    DebugMode::Off => DebugMode::SubSector,
+                    DebugMode::SubSector => DebugMode::NodeDepth,
+                    DebugMode::NodeDepth => DebugMode::Off,
+                };
+                println!("Render debug: {:?}", self.mode);
+            }
+            Scancode::N => {
+                let step = self.step.map_or(1, |s| s + 1);
+                println!("Render debug: drawing {} subsectors", step);
+                self.step = Some(step);
+            }
+            Scancode::Backspace => {
+                println!("Render debug: drawing all subsectors");
+                self.step = None;
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Set up a frame to be drawn
+    pub(crate) fn start_frame(&self) -> DebugFrame {
+        DebugFrame {
+            mode: self.mode,
+            step: self.step,
+            ..DebugFrame::default()
+        }
+    }
+
+    /// Draw the overlay for the frame once the view is drawn
+    pub(crate) fn end_frame(
+        &mut self,
+        stats: &RenderStats,
+        solidsegs: &[ClipRange],
+        canvas: &mut Canvas<Surface>,
+    ) {
+        if self.mode == DebugMode::Off {
+            return;
+        }
+
+        canvas.set_draw_color(Color::RGB(0, 160, 0));
+        canvas
+            .fill_rect(Rect::new(0, 0, SCREENWIDTH as u32, CLIP_STRIP_HEIGHT))
+            .unwrap();
+        canvas.set_draw_color(Color::RGB(200, 0, 0));
+        for range in solidsegs {
+            let first = range.first.max(0);
+            let last = range.last.min(SCREENWIDTH as i32 - 1);
+            if first <= last {
+                canvas
+                    .fill_rect(Rect::new(
+                        first,
+                        0,
+                        (last - first + 1) as u32,
+                        CLIP_STRIP_HEIGHT,
+                    ))
+                    .unwrap();
+            }
+        }
+
+        if stats.counts() != self.last_counts {
+            println!("{}", stats);
+            self.last_counts = stats.counts();
+        }
+    }
+}
+
+/// A bright colour that differs for neighbouring `n`
+fn debug_colour(n: usize) -> Color {
+    let hash = (n as u32).wrapping_mul(0x9e37_79b9);
+    Color::RGB(
+        64 + (hash >> 24) as u8 / 4 * 3,
+        64 + (hash >> 16) as u8 / 4 * 3,
+        64 + (hash >> 8) as u8 / 4 * 3,
+    )
+}
@@ -34,10 +34,10 @@ pub(crate) struct DrawSeg {
     /// do not clip sprites below this
     pub tsilheight: f32,
 
-    // TODO: Pointers to lists for sprite clipping,
-    //  all three adjusted so [x1] is first value.
-    pub sprtopclip:       i16,
-    pub sprbottomclip:    i16,
+    /// The ceiling and floor clips for sprites, the first value is for `x1`
+    pub sprtopclip:       Option<Vec<f32>>,
+    pub sprbottomclip:    Option<Vec<f32>>,
+    // TODO: Pointer to the list of masked texture columns
     pub maskedtexturecol: i16,
 }
 
@@ -53,8 +53,8 @@ impl DrawSeg {
             silhouette:       0,
             bsilheight:       0.0,
             tsilheight:       0.0,
-            sprtopclip:       0,
-            sprbottomclip:    0,
+            sprtopclip:       None,
+            sprbottomclip:    None,
             maskedtexturecol: 0,
         }
     }
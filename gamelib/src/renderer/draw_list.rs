@@ -0,0 +1,412 @@
+//! The BSP walk records what is to be drawn in a `DrawList` rather than
+//! drawing as it goes, the list is then rasterised in vertical strips of the
+//! screen. With more than one thread the strips are drawn in parallel.
+//!
+//! Each column is only ever written by the strip it is in, and the commands
+//! for a strip are kept in the order they were recorded, so the output is
+//! the same however many threads are used.
+
+use rayon::prelude::*;
+use sdl2::pixels::Color;
+
+use crate::renderer::defs::SCREENHEIGHT;
+
+/// A single column of one colour, `y1` to `y2` inclusive in either order.
+/// Used for walls and for the floor and ceiling planes.
+#[derive(Debug, Clone, Copy)]
+pub struct SolidColumn {
+    pub x:      i32,
+    pub y1:     i32,
+    pub y2:     i32,
+    /// RGBA bytes in memory order
+    pub colour: [u8; 4],
+}
+
+/// How many rows above (-1) or below (1) each pixel of a fuzz column is
+/// taken from, this is vanilla's `fuzzoffset`
+const FUZZ_OFFSETS: [i8; 50] = [
+    1, -1, 1, -1, 1, 1, -1, 1, 1, -1, 1, 1, 1, -1, 1, 1, 1, -1, -1, -1, -1, 1,
+    -1, -1, 1, 1, 1, 1, -1, 1, -1, 1, 1, -1, -1, 1, 1, -1, -1, -1, -1, 1, 1, 1,
+    1, -1, 1, 1, -1, 1,
+];
+
+/// The fuzz pixels are darkened the same as vanilla's colormap 6, in 32nds
+const FUZZ_SHADE: u32 = 32 - 6;
+
+/// A column of fuzz, the shadow draw of spectres and invisible players
+#[derive(Debug, Clone, Copy)]
+pub struct FuzzColumn {
+    pub x:        i32,
+    pub y1:       i32,
+    pub y2:       i32,
+    /// Where in `FUZZ_OFFSETS` the column starts. Vanilla's `fuzzpos` runs
+    /// on across every column drawn, it is kept per column here so that the
+    /// strips can be drawn in any order.
+    pub fuzz_pos: usize,
+}
+
+/// One post of a sprite column, the pixels are drawn down from `y1`
+#[derive(Debug, Clone, Copy)]
+pub struct SpriteColumn {
+    pub x:     i32,
+    pub y1:    i32,
+    /// Where the post's pixels start in `DrawList::sprite_pixels`
+    pub start: usize,
+    pub len:   usize,
+}
+
+/// Sprites and fuzz are drawn together, back to front, so that a fuzz
+/// column only distorts what is behind it
+#[derive(Debug, Clone, Copy)]
+pub enum MaskedColumn {
+    Sprite(SpriteColumn),
+    Fuzz(FuzzColumn),
+}
+
+impl MaskedColumn {
+    fn x(&self) -> i32 {
+        match self {
+            MaskedColumn::Sprite(column) => column.x,
+            MaskedColumn::Fuzz(column) => column.x,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DrawList {
+    pub walls:         Vec<SolidColumn>,
+    /// The floor and ceiling, R_DrawPlanes
+    pub planes:        Vec<SolidColumn>,
+    /// R_DrawMasked, drawn after the walls and planes
+    pub masked:        Vec<MaskedColumn>,
+    /// The RGBA pixels of every `SpriteColumn`
+    pub sprite_pixels: Vec<[u8; 4]>,
+    fuzz_pos:          usize,
+}
+
+impl DrawList {
+    pub fn clear(&mut self) {
+        self.walls.clear();
+        self.planes.clear();
+        self.masked.clear();
+        self.sprite_pixels.clear();
+    }
+
+    pub fn push_wall(&mut self, x: i32, y1: i32, y2: i32, colour: Color) {
+        self.walls.push(SolidColumn {
+            x,
+            y1,
+            y2,
+            colour: [colour.r, colour.g, colour.b, colour.a],
+        });
+    }
+
+    pub fn push_plane(&mut self, x: i32, y1: i32, y2: i32, colour: Color) {
+        self.planes.push(SolidColumn {
+            x,
+            y1,
+            y2,
+            colour: [colour.r, colour.g, colour.b, colour.a],
+        });
+    }
+
+    /// R_DrawColumn for a sprite post, one pixel for each row from `y1` down
+    pub fn push_sprite<I>(&mut self, x: i32, y1: i32, pixels: I)
+    where
+        I: IntoIterator<Item = [u8; 4]>, {
+        let start = self.sprite_pixels.len();
+        self.sprite_pixels.extend(pixels);
+        let len = self.sprite_pixels.len() - start;
+        if len != 0 {
+            self.masked.push(MaskedColumn::Sprite(SpriteColumn {
+                x,
+                y1,
+                start,
+                len,
+            }));
+        }
+    }
+
+    /// R_DrawFuzzColumn, `y1` to `y2` inclusive
+    pub fn push_fuzz(&mut self, x: i32, y1: i32, y2: i32) {
+        // The fuzz reads the rows above and below, so the top and bottom
+        // rows are left out. This is done before the count so `fuzz_pos`
+        // moves on by the pixels drawn, as vanilla's does.
+        let top = y1.min(y2).max(1);
+        let bottom = y1.max(y2).min(SCREENHEIGHT as i32 - 2);
+        if bottom < top {
+            return;
+        }
+        self.masked.push(MaskedColumn::Fuzz(FuzzColumn {
+            x,
+            y1: top,
+            y2: bottom,
+            fuzz_pos: self.fuzz_pos,
+        }));
+        let count = (bottom - top + 1) as usize;
+        self.fuzz_pos = (self.fuzz_pos + count) % FUZZ_OFFSETS.len();
+    }
+
+    /// How many columns, or posts of sprite columns, are in the list
+    pub fn len(&self) -> usize {
+        self.walls.len() + self.planes.len() + self.masked.len()
+    }
+
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+}
+
+/// The commands of one strip of the screen
+#[derive(Default, Clone)]
+struct Strip {
+    walls:  Vec<SolidColumn>,
+    planes: Vec<SolidColumn>,
+    masked: Vec<MaskedColumn>,
+}
+
+/// Draws `DrawList`s using a pool of worker threads
+pub struct Rasteriser {
+    /// `None` if single threaded
+    pool:    Option<rayon::ThreadPool>,
+    threads: usize,
+}
+
+impl Default for Rasteriser {
+    /// Draw on the calling thread
+    fn default() -> Self {
+        Self {
+            pool:    None,
+            threads: 1,
+        }
+    }
+}
+
+impl Rasteriser {
+    /// A `threads` of 0 uses one thread per core. Fails if the worker
+    /// threads could not be started.
+    pub fn new(threads: usize) -> Result<Self, rayon::ThreadPoolBuildError> {
+        let threads = if threads == 0 {
+            // The global pool has one thread per core
+            rayon::current_num_threads()
+        } else {
+            threads
+        };
+        if threads <= 1 {
+            return Ok(Self::default());
+        }
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()?;
+        Ok(Self {
+            pool: Some(pool),
+            threads,
+        })
+    }
+
+    pub fn threads(&self) -> usize { self.threads }
+
+    /// Draw the list in to `buf`, RGBA32 pixels with rows `pitch` bytes apart
+    pub fn draw(
+        &self,
+        list: &DrawList,
+        buf: &mut [u8],
+        width: usize,
+        height: usize,
+        pitch: usize,
+    ) {
+        if width == 0 || height == 0 {
+            return;
+        }
+        let strip_width = (width + self.threads - 1) / self.threads;
+        let strip_count = (width + strip_width - 1) / strip_width;
+
+        // Sort the commands in to the strip they fall in, keeping order
+        let strip_of = |x: i32| {
+            if x >= 0 && (x as usize) < width {
+                Some(x as usize / strip_width)
+            } else {
+                None
+            }
+        };
+        let mut commands = vec![Strip::default(); strip_count];
+        for wall in &list.walls {
+            if let Some(i) = strip_of(wall.x) {
+                commands[i].walls.push(*wall);
+            }
+        }
+        for plane in &list.planes {
+            if let Some(i) = strip_of(plane.x) {
+                commands[i].planes.push(*plane);
+            }
+        }
+        for column in &list.masked {
+            if let Some(i) = strip_of(column.x()) {
+                commands[i].masked.push(*column);
+            }
+        }
+
+        // Split each row between the strips so every strip has its own
+        // pixels to write to
+        let mut strips: Vec<Vec<&mut [u8]>> = (0..strip_count)
+            .map(|_| Vec::with_capacity(height))
+            .collect();
+        for row in buf.chunks_mut(pitch).take(height) {
+            let mut rest = &mut row[..width * 4];
+            for strip in strips.iter_mut() {
+                let len = (strip_width * 4).min(rest.len());
+                let (pixels, next) = rest.split_at_mut(len);
+                strip.push(pixels);
+                rest = next;
+            }
+        }
+
+        let pixels = &list.sprite_pixels;
+        match self.pool {
+            Some(ref pool) => pool.install(|| {
+                strips
+                    .par_iter_mut()
+                    .zip(commands.par_iter())
+                    .enumerate()
+                    .for_each(|(i, (rows, strip))| {
+                        draw_strip(rows, i * strip_width, strip, pixels)
+                    })
+            }),
+            None => draw_strip(&mut strips[0], 0, &commands[0], pixels),
+        }
+    }
+}
+
+/// Draw the walls, planes, then sprites and fuzz of one strip. `rows` are
+/// the strip's part of each row and `x0` the screen column the strip
+/// starts at.
+fn draw_strip(
+    rows: &mut [&mut [u8]],
+    x0: usize,
+    strip: &Strip,
+    pixels: &[[u8; 4]],
+) {
+    for column in strip.walls.iter().chain(strip.planes.iter()) {
+        draw_solid(rows, x0, column);
+    }
+    for column in &strip.masked {
+        match column {
+            MaskedColumn::Sprite(column) => {
+                draw_sprite(rows, x0, column, pixels)
+            }
+            MaskedColumn::Fuzz(column) => draw_fuzz(rows, x0, column),
+        }
+    }
+}
+
+fn draw_solid(rows: &mut [&mut [u8]], x0: usize, column: &SolidColumn) {
+    let top = column.y1.min(column.y2).max(0);
+    let bottom = column.y1.max(column.y2).min(rows.len() as i32 - 1);
+    let x = (column.x as usize - x0) * 4;
+    for y in top..=bottom {
+        rows[y as usize][x..x + 4].copy_from_slice(&column.colour);
+    }
+}
+
+/// R_DrawColumn, the pixels were looked up when the post was recorded
+fn draw_sprite(
+    rows: &mut [&mut [u8]],
+    x0: usize,
+    column: &SpriteColumn,
+    pixels: &[[u8; 4]],
+) {
+    let x = (column.x as usize - x0) * 4;
+    let post = &pixels[column.start..column.start + column.len];
+    for (y, pixel) in (column.y1..).zip(post.iter()) {
+        if y >= 0 && (y as usize) < rows.len() {
+            rows[y as usize][x..x + 4].copy_from_slice(pixel);
+        }
+    }
+}
+
+/// R_DrawFuzzColumn
+///
+/// Each pixel is replaced by a darkened copy of the one above or below it.
+/// This is done in place top down like vanilla, so a pixel taken from above
+/// has already been fuzzed.
+fn draw_fuzz(rows: &mut [&mut [u8]], x0: usize, column: &FuzzColumn) {
+    // Adjust borders. Low...
+    let top = column.y1.max(1);
+    // .. and high.
+    let bottom = column.y2.min(rows.len() as i32 - 2);
+    let x = (column.x as usize - x0) * 4;
+    let mut fuzz_pos = column.fuzz_pos;
+    for y in top..=bottom {
+        let from = (y + FUZZ_OFFSETS[fuzz_pos] as i32) as usize;
+        let mut pixel = [0; 4];
+        pixel.copy_from_slice(&rows[from][x..x + 4]);
+        for c in pixel.iter_mut().take(3) {
+            *c = (*c as u32 * FUZZ_SHADE / 32) as u8;
+        }
+        rows[y as usize][x..x + 4].copy_from_slice(&pixel);
+        fuzz_pos = (fuzz_pos + 1) % FUZZ_OFFSETS.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DrawList, Rasteriser};
+    use sdl2::pixels::Color;
+
+    const WIDTH: usize = 320;
+    const HEIGHT: usize = 200;
+    /// Padded like an SDL surface's rows can be
+    const PITCH: usize = WIDTH * 4 + 16;
+
+    /// A bit of everything, overlapping and running off the screen
+    fn draw_list() -> DrawList {
+        let mut list = DrawList::default();
+        for x in -2..WIDTH as i32 + 2 {
+            let top = 40 + x % 37;
+            let bottom = 160 - x % 23;
+            list.push_plane(x, -5, top - 1, Color::RGBA(90, 80, 80, 255));
+            list.push_wall(
+                x,
+                top,
+                bottom,
+                Color::RGBA(x as u8, 100, (x * 7) as u8, 255),
+            );
+            list.push_plane(x, bottom + 1, 205, Color::RGBA(90, 90, 90, 255));
+        }
+        for x in 50..120 {
+            let y = x - 10;
+            list.push_sprite(
+                x,
+                y,
+                (0..60).map(|i| [i as u8 * 4, x as u8, 30, 255]),
+            );
+        }
+        for x in 100..260 {
+            // Fuzz running off the top and bottom of the screen
+            list.push_fuzz(x, x - 150, 250 - x / 2);
+        }
+        for x in 110..130 {
+            list.push_sprite(x, -10, (0..30).map(|i| [255, i as u8, 0, 255]));
+        }
+        list
+    }
+
+    fn rasterise(threads: usize) -> Vec<u8> {
+        let rasteriser = Rasteriser::new(threads).unwrap();
+        assert_eq!(rasteriser.threads(), threads);
+        let mut buf = vec![0; PITCH * HEIGHT];
+        rasteriser.draw(&draw_list(), &mut buf, WIDTH, HEIGHT, PITCH);
+        buf
+    }
+
+    #[test]
+    fn threaded_draw_is_bit_identical() {
+        let single = rasterise(1);
+        assert!(single.iter().any(|b| *b != 0));
+        for threads in &[2, 3, 4, 7] {
+            assert!(
+                single == rasterise(*threads),
+                "{} threads differ from 1",
+                threads
+            );
+        }
+    }
+}
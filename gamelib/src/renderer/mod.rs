@@ -1,12 +1,22 @@
+use std::collections::HashSet;
+
 use crate::angle::Angle;
-use crate::renderer::defs::DrawSeg;
+use crate::level_data::map_defs::Sector;
+use crate::renderer::debug::DebugFrame;
+use crate::renderer::defs::{DrawSeg, SCREENHEIGHT};
+use crate::renderer::draw_list::DrawList;
 use crate::renderer::portals::PortalClip;
+use crate::renderer::stats::RenderStats;
 
 pub(crate) mod bsp;
+pub(crate) mod debug;
 pub(crate) mod defs;
+pub mod draw_list;
 pub(crate) mod plane;
 pub(crate) mod portals;
 pub(crate) mod segs;
+pub mod stats;
+pub(crate) mod things;
 
 /// We store most of what is needed for rendering in various functions here to avoid
 /// having to pass too many things in args through multiple function calls. This
@@ -21,15 +31,45 @@ pub(crate) mod segs;
 /// - R_DrawPlanes, r_plane.c, checks only for overflow of drawsegs
 #[derive(Default)]
 pub(crate) struct RenderData {
-    pub rw_angle1:   Angle,
+    pub rw_angle1:      Angle,
     // DrawSeg used, which is inserted in drawsegs at end of r_segs
-    pub drawsegs:    Vec<DrawSeg>,
-    pub portal_clip: PortalClip,
+    pub drawsegs:       Vec<DrawSeg>,
+    pub portal_clip:    PortalClip,
     /// index to drawsegs
     /// Used in r_segs and r_things
-    pub ds_p:        usize, // Or, depending on place in code this can be skipped and a new
+    pub ds_p:           usize, // Or, depending on place in code this can be skipped and a new
+    /// The screen row of the horizon. Half the view height, moved up or down
+    /// by free-look which shears the view rather than rotating it.
+    pub centery:        f32,
+    /// The sectors the BSP walk went through, only things in these are
+    /// drawn. Vanilla marks these with `validcount`.
+    pub sprite_sectors: HashSet<*const Sector>,
+    /// What the BSP walk found to draw, drawn once the walk is done
+    pub draw_list:      DrawList,
+    /// The dev overlay for this frame
+    pub debug:          DebugFrame,
+    pub stats:          RenderStats,
 }
 
 impl RenderData {
-    pub fn clear_data(&mut self) { self.portal_clip.clear(); }
+    pub fn clear_data(&mut self) {
+        self.portal_clip.clear();
+        self.drawsegs.clear();
+        self.sprite_sectors.clear();
+        self.draw_list.clear();
+        self.stats = RenderStats::default();
+    }
+
+    /// Set the horizon for the view pitch, 0.0 is vanilla
+    pub fn set_view_pitch(&mut self, pitch: f32) {
+        // 160 is the distance to the projection plane for a 90 degree view
+        self.centery = SCREENHEIGHT as f32 / 2.0 + 160.0 * pitch.tan();
+    }
+}
+
+/// Darken a colour component for a light level, 0 to 255. Until there are
+/// colormaps a light level of 0 keeps a fifth of the colour.
+pub(crate) fn light_shade(c: u8, lightlevel: i32) -> u8 {
+    let light = lightlevel.max(0).min(255) as u32;
+    (c as u32 * (64 + light) / 319) as u8
 }
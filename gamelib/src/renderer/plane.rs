@@ -91,6 +91,16 @@ impl VisPlaneCtrl {
         self.baseyscale = -(160.0f32).sin();
     }
 
+    /// R_ExecuteSetViewSize, the part setting `yslope`. The slopes are from
+    /// `centery` so that free-look shears the floors and ceilings with the
+    /// walls.
+    pub fn set_yslope(&mut self, centery: f32) {
+        for (i, slope) in self.yslope.iter_mut().enumerate() {
+            let dy = (i as f32 - centery + 0.5).abs();
+            *slope = (SCREENWIDTH as f32 / 2.0) / dy;
+        }
+    }
+
     pub fn current_floor_plane(&self) -> &Visplane {
         &self.visplanes[self.floorplane]
     }
@@ -1,4 +1,4 @@
-use sdl2::{rect::Rect, render::Canvas, surface::Surface};
+use sdl2::{pixels::Color, rect::Rect, render::Canvas, surface::Surface};
 use std::{
     f32::consts::{FRAC_PI_2, PI},
     ptr::NonNull,
@@ -12,7 +12,7 @@ use crate::player::Player;
 use crate::renderer::defs::{
     DrawSeg, MAXDRAWSEGS, SCREENHEIGHT, SIL_BOTH, SIL_BOTTOM, SIL_NONE, SIL_TOP,
 };
-use crate::renderer::RenderData;
+use crate::renderer::{light_shade, RenderData};
 use crate::{point_to_dist, scale_from_view_angle};
 use std::f32::EPSILON;
 
@@ -122,7 +122,6 @@ impl SegRender {
         seg: &Segment,
         object: &Player,
         rdata: &mut RenderData,
-        canvas: &mut Canvas<Surface>,
     ) {
         // Keep original Doom behaviour here
         if rdata.drawsegs.len() >= MAXDRAWSEGS {
@@ -133,6 +132,7 @@ impl SegRender {
             println!("Bad R_RenderWallRange: {} to {}", start, stop);
             return;
         }
+        rdata.stats.drawsegs += 1;
 
         // These need only be locally defined to make some things easier
         let sidedef = seg.sidedef.clone();
@@ -159,6 +159,7 @@ impl SegRender {
         self.rw_distance = hyp * distangle.sin(); // COrrect??? Seems to be...
 
         let mut ds_p = DrawSeg::new(NonNull::from(seg));
+        let width = (stop - start + 1) as usize;
 
         // viewangle = player->mo->angle + viewangleoffset; // offset can be 0, 90, 270
         let view_angle = object.mobj.as_ref().unwrap().obj.angle;
@@ -224,15 +225,14 @@ impl SegRender {
             self.rw_midtexturemid += seg.sidedef.rowoffset;
 
             ds_p.silhouette = SIL_BOTH;
-            // TODO: ds_p.sprtopclip = screenheightarray;
-            // TODO: ds_p.sprbottomclip = negonearray;
+            // screenheightarray and negonearray
+            ds_p.sprtopclip = Some(vec![SCREENHEIGHT as f32; width]);
+            ds_p.sprbottomclip = Some(vec![-1.0; width]);
             ds_p.bsilheight = f32::MAX;
-            ds_p.tsilheight = f32::MAX;
+            ds_p.tsilheight = f32::MIN;
         } else {
             let backsector = seg.backsector.as_ref().unwrap();
             // two sided line
-            // TODO: when thing render started
-            //  ds_p->sprtopclip = ds_p->sprbottomclip = NULL;
             ds_p.silhouette = SIL_NONE;
 
             if frontsector.floorheight > backsector.floorheight {
@@ -244,23 +244,23 @@ impl SegRender {
             }
 
             if frontsector.ceilingheight < backsector.ceilingheight {
-                ds_p.silhouette = SIL_TOP;
+                ds_p.silhouette |= SIL_TOP;
                 ds_p.tsilheight = frontsector.ceilingheight;
             } else if backsector.ceilingheight < viewz {
-                ds_p.silhouette = SIL_TOP;
-                ds_p.bsilheight = f32::MIN;
+                ds_p.silhouette |= SIL_TOP;
+                ds_p.tsilheight = f32::MIN;
             }
 
             if backsector.ceilingheight <= frontsector.floorheight {
-                // TODO: ds_p->sprbottomclip = negonearray;
-                ds_p.silhouette = SIL_BOTTOM;
+                ds_p.sprbottomclip = Some(vec![-1.0; width]);
                 ds_p.bsilheight = f32::MAX;
+                ds_p.silhouette |= SIL_BOTTOM;
             }
 
             if backsector.floorheight >= frontsector.ceilingheight {
-                // TODO: ds_p->sprtopclip = screenheightarray;
-                ds_p.silhouette = SIL_TOP;
-                ds_p.bsilheight = f32::MIN;
+                ds_p.sprtopclip = Some(vec![SCREENHEIGHT as f32; width]);
+                ds_p.tsilheight = f32::MIN;
+                ds_p.silhouette |= SIL_TOP;
             }
 
             self.worldhigh = backsector.ceilingheight - viewz;
@@ -386,21 +386,22 @@ impl SegRender {
             self.markceiling = false;
         }
 
-        // TODO: 100 is half VIEWHEIGHT. Need to sort this stuff out
+        // centery is half VIEWHEIGHT, sheared by free-look
+        let centery = rdata.centery;
         self.topstep = -(self.worldtop * self.rw_scalestep);
-        self.topfrac = 100.0 - (self.worldtop * self.rw_scale);
+        self.topfrac = centery - (self.worldtop * self.rw_scale);
 
         self.bottomstep = -(self.worldbottom * self.rw_scalestep);
-        self.bottomfrac = 100.0 - (self.worldbottom * self.rw_scale);
+        self.bottomfrac = centery - (self.worldbottom * self.rw_scale);
 
         if seg.backsector.is_some() {
             if self.worldhigh < self.worldtop {
-                self.pixhigh = 100.0 - (self.worldhigh * self.rw_scale);
+                self.pixhigh = centery - (self.worldhigh * self.rw_scale);
                 self.pixhighstep = -(self.worldhigh * self.rw_scalestep);
             }
 
             if self.worldlow > self.worldbottom {
-                self.pixlow = 100.0 - (self.worldlow * self.rw_scale);
+                self.pixlow = centery - (self.worldlow * self.rw_scale);
                 self.pixlowstep = -(self.worldlow * self.rw_scalestep);
             }
         }
@@ -414,7 +415,31 @@ impl SegRender {
             // floorplane = R_CheckPlane(floorplane, self.rw_x, self.rw_stopx - 1);
         }
 
-        self.render_seg_loop(object, seg, rdata, canvas);
+        self.render_seg_loop(object, seg, rdata);
+
+        // save sprite clipping info
+        let clip = start as usize..=stop as usize;
+        if (ds_p.silhouette & SIL_TOP != 0 || self.maskedtexture)
+            && ds_p.sprtopclip.is_none()
+        {
+            ds_p.sprtopclip =
+                Some(rdata.portal_clip.ceilingclip[clip.clone()].to_vec());
+        }
+        if (ds_p.silhouette & SIL_BOTTOM != 0 || self.maskedtexture)
+            && ds_p.sprbottomclip.is_none()
+        {
+            ds_p.sprbottomclip =
+                Some(rdata.portal_clip.floorclip[clip].to_vec());
+        }
+        if self.maskedtexture && ds_p.silhouette & SIL_TOP == 0 {
+            ds_p.silhouette |= SIL_TOP;
+            ds_p.tsilheight = f32::MIN;
+        }
+        if self.maskedtexture && ds_p.silhouette & SIL_BOTTOM == 0 {
+            ds_p.silhouette |= SIL_BOTTOM;
+            ds_p.bsilheight = f32::MAX;
+        }
+        rdata.drawsegs.push(ds_p);
     }
 
     fn render_seg_loop(
@@ -422,7 +447,6 @@ impl SegRender {
         player: &Player,
         seg: &Segment,
         rdata: &mut RenderData,
-        canvas: &mut Canvas<Surface>,
     ) {
         //
         // TESTING STUFF
@@ -441,12 +465,21 @@ impl SegRender {
         let z = seg.sidedef.sector.floorheight.abs() as u8 / 2;
 
         let colour = sdl2::pixels::Color::RGBA(
-            100 + (self.midtexture * 5) as u8 + lightnum - (z >> 2) as u8,
-            100 + (self.toptexture * 5) as u8 + lightnum - (z >> 2) as u8,
-            100 + (self.bottomtexture * 5) as u8 + lightnum - (z >> 2) as u8,
+            100 + (self.midtexture.min(1) * 5) as u8 + lightnum
+                - (z >> 2) as u8,
+            100 + (self.toptexture.min(1) * 5) as u8 + lightnum
+                - (z >> 2) as u8,
+            100 + (self.bottomtexture.min(1) * 5) as u8 + lightnum
+                - (z >> 2) as u8,
             255,
         );
-        canvas.set_draw_color(colour);
+        let colour = rdata.debug.seg_colour(colour);
+
+        // TODO: R_DrawPlanes with the flats, until then the planes are the
+        //  colours the view is cleared to, shaded by the sector's light
+        let lightlevel = seg.frontsector.lightlevel;
+        let ceiling_colour = plane_colour(90, 80, 80, lightlevel);
+        let floor_colour = plane_colour(90, 90, 90, lightlevel);
 
         // R_RenderSegLoop
         let mut yl;
@@ -455,6 +488,7 @@ impl SegRender {
         let mut bottom;
         let mut mid;
         while self.rw_x < self.rw_stopx {
+            let commands = rdata.draw_list.len();
             yl = self.topfrac + 1.0;
             if yl < rdata.portal_clip.ceilingclip[self.rw_x as usize] + 1.0 {
                 yl = rdata.portal_clip.ceilingclip[self.rw_x as usize] + 1.0;
@@ -469,7 +503,12 @@ impl SegRender {
                         rdata.portal_clip.floorclip[self.rw_x as usize] - 1.0;
                 }
                 if top <= bottom {
-                    // TODO: ceilingplane
+                    rdata.draw_list.push_plane(
+                        self.rw_x,
+                        top as i32,
+                        bottom as i32,
+                        ceiling_colour,
+                    );
                 }
             }
 
@@ -486,7 +525,12 @@ impl SegRender {
                         rdata.portal_clip.ceilingclip[self.rw_x as usize] + 1.0;
                 }
                 if top <= bottom {
-                    // TODO: floorplane
+                    rdata.draw_list.push_plane(
+                        self.rw_x,
+                        top as i32,
+                        bottom as i32,
+                        floor_colour,
+                    );
                 }
             }
 
@@ -495,9 +539,9 @@ impl SegRender {
             }
 
             if self.midtexture != 0 && yh > yl {
-                canvas
-                    .draw_line((self.rw_x, yl as i32), (self.rw_x, yh as i32))
-                    .unwrap();
+                rdata
+                    .draw_list
+                    .push_wall(self.rw_x, yl as i32, yh as i32, colour);
 
                 rdata.portal_clip.ceilingclip[self.rw_x as usize] =
                     SCREENHEIGHT as f32;
@@ -518,12 +562,9 @@ impl SegRender {
                             &player.mobj.as_ref().unwrap().obj.xy,
                         ) == 0
                         {
-                            canvas
-                                .draw_line(
-                                    (self.rw_x, yl as i32),
-                                    (self.rw_x, mid as i32),
-                                )
-                                .unwrap();
+                            rdata.draw_list.push_wall(
+                                self.rw_x, yl as i32, mid as i32, colour,
+                            );
                         }
 
                         rdata.portal_clip.ceilingclip[self.rw_x as usize] = mid;
@@ -552,12 +593,9 @@ impl SegRender {
                             &player.mobj.as_ref().unwrap().obj.xy,
                         ) == 0
                         {
-                            canvas
-                                .draw_line(
-                                    (self.rw_x, yh as i32),
-                                    (self.rw_x, mid as i32),
-                                )
-                                .unwrap();
+                            rdata.draw_list.push_wall(
+                                self.rw_x, yh as i32, mid as i32, colour,
+                            );
                         }
 
                         rdata.portal_clip.floorclip[self.rw_x as usize] = mid;
@@ -570,6 +608,9 @@ impl SegRender {
                 }
             }
 
+            if rdata.draw_list.len() != commands {
+                rdata.stats.columns += 1;
+            }
             self.rw_x += 1;
             self.rw_scale += self.rw_scalestep;
             self.topfrac += self.topstep;
@@ -593,3 +634,14 @@ impl SegRender {
         // }
     }
 }
+
+/// Scale a plane colour by a sector light level
+fn plane_colour(r: u8, g: u8, b: u8, lightlevel: i16) -> Color {
+    let light = lightlevel as i32;
+    Color::RGBA(
+        light_shade(r, light),
+        light_shade(g, light),
+        light_shade(b, light),
+        255,
+    )
+}
@@ -0,0 +1,62 @@
+//! Counts and timings of the renderer for each frame. These are shown by the
+//! stats HUD and the `dev_parm` overlay, and can be read with
+//! `Game::render_stats` for benchmarks and performance checks.
+
+use std::{fmt, time::Duration};
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RenderStats {
+    /// BSP nodes visited by R_RenderBSPNode, subsectors included
+    pub nodes:         usize,
+    pub subsectors:    usize,
+    /// Segs given to R_AddLine
+    pub segs:          usize,
+    /// Segs R_AddLine found facing away, off screen or with nothing to draw
+    pub segs_rejected: usize,
+    /// Seg ranges stored by R_StoreWallRange
+    pub drawsegs:      usize,
+    /// Screen columns drawn by each seg and sprite, a column drawn in more
+    /// than one piece counts once
+    pub columns:       usize,
+    pub visplanes:     usize,
+    /// Things projected by R_ProjectSprite
+    pub sprites:       usize,
+    /// Time taken by the BSP walk
+    pub bsp_time:      Duration,
+    /// Time taken to rasterise the draw list
+    pub draw_time:     Duration,
+}
+
+impl RenderStats {
+    /// Everything but the times, these are the same for the same view
+    pub fn counts(&self) -> [usize; 8] {
+        [
+            self.nodes,
+            self.subsectors,
+            self.segs,
+            self.segs_rejected,
+            self.drawsegs,
+            self.columns,
+            self.visplanes,
+            self.sprites,
+        ]
+    }
+}
+
+impl fmt::Display for RenderStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "RenderStats:\n  - nodes: {}\n  - subsectors: {}\n  - segs: {} ({} rejected)\n  - drawsegs: {}\n  - columns: {}\n  - visplanes: {}\n  - sprites: {}\n  - bsp: {:.2}ms\n  - draw: {:.2}ms",
+            self.nodes,
+            self.subsectors,
+            self.segs,
+            self.segs_rejected,
+            self.drawsegs,
+            self.columns,
+            self.visplanes,
+            self.sprites,
+            self.bsp_time.as_secs_f32() * 1000.0,
+            self.draw_time.as_secs_f32() * 1000.0,
+        ))
+    }
+}
@@ -0,0 +1,669 @@
+//! Parts of r_things.c, how things and the player's weapon are drawn.
+//!
+//! The BSP walk notes the sectors it goes through, once it is done the things
+//! in those sectors are projected and recorded in the draw list back to front.
+//! Each sprite is clipped by the drawsegs in front of it. Shadows are drawn as
+//! fuzz rather than a normal masked column.
+
+use glam::Vec2;
+use std::{cmp::Ordering, f32::consts::PI};
+use wad::{
+    lumps::{WadPalette, WadPatch},
+    WadData,
+};
+
+use crate::{
+    angle::Angle,
+    doom_def::PowerType,
+    info::{SpriteNum, SPRNAMES},
+    p_map_object::{MapObject, MapObjectFlag},
+    p_player_sprite::PspDef,
+    player::Player,
+    renderer::{
+        bsp::point_to_angle_2,
+        defs::{SCREENHEIGHT, SCREENWIDTH, SIL_BOTTOM, SIL_NONE, SIL_TOP},
+        draw_list::DrawList,
+        light_shade, RenderData,
+    },
+};
+
+/// The frame is drawn at full brightness
+pub(crate) const FF_FULLBRIGHT: i32 = 0x8000;
+pub(crate) const FF_FRAMEMASK: i32 = 0x7fff;
+
+/// Frames are lettered `A` on, up to `]` in Doom II
+const MAX_SPRITE_FRAMES: usize = 29;
+
+/// Things closer than this are not drawn
+const MINZ: f32 = 4.0;
+
+/// `centerxfrac`, which is also the distance to the projection plane
+const PROJECTION: f32 = SCREENWIDTH as f32 / 2.0;
+
+/// The weapon is placed relative to this row
+const BASEYCENTER: f32 = 100.0;
+
+/// `spriteframe_t`
+#[derive(Debug, Clone, Copy)]
+struct SpriteFrame {
+    /// If false use 0 for any position
+    rotate: bool,
+    /// The patch for each view angle, an index in to `SpriteData::patches`
+    lump:   [usize; 8],
+    /// Flip the patch horizontally for each view angle
+    flip:   [bool; 8],
+}
+
+/// A frame while the sprite lumps are being read. `rotate` and the `lump`s
+/// are `None` until found, vanilla's -1.
+#[derive(Debug, Default, Clone, Copy)]
+struct TempFrame {
+    rotate: Option<bool>,
+    lump:   [Option<usize>; 8],
+    flip:   [bool; 8],
+}
+
+/// The frames of every sprite and the patches they use, vanilla's
+/// `sprites` and sprite lump caches
+pub(crate) struct SpriteData {
+    /// The frames of each `SpriteNum`, vanilla's `spritedef_t`
+    sprites: Vec<Vec<SpriteFrame>>,
+    patches: Vec<WadPatch>,
+}
+
+impl SpriteData {
+    /// R_InitSprites
+    pub fn new(wad: &WadData) -> Self {
+        let names = wad.sprite_names();
+        let sprites = SPRNAMES
+            .iter()
+            .map(|sprite| init_sprite_def(sprite, &names))
+            .collect();
+        let patches = names.iter().map(|name| wad.get_patch(name)).collect();
+        Self { sprites, patches }
+    }
+
+    fn frame(&self, sprite: SpriteNum, frame: i32) -> Option<&SpriteFrame> {
+        self.sprites
+            .get(sprite as usize)?
+            .get((frame & FF_FRAMEMASK) as usize)
+    }
+}
+
+/// R_InitSpriteDefs for one sprite. Each lump named for the sprite holds a
+/// frame at one rotation, or all of them if the rotation is 0, and may name
+/// a second frame and rotation that it is flipped for.
+fn init_sprite_def(sprite: &str, names: &[String]) -> Vec<SpriteFrame> {
+    let mut temp = [TempFrame::default(); MAX_SPRITE_FRAMES];
+    let mut maxframe = None;
+
+    for (lump, name) in names.iter().enumerate() {
+        let name = name.as_bytes();
+        if name.len() < 6 || !name.starts_with(sprite.as_bytes()) {
+            continue;
+        }
+        let frame = name[4].wrapping_sub(b'A') as usize;
+        let rotation = name[5].wrapping_sub(b'0') as usize;
+        install_sprite_lump(&mut temp, sprite, lump, frame, rotation, false);
+        maxframe = maxframe.max(Some(frame));
+
+        if name.len() >= 8 {
+            let frame = name[6].wrapping_sub(b'A') as usize;
+            let rotation = name[7].wrapping_sub(b'0') as usize;
+            install_sprite_lump(&mut temp, sprite, lump, frame, rotation, true);
+            maxframe = maxframe.max(Some(frame));
+        }
+    }
+
+    let maxframe = match maxframe {
+        Some(frame) => frame,
+        // no frames, such as the Doom II only sprites in Doom
+        None => return Vec::new(),
+    };
+
+    // check the frames that were found for completeness
+    temp[..=maxframe]
+        .iter()
+        .enumerate()
+        .map(|(frame, temp)| match temp.rotate {
+            None => panic!(
+                "R_InitSprites: No patches found for {} frame {}",
+                sprite,
+                (b'A' + frame as u8) as char
+            ),
+            Some(rotate) => {
+                let mut lump = [0; 8];
+                for (r, l) in temp.lump.iter().enumerate() {
+                    // must have all 8 frames
+                    lump[r] = l.unwrap_or_else(|| {
+                        panic!(
+                            "R_InitSprites: Sprite {} frame {} is missing \
+                             rotations",
+                            sprite,
+                            (b'A' + frame as u8) as char
+                        )
+                    });
+                }
+                SpriteFrame {
+                    rotate,
+                    lump,
+                    flip: temp.flip,
+                }
+            }
+        })
+        .collect()
+}
+
+/// R_InstallSpriteLump
+fn install_sprite_lump(
+    temp: &mut [TempFrame; MAX_SPRITE_FRAMES],
+    sprite: &str,
+    lump: usize,
+    frame: usize,
+    rotation: usize,
+    flipped: bool,
+) {
+    if frame >= MAX_SPRITE_FRAMES || rotation > 8 {
+        panic!("R_InstallSpriteLump: Bad frame characters in {}", sprite);
+    }
+    let temp = &mut temp[frame];
+    let letter = (b'A' + frame as u8) as char;
+
+    if rotation == 0 {
+        // the lump should be used for all rotations
+        match temp.rotate {
+            Some(false) => panic!(
+                "R_InitSprites: Sprite {} frame {} has multip rot=0 lump",
+                sprite, letter
+            ),
+            Some(true) => panic!(
+                "R_InitSprites: Sprite {} frame {} has rotations and a rot=0 \
+                 lump",
+                sprite, letter
+            ),
+            None => {}
+        }
+        temp.rotate = Some(false);
+        temp.lump = [Some(lump); 8];
+        temp.flip = [flipped; 8];
+        return;
+    }
+
+    // the lump is only used for one rotation
+    if temp.rotate == Some(false) {
+        panic!(
+            "R_InitSprites: Sprite {} frame {} has rotations and a rot=0 lump",
+            sprite, letter
+        );
+    }
+    temp.rotate = Some(true);
+
+    // make 0 based
+    let rotation = rotation - 1;
+    if temp.lump[rotation].is_some() {
+        panic!(
+            "R_InitSprites: Sprite {} : {} : {} has two lumps mapped to it",
+            sprite,
+            letter,
+            (b'1' + rotation as u8) as char
+        );
+    }
+    temp.lump[rotation] = Some(lump);
+    temp.flip[rotation] = flipped;
+}
+
+/// A thing or the weapon ready to draw, `vissprite_t`
+struct VisSprite {
+    x1:         i32,
+    x2:         i32,
+    /// For line side calculation
+    xy:         Vec2,
+    /// Global bottom and top, for silhouette clipping
+    gz:         f32,
+    gzt:        f32,
+    /// Horizontal position of x1 in the patch
+    startfrac:  f32,
+    scale:      f32,
+    /// Negative if flipped
+    xiscale:    f32,
+    texturemid: f32,
+    patch:      usize,
+    /// The light level to draw with, `None` if drawn as fuzz
+    light:      Option<i32>,
+}
+
+/// Where things are seen from
+struct View {
+    xy:    Vec2,
+    angle: Angle,
+    z:     f32,
+    /// Added to the sector light, in light levels
+    light: i32,
+}
+
+/// The flag checked by R_ProjectSprite, things with `MF_SHADOW` such as
+/// spectres are drawn as fuzz
+pub(crate) fn thing_is_shadow(thing: &MapObject) -> bool {
+    thing.flags & MapObjectFlag::MF_SHADOW as u32 != 0
+}
+
+/// The check from R_DrawPSprite, the weapon of an invisible player is fuzz.
+/// It flickers back to normal as the power runs out.
+pub(crate) fn psprite_is_shadow(player: &Player) -> bool {
+    let invisibility = player.powers[PowerType::pw_invisibility as usize];
+    invisibility > 4 * 32 || invisibility & 8 != 0
+}
+
+/// R_DrawMasked, run once the BSP walk is done. The things in the sectors
+/// the walk went through are drawn back to front, R_AddSprites, then the
+/// player's weapon over them. `frac` is how far through the tic the frame
+/// is, as for the view.
+pub(crate) fn draw_masked<'a>(
+    things: impl Iterator<Item = &'a MapObject>,
+    frac: f32,
+    player: &Player,
+    sprites: &SpriteData,
+    palette: &WadPalette,
+    rdata: &mut RenderData,
+) {
+    let mobj = match player.mobj {
+        Some(ref mobj) => &mobj.obj,
+        None => return,
+    };
+    let view = View {
+        xy:    mobj.xy,
+        angle: mobj.angle,
+        z:     player.viewz,
+        light: player.extralight * 16,
+    };
+
+    let mut vissprites: Vec<VisSprite> = things
+        .filter(|thing| {
+            // things not in a sector's list are never seen
+            thing.flags & MapObjectFlag::MF_NOSECTOR as u32 == 0
+                && rdata
+                    .sprite_sectors
+                    .contains(&(&*thing.subsector.sector as *const _))
+        })
+        .filter_map(|thing| project_sprite(thing, frac, &view, sprites))
+        .collect();
+    rdata.stats.sprites = vissprites.len();
+
+    // R_SortVisSprites, furthest first
+    vissprites.sort_by(|a, b| {
+        a.scale.partial_cmp(&b.scale).unwrap_or(Ordering::Equal)
+    });
+    for vis in &vissprites {
+        draw_sprite(vis, sprites, palette, rdata);
+    }
+
+    // TODO: R_RenderMaskedSegRange for the drawsegs with mid textures
+
+    // R_DrawPlayerSprites
+    let light = mobj.subsector.sector.lightlevel as i32 + view.light;
+    for psp in player.psprites.iter() {
+        rdata.stats.columns += draw_psprite(
+            psp,
+            player,
+            light,
+            sprites,
+            palette,
+            &mut rdata.draw_list,
+        );
+    }
+}
+
+/// R_ProjectSprite, generates a vissprite for a thing if it might be visible.
+/// The thing is drawn `frac` of the way from where it was at the start of
+/// the tic to where it is now.
+fn project_sprite(
+    thing: &MapObject,
+    frac: f32,
+    view: &View,
+    sprites: &SpriteData,
+) -> Option<VisSprite> {
+    let xy = thing.prev_xy + (thing.xy - thing.prev_xy) * frac;
+    let z = thing.prev_z + (thing.z - thing.prev_z) * frac;
+    let angle = thing.prev_angle.lerp(thing.angle, frac);
+
+    // transform the origin point
+    let tr = xy - view.xy;
+    let (sin, cos) = (view.angle.sin(), view.angle.cos());
+    let tz = tr.x() * cos + tr.y() * sin;
+
+    // thing is behind view plane?
+    if tz < MINZ {
+        return None;
+    }
+    let xscale = PROJECTION / tz;
+
+    let mut tx = tr.x() * sin - tr.y() * cos;
+    // too far off the side?
+    if tx.abs() > tz * 4.0 {
+        return None;
+    }
+
+    let sprframe = sprites.frame(thing.sprite, thing.frame)?;
+    let (lump, flip) = if sprframe.rotate {
+        // choose a different rotation based on player view
+        let ang = point_to_angle_2(&xy, &view.xy);
+        let rot = ((ang - angle).rad() + PI / 8.0 * 9.0) / (PI / 4.0);
+        let rot = rot as usize % 8;
+        (sprframe.lump[rot], sprframe.flip[rot])
+    } else {
+        // use single rotation for all views
+        (sprframe.lump[0], sprframe.flip[0])
+    };
+    let patch = &sprites.patches[lump];
+
+    // calculate edges of the shape
+    tx -= patch.left_offset as f32;
+    let x1 = (PROJECTION + tx * xscale).floor() as i32;
+    // off the right side?
+    if x1 > SCREENWIDTH as i32 {
+        return None;
+    }
+    tx += patch.width as f32;
+    let x2 = (PROJECTION + tx * xscale).floor() as i32 - 1;
+    // off the left side
+    if x2 < 0 {
+        return None;
+    }
+
+    let gzt = z + patch.top_offset as f32;
+    let light = if thing_is_shadow(thing) {
+        None
+    } else if thing.frame & FF_FULLBRIGHT != 0 {
+        Some(255)
+    } else {
+        Some(thing.subsector.sector.lightlevel as i32 + view.light)
+    };
+    Some(vis_sprite(
+        x1,
+        x2,
+        xscale,
+        patch.width,
+        flip,
+        VisSprite {
+            x1: 0,
+            x2: 0,
+            xy,
+            gz: z,
+            gzt,
+            startfrac: 0.0,
+            scale: xscale,
+            xiscale: 0.0,
+            texturemid: gzt - view.z,
+            patch: lump,
+            light,
+        },
+    ))
+}
+
+/// Clip `x1` and `x2` to the screen and set where in the patch drawing
+/// starts and which way it steps
+fn vis_sprite(
+    x1: i32,
+    x2: i32,
+    xscale: f32,
+    width: u16,
+    flip: bool,
+    mut vis: VisSprite,
+) -> VisSprite {
+    vis.x1 = x1.max(0);
+    vis.x2 = x2.min(SCREENWIDTH as i32 - 1);
+    let iscale = 1.0 / xscale;
+    if flip {
+        // Just short of the right edge, as vanilla's `spritewidth - 1`
+        vis.startfrac = width as f32 - 1.0 / 65536.0;
+        vis.xiscale = -iscale;
+    } else {
+        vis.startfrac = 0.0;
+        vis.xiscale = iscale;
+    }
+    if vis.x1 > x1 {
+        vis.startfrac += vis.xiscale * (vis.x1 - x1) as f32;
+    }
+    vis
+}
+
+/// R_DrawSprite
+fn draw_sprite(
+    vis: &VisSprite,
+    sprites: &SpriteData,
+    palette: &WadPalette,
+    rdata: &mut RenderData,
+) {
+    // `None` until clipped, vanilla's -2
+    let mut clipbot = [None; SCREENWIDTH];
+    let mut cliptop = [None; SCREENWIDTH];
+
+    // Scan drawsegs from end to start for obscuring segs.
+    // The first drawseg that has a greater scale is the clip seg.
+    for ds in rdata.drawsegs.iter().rev() {
+        // determine if the drawseg obscures the sprite
+        if ds.x1 > vis.x2
+            || ds.x2 < vis.x1
+            || (ds.silhouette == SIL_NONE && ds.maskedtexturecol == 0)
+        {
+            // does not cover sprite
+            continue;
+        }
+
+        let r1 = ds.x1.max(vis.x1);
+        let r2 = ds.x2.min(vis.x2);
+        let (lowscale, scale) = if ds.scale1 > ds.scale2 {
+            (ds.scale2, ds.scale1)
+        } else {
+            (ds.scale1, ds.scale2)
+        };
+
+        let curline = unsafe { ds.curline.as_ref() };
+        if scale < vis.scale
+            || (lowscale < vis.scale && curline.is_facing_point(&vis.xy))
+        {
+            // masked mid texture?
+            // TODO: R_RenderMaskedSegRange
+            // seg is behind sprite
+            continue;
+        }
+
+        // clip this piece of the sprite
+        let mut silhouette = ds.silhouette;
+        if vis.gz >= ds.bsilheight {
+            silhouette &= !SIL_BOTTOM;
+        }
+        if vis.gzt <= ds.tsilheight {
+            silhouette &= !SIL_TOP;
+        }
+
+        for r in r1..=r2 {
+            let i = (r - ds.x1) as usize;
+            let x = r as usize;
+            if silhouette & SIL_BOTTOM != 0 {
+                if let Some(ref clip) = ds.sprbottomclip {
+                    clipbot[x].get_or_insert(clip[i]);
+                }
+            }
+            if silhouette & SIL_TOP != 0 {
+                if let Some(ref clip) = ds.sprtopclip {
+                    cliptop[x].get_or_insert(clip[i]);
+                }
+            }
+        }
+    }
+
+    // all clipping has been performed, so draw the sprite
+    // check for unclipped columns
+    let mut floorclip = [SCREENHEIGHT as f32; SCREENWIDTH];
+    let mut ceilingclip = [-1.0; SCREENWIDTH];
+    for x in vis.x1 as usize..=vis.x2 as usize {
+        if let Some(clip) = clipbot[x] {
+            floorclip[x] = clip;
+        }
+        if let Some(clip) = cliptop[x] {
+            ceilingclip[x] = clip;
+        }
+    }
+    rdata.stats.columns += draw_vis_sprite(
+        vis,
+        &sprites.patches[vis.patch],
+        palette,
+        rdata.centery,
+        &ceilingclip,
+        &floorclip,
+        &mut rdata.draw_list,
+    );
+}
+
+/// R_DrawPSprite, returns how many columns were drawn
+fn draw_psprite(
+    psp: &PspDef,
+    player: &Player,
+    light: i32,
+    sprites: &SpriteData,
+    palette: &WadPalette,
+    draw_list: &mut DrawList,
+) -> usize {
+    let state = match psp.state {
+        Some(ref state) => state,
+        None => return 0,
+    };
+    let sprframe = match sprites.frame(state.sprite, state.frame) {
+        Some(frame) => frame,
+        None => return 0,
+    };
+    let (lump, flip) = (sprframe.lump[0], sprframe.flip[0]);
+    let patch = &sprites.patches[lump];
+
+    // calculate edges of the shape
+    let mut tx = psp.sx - PROJECTION;
+    tx -= patch.left_offset as f32;
+    let x1 = (PROJECTION + tx).floor() as i32;
+    // off the right side
+    if x1 > SCREENWIDTH as i32 {
+        return 0;
+    }
+    tx += patch.width as f32;
+    let x2 = (PROJECTION + tx).floor() as i32 - 1;
+    // off the left side
+    if x2 < 0 {
+        return 0;
+    }
+
+    let light = if psprite_is_shadow(player) {
+        None
+    } else if state.frame & FF_FULLBRIGHT != 0 {
+        Some(255)
+    } else {
+        Some(light)
+    };
+    let vis = vis_sprite(
+        x1,
+        x2,
+        1.0,
+        patch.width,
+        flip,
+        VisSprite {
+            x1: 0,
+            x2: 0,
+            xy: Vec2::default(),
+            gz: 0.0,
+            gzt: 0.0,
+            startfrac: 0.0,
+            scale: 1.0,
+            xiscale: 0.0,
+            texturemid: BASEYCENTER + 0.5 - (psp.sy - patch.top_offset as f32),
+            patch: lump,
+            light,
+        },
+    );
+    // The weapon doesn't move with free-look, so it is placed from the
+    // middle of the screen rather than the sheared horizon
+    draw_vis_sprite(
+        &vis,
+        patch,
+        palette,
+        SCREENHEIGHT as f32 / 2.0,
+        &[-1.0; SCREENWIDTH],
+        &[SCREENHEIGHT as f32; SCREENWIDTH],
+        draw_list,
+    )
+}
+
+/// R_DrawVisSprite and R_DrawMaskedColumn. Each post of each column is
+/// clipped to between `ceilingclip` and `floorclip`. Returns how many
+/// columns had something drawn.
+fn draw_vis_sprite(
+    vis: &VisSprite,
+    patch: &WadPatch,
+    palette: &WadPalette,
+    centery: f32,
+    ceilingclip: &[f32; SCREENWIDTH],
+    floorclip: &[f32; SCREENWIDTH],
+    draw_list: &mut DrawList,
+) -> usize {
+    let iscale = 1.0 / vis.scale;
+    let sprtopscreen = centery - vis.texturemid * vis.scale;
+    let last_column = patch.columns.len() as i32 - 1;
+    let mut frac = vis.startfrac;
+    let mut columns = 0;
+
+    for x in vis.x1..=vis.x2 {
+        let texturecolumn = (frac.floor() as i32).max(0).min(last_column);
+        frac += vis.xiscale;
+        let column = match patch.columns.get(texturecolumn as usize) {
+            Some(column) => column,
+            None => continue,
+        };
+        let top_clip = ceilingclip[x as usize] as i32;
+        let bottom_clip = floorclip[x as usize] as i32;
+        let commands = draw_list.len();
+
+        for post in column {
+            // calculate unclipped screen coordinates for post
+            let topscreen = sprtopscreen + vis.scale * post.top_delta as f32;
+            let bottomscreen = topscreen + vis.scale * post.pixels.len() as f32;
+
+            let mut yl = topscreen.ceil() as i32;
+            let mut yh = bottomscreen.ceil() as i32 - 1;
+            if yh >= bottom_clip {
+                yh = bottom_clip - 1;
+            }
+            if yl <= top_clip {
+                yl = top_clip + 1;
+            }
+            if yl > yh || post.pixels.is_empty() {
+                continue;
+            }
+
+            match vis.light {
+                None => draw_list.push_fuzz(x, yl, yh),
+                Some(light) => {
+                    let texturemid = vis.texturemid - post.top_delta as f32;
+                    let last = post.pixels.len() - 1;
+                    draw_list.push_sprite(
+                        x,
+                        yl,
+                        (yl..=yh).map(|y| {
+                            let row =
+                                texturemid + (y as f32 - centery) * iscale;
+                            let row = (row.max(0.0) as usize).min(last);
+                            let colour = palette.0[post.pixels[row] as usize];
+                            [
+                                light_shade(colour.r, light),
+                                light_shade(colour.g, light),
+                                light_shade(colour.b, light),
+                                255,
+                            ]
+                        }),
+                    );
+                }
+            }
+        }
+        if draw_list.len() != commands {
+            columns += 1;
+        }
+    }
+    columns
+}
@@ -4,7 +4,7 @@ use glam::{Mat4, Vec3};
 use golem::Dimension::*;
 use golem::*;
 
-use super::{Renderer, GL_QUAD, GL_QUAD_INDICES};
+use super::{Renderer, ShaderParam, GL_QUAD, GL_QUAD_INDICES};
 
 pub(crate) struct Basic<'c> {
     ctx:        &'c Context,
@@ -125,4 +125,6 @@ impl<'c> Renderer for Basic<'c> {
         }
         Ok(())
     }
+
+    fn params_mut(&mut self) -> &mut [ShaderParam] { &mut [] }
 }
@@ -3,8 +3,9 @@ use std::f32::consts::FRAC_PI_4;
 use glam::{Mat4, Vec3};
 use golem::Dimension::*;
 use golem::*;
+use sdl2::rect::Rect;
 
-use super::{Renderer, GL_QUAD, GL_QUAD_INDICES};
+use super::{Renderer, ShaderParam, GL_QUAD, GL_QUAD_INDICES};
 
 /// CRT shader
 /// ```
@@ -30,6 +31,7 @@ pub(crate) struct CGWGCRT<'c> {
     texture:    Texture,
     vb:         VertexBuffer,
     eb:         ElementBuffer,
+    params:     Vec<ShaderParam>,
 }
 
 impl<'c> CGWGCRT<'c> {
@@ -396,6 +398,17 @@ void main()
             texture: Texture::new(ctx).unwrap(),
             vb,
             eb,
+            params: vec![
+                ShaderParam::new("CRTgamma", 1.9),
+                ShaderParam::new("monitorgamma", 2.4),
+                // distance from viewer
+                ShaderParam::new("d", 1.5),
+                // radius of curvature - 2.0 to 3.0?
+                ShaderParam::new("R", 2.3),
+                ShaderParam::new("cornersize", 0.02),
+                // border smoothness parameter
+                ShaderParam::new("cornersmooth", 80.0),
+            ],
         }
     }
 }
@@ -463,19 +476,10 @@ impl<'c> Renderer for CGWGCRT<'c> {
             ]),
         )?;
 
-        self.crt_shader
-            .set_uniform("CRTgamma", UniformValue::Float(1.9))?;
-        self.crt_shader
-            .set_uniform("monitorgamma", UniformValue::Float(2.4))?;
-        // distance from viewer
-        self.crt_shader.set_uniform("d", UniformValue::Float(1.5))?;
-        // radius of curvature - 2.0 to 3.0?
-        self.crt_shader.set_uniform("R", UniformValue::Float(2.3))?;
-        self.crt_shader
-            .set_uniform("cornersize", UniformValue::Float(0.02))?;
-        // border smoothness parameter
-        self.crt_shader
-            .set_uniform("cornersmooth", UniformValue::Float(80.0))?;
+        for param in &self.params {
+            self.crt_shader
+                .set_uniform(param.name, UniformValue::Float(param.value))?;
+        }
 
         self.crt_shader
             .set_uniform("overscan", UniformValue::Vector2([0.99, 0.99]))?;
@@ -488,4 +492,11 @@ impl<'c> Renderer for CGWGCRT<'c> {
         }
         Ok(())
     }
+
+    fn resize(&mut self, viewport: Rect) {
+        self.crt_width = viewport.width();
+        self.crt_height = viewport.height();
+    }
+
+    fn params_mut(&mut self) -> &mut [ShaderParam] { &mut self.params }
 }
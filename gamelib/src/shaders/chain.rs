@@ -0,0 +1,312 @@
+//! A chain of user supplied shader passes, e.g. scale -> CRT -> bloom.
+//!
+//! Each pass is a GLSL file with only the functions and `main` of a fragment
+//! shader, the declarations are added when it is compiled. Every pass gets:
+//!
+//! - `image`: the output of the previous pass, the first gets Doom's screen
+//! - `texCoord`: the texture coordinate
+//! - `inputSize`: the size of `image`
+//! - `outputSize`: the size of the pass output
+//! - `originalSize`: the size of Doom's screen
+//! - `frameCount`: frames drawn so far
+//!
+//! Tunable float uniforms are declared in the file using the RetroArch style
+//! `#pragma parameter <name> "<description>" <default> <min> <max> <step>`,
+//! where only the name and default are used. These are then set from the
+//! defaults file as `shader_param_<name>`.
+//!
+//! Passes other than the last draw in to a texture the size of the window
+//! view, the last draws to the screen.
+
+use std::{fs::read_to_string, num::NonZeroU32, ops::Range};
+
+use golem::Dimension::*;
+use golem::*;
+use sdl2::rect::Rect;
+
+use super::{Renderer, ShaderParam, GL_QUAD, GL_QUAD_INDICES};
+
+/// The uniforms every pass has, params are added to these
+const PASS_UNIFORMS: [(&str, UniformType); 6] = [
+    ("image", UniformType::Sampler2D),
+    ("inputSize", UniformType::Vector(NumberType::Float, D2)),
+    ("outputSize", UniformType::Vector(NumberType::Float, D2)),
+    ("originalSize", UniformType::Vector(NumberType::Float, D2)),
+    ("frameCount", UniformType::Scalar(NumberType::Float)),
+    ("flipY", UniformType::Scalar(NumberType::Float)),
+];
+
+/// Passes drawing in to a texture are flipped so that every pass reads its
+/// input the same way up as Doom's screen
+const PASS_VERTEX: &str = r#"
+void main() {
+    gl_Position = vec4(position.x, position.y * flipY, 0.0, 1.0);
+    texCoord = vert_uv;
+}"#;
+
+struct ShaderPass {
+    shader: ShaderProgram,
+    /// The params of this pass in the chain's params
+    params: Range<usize>,
+}
+
+impl ShaderPass {
+    /// Load the pass, adding its params to `all_params`
+    fn load(
+        ctx: &Context,
+        path: &str,
+        all_params: &mut Vec<ShaderParam>,
+    ) -> Result<Self, String> {
+        let source = read_to_string(path)
+            .map_err(|e| format!("Could not read shader {}: {}", path, e))?;
+
+        let mut params = Vec::new();
+        let mut fragment = String::with_capacity(source.len());
+        for line in source.lines() {
+            if let Some(param) = line.trim().strip_prefix("#pragma parameter") {
+                params.push(parse_param(param).ok_or_else(|| {
+                    format!("Invalid parameter in {}: {}", path, line)
+                })?);
+            } else {
+                fragment.push_str(line);
+                fragment.push('\n');
+            }
+        }
+
+        // Golem requires static names. Passes are loaded once at start so
+        // the few leaked here are not a concern.
+        let mut uniforms: Vec<Uniform> = PASS_UNIFORMS
+            .iter()
+            .map(|(name, kind)| Uniform::new(name, kind.clone()))
+            .collect();
+        for param in &params {
+            uniforms.push(Uniform::new(
+                param.name,
+                UniformType::Scalar(NumberType::Float),
+            ));
+        }
+
+        let shader = ShaderProgram::new(
+            ctx,
+            ShaderDescription {
+                vertex_input:    &[
+                    Attribute::new("position", AttributeType::Vector(D2)),
+                    Attribute::new("vert_uv", AttributeType::Vector(D2)),
+                ],
+                fragment_input:  &[Attribute::new(
+                    "texCoord",
+                    AttributeType::Vector(D2),
+                )],
+                uniforms:        &uniforms,
+                vertex_shader:   PASS_VERTEX,
+                fragment_shader: &fragment,
+            },
+        )
+        .map_err(|e| format!("Could not compile shader {}: {}", path, e))?;
+
+        let start = all_params.len();
+        all_params.extend(params);
+        Ok(Self {
+            shader,
+            params: start..all_params.len(),
+        })
+    }
+}
+
+/// Parse the part of `#pragma parameter` after the pragma
+fn parse_param(line: &str) -> Option<ShaderParam> {
+    let mut split = line.trim().splitn(2, char::is_whitespace);
+    let name = split.next()?;
+    // Skip the quoted description
+    let rest = split.next()?.trim();
+    let rest = rest.strip_prefix('"')?;
+    let rest = &rest[rest.find('"')? + 1..];
+    let value = rest.split_whitespace().next()?.parse().ok()?;
+
+    let name: &'static str = Box::leak(name.to_owned().into_boxed_str());
+    Some(ShaderParam::new(name, value))
+}
+
+/// The textures the passes before the last draw in to
+fn make_surfaces(
+    ctx: &Context,
+    count: usize,
+    width: u32,
+    height: u32,
+) -> Result<Vec<golem::Surface>, String> {
+    let mut surfaces = Vec::with_capacity(count);
+    for _ in 0..count {
+        let mut texture = Texture::new(ctx).map_err(|e| e.to_string())?;
+        texture.set_image(None, width, height, ColorFormat::RGBA);
+        texture
+            .set_minification(TextureFilter::Linear)
+            .map_err(|e| e.to_string())?;
+        texture
+            .set_magnification(TextureFilter::Linear)
+            .map_err(|e| e.to_string())?;
+        surfaces.push(
+            golem::Surface::new(ctx, texture).map_err(|e| e.to_string())?,
+        );
+    }
+    Ok(surfaces)
+}
+
+pub(crate) struct Chain<'c> {
+    ctx:         &'c Context,
+    indices:     [u32; 6],
+    passes:      Vec<ShaderPass>,
+    params:      Vec<ShaderParam>,
+    /// Where each pass but the last draws to
+    surfaces:    Vec<golem::Surface>,
+    /// The window view, x, y, width, height
+    viewport:    (u32, u32, u32, u32),
+    texture:     Texture,
+    vb:          VertexBuffer,
+    eb:          ElementBuffer,
+    frame_count: u32,
+}
+
+impl<'c> Chain<'c> {
+    /// Load and compile each of the GLSL files in `paths` as a pass, in order
+    pub fn new(
+        ctx: &'c Context,
+        paths: &[&str],
+        viewport: (u32, u32, u32, u32),
+    ) -> Result<Self, String> {
+        if paths.is_empty() {
+            return Err("No shader passes given".to_owned());
+        }
+
+        let mut params = Vec::new();
+        let passes = paths
+            .iter()
+            .map(|path| ShaderPass::load(ctx, path, &mut params))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let surfaces =
+            make_surfaces(ctx, passes.len() - 1, viewport.2, viewport.3)?;
+
+        let mut vb = VertexBuffer::new(ctx).unwrap();
+        let mut eb = ElementBuffer::new(ctx).unwrap();
+        vb.set_data(&GL_QUAD);
+        eb.set_data(&GL_QUAD_INDICES);
+
+        Ok(Self {
+            ctx,
+            indices: GL_QUAD_INDICES,
+            passes,
+            params,
+            surfaces,
+            viewport,
+            texture: Texture::new(ctx).unwrap(),
+            vb,
+            eb,
+            frame_count: 0,
+        })
+    }
+}
+
+impl<'c> Renderer for Chain<'c> {
+    fn clear(&self) {
+        self.ctx.set_clear_color(0.0, 0.0, 0.0, 1.0);
+        self.ctx.clear();
+    }
+
+    fn set_tex_filter(&self) -> Result<(), GolemError> {
+        self.texture.set_minification(TextureFilter::Nearest)?;
+        self.texture.set_magnification(TextureFilter::Linear)
+    }
+
+    fn set_image_data(&mut self, input: &[u8], input_size: (u32, u32)) {
+        self.texture.set_image(
+            Some(input),
+            input_size.0,
+            input_size.1,
+            ColorFormat::RGBA,
+        );
+    }
+
+    fn draw(&mut self) -> Result<(), GolemError> {
+        let bind_point = NonZeroU32::new(1).unwrap();
+        let (x, y, width, height) = self.viewport;
+        let original_size =
+            [self.texture.width() as f32, self.texture.height() as f32];
+        let last = self.passes.len() - 1;
+
+        for (i, pass) in self.passes.iter_mut().enumerate() {
+            let input = if i == 0 {
+                &self.texture
+            } else {
+                self.surfaces[i - 1].borrow_texture().unwrap()
+            };
+            let input_size = [input.width() as f32, input.height() as f32];
+            input.set_active(bind_point);
+
+            let flip = if i == last {
+                golem::Surface::unbind(self.ctx);
+                self.ctx.set_viewport(x, y, width, height);
+                1.0
+            } else {
+                self.surfaces[i].bind();
+                self.ctx.set_viewport(0, 0, width, height);
+                -1.0
+            };
+
+            pass.shader.bind();
+            pass.shader.prepare_draw(&self.vb, &self.eb)?;
+            pass.shader.set_uniform("image", UniformValue::Int(1))?;
+            pass.shader
+                .set_uniform("inputSize", UniformValue::Vector2(input_size))?;
+            pass.shader.set_uniform(
+                "outputSize",
+                UniformValue::Vector2([width as f32, height as f32]),
+            )?;
+            pass.shader.set_uniform(
+                "originalSize",
+                UniformValue::Vector2(original_size),
+            )?;
+            pass.shader.set_uniform(
+                "frameCount",
+                UniformValue::Float(self.frame_count as f32),
+            )?;
+            pass.shader
+                .set_uniform("flipY", UniformValue::Float(flip))?;
+            for param in &self.params[pass.params.clone()] {
+                pass.shader.set_uniform(
+                    param.name,
+                    UniformValue::Float(param.value),
+                )?;
+            }
+
+            unsafe {
+                pass.shader.draw_prepared(
+                    0..self.indices.len(),
+                    GeometryMode::Triangles,
+                );
+            }
+        }
+
+        self.frame_count = self.frame_count.wrapping_add(1);
+        Ok(())
+    }
+
+    fn resize(&mut self, viewport: Rect) {
+        self.viewport = (
+            viewport.x().max(0) as u32,
+            viewport.y().max(0) as u32,
+            viewport.width(),
+            viewport.height(),
+        );
+        match make_surfaces(
+            self.ctx,
+            self.surfaces.len(),
+            viewport.width(),
+            viewport.height(),
+        ) {
+            Ok(surfaces) => self.surfaces = surfaces,
+            Err(e) => println!("Shader chain not resized: {}", e),
+        }
+    }
+
+    fn params_mut(&mut self) -> &mut [ShaderParam] { &mut self.params }
+}
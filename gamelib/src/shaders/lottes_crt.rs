@@ -4,7 +4,7 @@ use glam::{Mat4, Vec3};
 use golem::Dimension::*;
 use golem::*;
 
-use super::{Renderer, GL_QUAD, GL_QUAD_INDICES};
+use super::{Renderer, ShaderParam, GL_QUAD, GL_QUAD_INDICES};
 
 pub(crate) struct LottesCRT<'c> {
     ctx:        &'c Context,
@@ -16,6 +16,7 @@ pub(crate) struct LottesCRT<'c> {
     texture:    Texture,
     vb:         VertexBuffer,
     eb:         ElementBuffer,
+    params:     Vec<ShaderParam>,
 }
 
 impl<'c> LottesCRT<'c> {
@@ -256,6 +257,27 @@ void main(void)
             texture: Texture::new(ctx).unwrap(),
             vb,
             eb,
+            params: vec![
+                // MASK
+                // Scanline visibility, -3.0 to -4.0
+                ShaderParam::new("hardScan", -2.78),
+                // CRT focus? -1 to -10
+                ShaderParam::new("hardPix", -6.14),
+                // brightMult needs to be increased as this decreases
+                // 0.01 to 0.9
+                ShaderParam::new("maskDark", 0.22),
+                ShaderParam::new("maskLight", 0.28),
+                // GAMMA
+                ShaderParam::new("blackClip", 0.01),
+                ShaderParam::new("brightMult", 4.1),
+                // SHAPE
+                // Curvature, 0.1 to 0.3
+                ShaderParam::new("distortion", 0.1),
+                // 0.01 to 0.05
+                ShaderParam::new("cornersize", 0.02),
+                // Edge hardness, 70.0 to 170.0
+                ShaderParam::new("cornersmooth", 170.0),
+            ],
         }
     }
 }
@@ -316,31 +338,10 @@ impl<'c> Renderer for LottesCRT<'c> {
                 self.texture.height() as f32,
             ]),
         )?;
-        // MASK
-        // Scanline visibility
-        self.crt_shader
-            .set_uniform("hardScan", UniformValue::Float(-2.78))?; // -3.0 to -4.0
-                                                                   // CRT focus?
-        self.crt_shader
-            .set_uniform("hardPix", UniformValue::Float(-6.14))?; // -1 to -10
-                                                                  // brightMult needs to be increased as this decreases
-        self.crt_shader
-            .set_uniform("maskDark", UniformValue::Float(0.22))?; // 0.01 to 0.9
-        self.crt_shader
-            .set_uniform("maskLight", UniformValue::Float(0.28))?;
-        // GAMMA
-        self.crt_shader
-            .set_uniform("blackClip", UniformValue::Float(0.01))?;
-        self.crt_shader
-            .set_uniform("brightMult", UniformValue::Float(4.1))?;
-        // SHAPE
-        self.crt_shader
-            .set_uniform("distortion", UniformValue::Float(0.1))?; // 0.1 to 0.3
-        self.crt_shader
-            .set_uniform("cornersize", UniformValue::Float(0.02))?; // 0.01 to 0.05
-                                                                    // Edge hardness
-        self.crt_shader
-            .set_uniform("cornersmooth", UniformValue::Float(170.0))?; // 70.0 to 170.0
+        for param in &self.params {
+            self.crt_shader
+                .set_uniform(param.name, UniformValue::Float(param.value))?;
+        }
 
         let bind_point = std::num::NonZeroU32::new(1).unwrap();
         self.texture.set_active(bind_point);
@@ -351,4 +352,6 @@ impl<'c> Renderer for LottesCRT<'c> {
         }
         Ok(())
     }
+
+    fn params_mut(&mut self) -> &mut [ShaderParam] { &mut self.params }
 }
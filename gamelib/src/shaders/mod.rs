@@ -1,7 +1,13 @@
+use std::ffi::c_void;
+
 use golem::GolemError;
+use sdl2::{rect::Rect, video::Window};
+
+use crate::m_misc::Defaults;
 
 pub(crate) mod basic;
 pub(crate) mod cgwg_crt;
+pub(crate) mod chain;
 pub(crate) mod lottes_crt;
 
 const GL_QUAD: [f32; 16] = [
@@ -14,6 +20,18 @@ const GL_QUAD: [f32; 16] = [
 
 const GL_QUAD_INDICES: [u32; 6] = [0, 1, 2, 2, 3, 0];
 
+/// A float uniform of a shader which can be tuned from the defaults file
+/// as `shader_param_<name>`
+#[derive(Debug, Clone)]
+pub(crate) struct ShaderParam {
+    pub name:  &'static str,
+    pub value: f32,
+}
+
+impl ShaderParam {
+    pub fn new(name: &'static str, value: f32) -> Self { Self { name, value } }
+}
+
 pub(crate) trait Renderer {
     fn clear(&self);
 
@@ -23,4 +41,94 @@ pub(crate) trait Renderer {
     fn set_image_data(&mut self, input: &[u8], input_size: (u32, u32));
 
     fn draw(&mut self) -> Result<(), GolemError>;
+
+    /// The part of the window drawn to has changed
+    fn resize(&mut self, _viewport: Rect) {}
+
+    /// The tunable uniforms of the shader, these are set on each draw
+    fn params_mut(&mut self) -> &mut [ShaderParam];
+
+    /// Set any params given in the defaults file
+    fn load_params(&mut self, defaults: &Defaults) {
+        for param in self.params_mut() {
+            if let Some(v) =
+                defaults.get(&format!("shader_param_{}", param.name))
+            {
+                param.value = v;
+            }
+        }
+    }
+}
+
+/// The renderers which can be switched between while running, and the one
+/// in use
+pub(crate) struct Renderers<'c> {
+    renderers: Vec<Box<dyn Renderer + 'c>>,
+    current:   usize,
+}
+
+impl<'c> Renderers<'c> {
+    /// `current` is the index of the renderer to start with
+    pub fn new(renderers: Vec<Box<dyn Renderer + 'c>>, current: usize) -> Self {
+        Self { renderers, current }
+    }
+
+    pub fn current(&mut self) -> &mut dyn Renderer {
+        self.renderers[self.current].as_mut()
+    }
+
+    /// The part of the window drawn to has changed
+    pub fn resize(&mut self, viewport: Rect) {
+        for renderer in self.renderers.iter_mut() {
+            renderer.resize(viewport);
+        }
+    }
+
+    /// Switch to the next renderer, wrapping around
+    pub fn cycle(&mut self) {
+        self.current = (self.current + 1) % self.renderers.len();
+    }
+}
+
+/// Read `rect` of the window's back buffer, after the shader has drawn, as
+/// RGBA32 with the top row first. Golem has no way to read the screen so
+/// `glReadPixels` is loaded directly.
+pub(crate) fn read_window_pixels(
+    window: &Window,
+    rect: Rect,
+) -> Result<Vec<u8>, String> {
+    type ReadPixels =
+        extern "system" fn(i32, i32, i32, i32, u32, u32, *mut c_void);
+    const GL_RGBA: u32 = 0x1908;
+    const GL_UNSIGNED_BYTE: u32 = 0x1401;
+
+    let ptr = window.subsystem().gl_get_proc_address("glReadPixels");
+    if ptr.is_null() {
+        return Err("glReadPixels not available".to_owned());
+    }
+
+    let width = rect.width() as usize;
+    let height = rect.height() as usize;
+    // GL counts rows from the bottom
+    let y = window.drawable_size().1 as i32 - rect.bottom();
+    let mut buf = vec![0u8; width * height * 4];
+    unsafe {
+        let read_pixels: ReadPixels = std::mem::transmute(ptr);
+        read_pixels(
+            rect.x(),
+            y,
+            width as i32,
+            height as i32,
+            GL_RGBA,
+            GL_UNSIGNED_BYTE,
+            buf.as_mut_ptr() as *mut c_void,
+        );
+    }
+
+    Ok(buf
+        .chunks_exact(width * 4)
+        .rev()
+        .flatten()
+        .copied()
+        .collect())
 }
@@ -75,6 +75,8 @@ pub(crate) struct TicCmd {
     pub sidemove:    i8,
     /// <<16 for angle delta
     pub angleturn:   i16,
+    /// Free-look pitch delta, in the same units as `angleturn`
+    pub lookpitch:   i16,
     /// checks for net game
     pub consistancy: i16,
     pub chatchar:    u8,
@@ -87,6 +89,7 @@ impl TicCmd {
             forwardmove: 0,
             sidemove:    0,
             angleturn:   0,
+            lookpitch:   0,
             consistancy: 0,
             chatchar:    0,
             buttons:     0,
@@ -0,0 +1,208 @@
+//! Drawing of WAD pictures (patches and flats) directly in to the screen
+//! buffer. The buffer is expected to be `RGBA32`.
+
+use sdl2::{rect::Rect, render::Canvas, surface::Surface};
+use wad::lumps::{WadColour, WadFlat, WadPalette, WadPatch};
+
+/// V_DrawPatch
+///
+/// The patch offsets are applied, and anything that lands off screen
+/// is clipped rather than being an error like in vanilla.
+pub(crate) fn draw_patch(
+    patch: &WadPatch,
+    x: i32,
+    y: i32,
+    palette: &WadPalette,
+    canvas: &mut Canvas<Surface>,
+) {
+    let x = x - patch.left_offset as i32;
+    let y = y - patch.top_offset as i32;
+
+    let surface = canvas.surface_mut();
+    let width = surface.width() as i32;
+    let height = surface.height() as i32;
+    let pitch = surface.pitch() as usize;
+
+    surface.with_lock_mut(|buf| {
+        for (c, column) in patch.columns.iter().enumerate() {
+            let sx = x + c as i32;
+            if sx < 0 || sx >= width {
+                continue;
+            }
+            for post in column {
+                for (p, index) in post.pixels.iter().enumerate() {
+                    let sy = y + post.top_delta + p as i32;
+                    if sy < 0 || sy >= height {
+                        continue;
+                    }
+                    let i = sy as usize * pitch + sx as usize * 4;
+                    put_pixel(&mut buf[i..i + 4], *index, palette);
+                }
+            }
+        }
+    });
+}
+
+/// Draw column `column` of the patch at screen column `x`. The patch offsets
+/// are not used, as in F_DrawPatchCol.
+pub(crate) fn draw_patch_column(
+    patch: &WadPatch,
+    column: usize,
+    x: i32,
+    y: i32,
+    palette: &WadPalette,
+    canvas: &mut Canvas<Surface>,
+) {
+    let surface = canvas.surface_mut();
+    let width = surface.width() as i32;
+    let height = surface.height() as i32;
+    let pitch = surface.pitch() as usize;
+    if x < 0 || x >= width {
+        return;
+    }
+
+    if let Some(posts) = patch.columns.get(column) {
+        surface.with_lock_mut(|buf| {
+            for post in posts {
+                for (p, index) in post.pixels.iter().enumerate() {
+                    let sy = y + post.top_delta + p as i32;
+                    if sy < 0 || sy >= height {
+                        continue;
+                    }
+                    let i = sy as usize * pitch + x as usize * 4;
+                    put_pixel(&mut buf[i..i + 4], *index, palette);
+                }
+            }
+        });
+    }
+}
+
+/// Tile a flat over the area of `rect`, with the flat aligned to the screen
+/// origin the same as vanilla does for the view border
+pub(crate) fn fill_flat(
+    flat: &WadFlat,
+    rect: Rect,
+    palette: &WadPalette,
+    canvas: &mut Canvas<Surface>,
+) {
+    let surface = canvas.surface_mut();
+    let rect = match rect.intersection(surface.rect()) {
+        Some(r) => r,
+        None => return,
+    };
+    let pitch = surface.pitch() as usize;
+
+    surface.with_lock_mut(|buf| {
+        for y in rect.top()..rect.bottom() {
+            for x in rect.left()..rect.right() {
+                let index = flat.data[((y & 63) * 64 + (x & 63)) as usize];
+                let i = y as usize * pitch + x as usize * 4;
+                put_pixel(&mut buf[i..i + 4], index, palette);
+            }
+        }
+    });
+}
+
+fn put_pixel(pixel: &mut [u8], index: u8, palette: &WadPalette) {
+    let colour = palette.0[index as usize];
+    pixel[0] = colour.r;
+    pixel[1] = colour.g;
+    pixel[2] = colour.b;
+    pixel[3] = 255;
+}
+
+/// Maps each red, green and blue value of the normal palette to its value in
+/// one of the other PLAYPAL palettes.
+///
+/// The view is drawn in full colour so the palette can't be swapped like
+/// vanilla's I_SetPalette. The other palettes are the normal palette blended
+/// toward a tint, so that blend is recovered per channel with a least squares
+/// fit over all 256 colours and applied to the finished frame instead.
+pub(crate) struct PaletteShift([[u8; 256]; 3]);
+
+impl PaletteShift {
+    pub(crate) fn new(base: &WadPalette, palette: &WadPalette) -> Self {
+        let mut lookup = [[0; 256]; 3];
+        for (c, channel) in lookup.iter_mut().enumerate() {
+            let get = |colour: &WadColour| match c {
+                0 => colour.r,
+                1 => colour.g,
+                _ => colour.b,
+            } as f32;
+            let pairs: Vec<(f32, f32)> = base
+                .0
+                .iter()
+                .zip(palette.0.iter())
+                .map(|(from, to)| (get(from), get(to)))
+                .collect();
+
+            let n = pairs.len() as f32;
+            let mean_x = pairs.iter().map(|p| p.0).sum::<f32>() / n;
+            let mean_y = pairs.iter().map(|p| p.1).sum::<f32>() / n;
+            let mut covariance = 0.0;
+            let mut variance = 0.0;
+            for (x, y) in &pairs {
+                covariance += (x - mean_x) * (y - mean_y);
+                variance += (x - mean_x) * (x - mean_x);
+            }
+            let scale = if variance > 0.0 {
+                covariance / variance
+            } else {
+                1.0
+            };
+            let offset = mean_y - scale * mean_x;
+
+            for (v, out) in channel.iter_mut().enumerate() {
+                *out = (v as f32 * scale + offset).round().max(0.0).min(255.0)
+                    as u8;
+            }
+        }
+        Self(lookup)
+    }
+
+    /// Shift the colours of everything drawn to `canvas`
+    pub(crate) fn apply(&self, canvas: &mut Canvas<Surface>) {
+        let surface = canvas.surface_mut();
+        let width = surface.width() as usize;
+        let pitch = surface.pitch() as usize;
+        surface.with_lock_mut(|buf| {
+            for row in buf.chunks_exact_mut(pitch) {
+                for pixel in row[..width * 4].chunks_exact_mut(4) {
+                    pixel[0] = self.0[0][pixel[0] as usize];
+                    pixel[1] = self.0[1][pixel[1] as usize];
+                    pixel[2] = self.0[2][pixel[2] as usize];
+                }
+            }
+        });
+    }
+}
+
+/// How many gamma correction levels there are, 0 is off
+pub(crate) const NUM_GAMMA: usize = 5;
+
+/// gammatable
+///
+/// Vanilla has these as a table of numbers, they are the curve
+/// `255 * (i / 255)^(1 - level / 8)` rounded, so are made here instead.
+pub(crate) fn gamma_tables() -> [[u8; 256]; NUM_GAMMA] {
+    let mut tables = [[0; 256]; NUM_GAMMA];
+    for (level, table) in tables.iter_mut().enumerate() {
+        let exponent = 1.0 - level as f32 / 8.0;
+        for (i, v) in table.iter_mut().enumerate() {
+            *v = (255.0 * (i as f32 / 255.0).powf(exponent)).round() as u8;
+        }
+    }
+    tables
+}
+
+/// Copy the RGBA32 pixels of `from` in to `to` with the gamma `table`
+/// applied to the colour
+pub(crate) fn apply_gamma(table: &[u8; 256], from: &[u8], to: &mut Vec<u8>) {
+    to.resize(from.len(), 0);
+    for (out, pixel) in to.chunks_exact_mut(4).zip(from.chunks_exact(4)) {
+        out[0] = table[pixel[0] as usize];
+        out[1] = table[pixel[1] as usize];
+        out[2] = table[pixel[2] as usize];
+        out[3] = pixel[3];
+    }
+}
@@ -0,0 +1,888 @@
+//! Intermission screens shown between levels: the stats tally, then the
+//! episode map with the "you are here" pointer and the "entering" screen.
+//!
+//! TODO: the netgame and deathmatch stats screens. Only the single player
+//!  stats are done, which are used for all games for now.
+
+use sdl2::{render::Canvas, surface::Surface};
+use wad::{
+    lumps::{WadPalette, WadPatch},
+    WadData,
+};
+
+use crate::{
+    doom_def::{GameMode, TICRATE},
+    p_local::m_random,
+    player::{Player, WBStartStruct},
+    tic_cmd::TIC_CMD_BUTTONS,
+    v_video::draw_patch,
+};
+
+/// GLOBAL LOCATIONS
+const WI_TITLEY: i32 = 2;
+
+/// SINGPLE-PLAYER STUFF
+const SP_STATSX: i32 = 50;
+const SP_STATSY: i32 = 50;
+const SP_TIMEX: i32 = 16;
+const SP_TIMEY: i32 = 200 - 32;
+
+/// in seconds
+const SHOWNEXTLOCDELAY: i32 = 4;
+
+const NUMCMAPS: usize = 32;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum WiState {
+    NoState,
+    StatCount,
+    ShowNextLoc,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum AnimKind {
+    Always,
+    Random,
+    Level,
+}
+
+/// Static data for an animation on the episode map
+struct AnimInfo {
+    kind:   AnimKind,
+    /// period in tics between animations
+    period: i32,
+    /// number of animation frames
+    nanims: usize,
+    /// location of animation
+    loc:    (i32, i32),
+    /// ALWAYS: n/a, RANDOM: period deviation (<256), LEVEL: level
+    data1:  i32,
+}
+
+const fn anim(
+    kind: AnimKind,
+    period: i32,
+    nanims: usize,
+    loc: (i32, i32),
+    data1: i32,
+) -> AnimInfo {
+    AnimInfo {
+        kind,
+        period,
+        nanims,
+        loc,
+        data1,
+    }
+}
+
+const EPSD0_ANIMINFO: [AnimInfo; 10] = [
+    anim(AnimKind::Always, TICRATE / 3, 3, (224, 104), 0),
+    anim(AnimKind::Always, TICRATE / 3, 3, (184, 160), 0),
+    anim(AnimKind::Always, TICRATE / 3, 3, (112, 136), 0),
+    anim(AnimKind::Always, TICRATE / 3, 3, (72, 112), 0),
+    anim(AnimKind::Always, TICRATE / 3, 3, (88, 96), 0),
+    anim(AnimKind::Always, TICRATE / 3, 3, (64, 48), 0),
+    anim(AnimKind::Always, TICRATE / 3, 3, (192, 40), 0),
+    anim(AnimKind::Always, TICRATE / 3, 3, (136, 16), 0),
+    anim(AnimKind::Always, TICRATE / 3, 3, (80, 16), 0),
+    anim(AnimKind::Always, TICRATE / 3, 3, (64, 24), 0),
+];
+
+const EPSD1_ANIMINFO: [AnimInfo; 9] = [
+    anim(AnimKind::Level, TICRATE / 3, 1, (128, 136), 1),
+    anim(AnimKind::Level, TICRATE / 3, 1, (128, 136), 2),
+    anim(AnimKind::Level, TICRATE / 3, 1, (128, 136), 3),
+    anim(AnimKind::Level, TICRATE / 3, 1, (128, 136), 4),
+    anim(AnimKind::Level, TICRATE / 3, 1, (128, 136), 5),
+    anim(AnimKind::Level, TICRATE / 3, 1, (128, 136), 6),
+    anim(AnimKind::Level, TICRATE / 3, 1, (128, 136), 7),
+    anim(AnimKind::Level, TICRATE / 3, 3, (192, 144), 8),
+    anim(AnimKind::Level, TICRATE / 3, 1, (128, 136), 8),
+];
+
+const EPSD2_ANIMINFO: [AnimInfo; 6] = [
+    anim(AnimKind::Always, TICRATE / 3, 3, (104, 168), 0),
+    anim(AnimKind::Always, TICRATE / 3, 3, (40, 136), 0),
+    anim(AnimKind::Always, TICRATE / 3, 3, (160, 96), 0),
+    anim(AnimKind::Always, TICRATE / 3, 3, (104, 80), 0),
+    anim(AnimKind::Always, TICRATE / 3, 3, (120, 32), 0),
+    anim(AnimKind::Always, TICRATE / 4, 3, (40, 0), 0),
+];
+
+/// Location of each level on the episode maps
+const LNODES: [[(i32, i32); 9]; 3] = [
+    // Episode 0 World Map
+    [
+        (185, 164),
+        (148, 143),
+        (69, 122),
+        (209, 102),
+        (116, 89),
+        (166, 55),
+        (71, 56),
+        (135, 29),
+        (71, 24),
+    ],
+    // Episode 1 World Map
+    [
+        (254, 25),
+        (97, 50),
+        (188, 64),
+        (128, 78),
+        (214, 92),
+        (133, 130),
+        (208, 136),
+        (148, 140),
+        (235, 158),
+    ],
+    // Episode 2 World Map
+    [
+        (156, 168),
+        (48, 154),
+        (174, 95),
+        (265, 75),
+        (130, 48),
+        (279, 23),
+        (198, 48),
+        (140, 25),
+        (281, 136),
+    ],
+];
+
+/// An animation on the episode map, with its running state
+struct Anim {
+    info:    &'static AnimInfo,
+    /// actual graphics for frames of animations
+    patches: Vec<WadPatch>,
+    /// next value of bcnt (used in conjunction with period)
+    nexttic: i32,
+    /// next frame number to animate
+    ctr:     i32,
+}
+
+/// All the graphics used by the intermission, loaded by `WI_loadData`
+struct WiPatches {
+    background: WadPatch,
+    /// You Are Here graphic
+    yah:        [Option<WadPatch>; 2],
+    /// splat
+    splat:      Option<WadPatch>,
+    /// %, : graphics
+    percent:    WadPatch,
+    colon:      WadPatch,
+    /// 0-9 graphic
+    num:        Vec<WadPatch>,
+    /// minus sign
+    wiminus:    WadPatch,
+    /// "Finished!" graphics
+    finished:   WadPatch,
+    /// "Entering" graphic
+    entering:   WadPatch,
+    /// "secret"
+    sp_secret:  WadPatch,
+    /// "Kills", "Scrt", "Items", "Frags"
+    kills:      WadPatch,
+    items:      WadPatch,
+    /// Time sucks.
+    time:       WadPatch,
+    par:        WadPatch,
+    sucks:      WadPatch,
+    /// Name graphics of each level (centered)
+    lnames:     Vec<WadPatch>,
+}
+
+/// The intermission state, created by `WI_Start` and dropped at `WI_End`
+pub(crate) struct Intermission {
+    state:           WiState,
+    /// contains information passed into intermission
+    wbs:             WBStartStruct,
+    /// wbs->pnum
+    me:              usize,
+    /// used to accelerate or skip a stage
+    acceleratestage: bool,
+    /// used for general timing
+    cnt:             i32,
+    /// used for timing of background animation
+    bcnt:            i32,
+    sp_state:        i32,
+    cnt_kills:       i32,
+    cnt_items:       i32,
+    cnt_secret:      i32,
+    cnt_time:        i32,
+    cnt_par:         i32,
+    cnt_pause:       i32,
+    /// Flashing "you are here" pointer is on
+    snl_pointeron:   bool,
+    game_mode:       GameMode,
+    anims:           Vec<Anim>,
+    patches:         WiPatches,
+    palette:         WadPalette,
+}
+
+impl Intermission {
+    /// WI_Start
+    pub(crate) fn start(
+        mut wbs: WBStartStruct,
+        game_mode: GameMode,
+        wad: &WadData,
+        palette: &WadPalette,
+    ) -> Self {
+        // WI_initVariables
+        if wbs.maxkills == 0 {
+            wbs.maxkills = 1;
+        }
+        if wbs.maxitems == 0 {
+            wbs.maxitems = 1;
+        }
+        if wbs.maxsecret == 0 {
+            wbs.maxsecret = 1;
+        }
+        if game_mode != GameMode::Retail && wbs.epsd > 2 {
+            wbs.epsd -= 3;
+        }
+
+        let (patches, anims) = Self::load_data(&wbs, game_mode, wad);
+
+        let mut wi = Self {
+            state: WiState::StatCount,
+            me: wbs.pnum as usize,
+            wbs,
+            acceleratestage: false,
+            cnt: 0,
+            bcnt: 0,
+            sp_state: 1,
+            cnt_kills: -1,
+            cnt_items: -1,
+            cnt_secret: -1,
+            cnt_time: -1,
+            cnt_par: -1,
+            cnt_pause: TICRATE,
+            snl_pointeron: false,
+            game_mode,
+            anims,
+            patches,
+            palette: palette.clone(),
+        };
+
+        // TODO: WI_initDeathmatchStats, WI_initNetgameStats
+        wi.init_stats();
+        wi
+    }
+
+    /// WI_loadData
+    fn load_data(
+        wbs: &WBStartStruct,
+        game_mode: GameMode,
+        wad: &WadData,
+    ) -> (WiPatches, Vec<Anim>) {
+        let background = if game_mode == GameMode::Commercial
+            || (game_mode == GameMode::Retail && wbs.epsd == 3)
+        {
+            "INTERPIC".to_owned()
+        } else {
+            format!("WIMAP{}", wbs.epsd)
+        };
+
+        let lnames = if game_mode == GameMode::Commercial {
+            (0..NUMCMAPS)
+                .map(|i| format!("CWILV{:02}", i))
+                .filter(|n| wad.lump_exists(n))
+                .map(|n| wad.get_patch(&n))
+                .collect()
+        } else {
+            (0..9)
+                .map(|i| wad.get_patch(&format!("WILV{}{}", wbs.epsd, i)))
+                .collect()
+        };
+
+        let mut anims: Vec<Anim> = Vec::new();
+        let mut yah = [None, None];
+        let mut splat = None;
+        if game_mode != GameMode::Commercial {
+            // you are here
+            yah =
+                [Some(wad.get_patch("WIURH0")), Some(wad.get_patch("WIURH1"))];
+            // splat
+            splat = Some(wad.get_patch("WISPLAT"));
+
+            let infos: &'static [AnimInfo] = match wbs.epsd {
+                0 => &EPSD0_ANIMINFO,
+                1 => &EPSD1_ANIMINFO,
+                2 => &EPSD2_ANIMINFO,
+                _ => &[],
+            };
+            for (j, info) in infos.iter().enumerate() {
+                let patches = if wbs.epsd != 1 || j != 8 {
+                    (0..info.nanims)
+                        .map(|i| {
+                            wad.get_patch(&format!(
+                                "WIA{}{:02}{:02}",
+                                wbs.epsd, j, i
+                            ))
+                        })
+                        .collect()
+                } else {
+                    // HACK ALERT!
+                    anims[4].patches.clone()
+                };
+                anims.push(Anim {
+                    info,
+                    patches,
+                    nexttic: 0,
+                    ctr: -1,
+                });
+            }
+        }
+
+        let patches = WiPatches {
+            background: wad.get_patch(&background),
+            yah,
+            splat,
+            percent: wad.get_patch("WIPCNT"),
+            colon: wad.get_patch("WICOLON"),
+            num: (0..10)
+                .map(|i| wad.get_patch(&format!("WINUM{}", i)))
+                .collect(),
+            wiminus: wad.get_patch("WIMINUS"),
+            finished: wad.get_patch("WIF"),
+            entering: wad.get_patch("WIENTER"),
+            sp_secret: wad.get_patch("WISCRT2"),
+            kills: wad.get_patch("WIOSTK"),
+            items: wad.get_patch("WIOSTI"),
+            time: wad.get_patch("WITIME"),
+            par: wad.get_patch("WIPAR"),
+            sucks: wad.get_patch("WISUCKS"),
+            lnames,
+        };
+
+        (patches, anims)
+    }
+
+    /// WI_initAnimatedBack
+    fn init_animated_back(&mut self) {
+        for a in self.anims.iter_mut() {
+            // init variables
+            a.ctr = -1;
+
+            // specify the next time to draw it
+            match a.info.kind {
+                AnimKind::Always => {
+                    a.nexttic =
+                        self.bcnt + 1 + (m_random() as i32 % a.info.period);
+                }
+                AnimKind::Random => {
+                    a.nexttic = self.bcnt
+                        + 1
+                        + a.info.period
+                        + (m_random() as i32 % a.info.data1);
+                }
+                AnimKind::Level => a.nexttic = self.bcnt + 1,
+            }
+        }
+    }
+
+    /// WI_updateAnimatedBack
+    fn update_animated_back(&mut self) {
+        let bcnt = self.bcnt;
+        let stat_count = self.state == WiState::StatCount;
+        let next = self.wbs.next;
+
+        for (i, a) in self.anims.iter_mut().enumerate() {
+            if bcnt != a.nexttic {
+                continue;
+            }
+            match a.info.kind {
+                AnimKind::Always => {
+                    a.ctr += 1;
+                    if a.ctr as usize >= a.info.nanims {
+                        a.ctr = 0;
+                    }
+                    a.nexttic = bcnt + a.info.period;
+                }
+                AnimKind::Random => {
+                    a.ctr += 1;
+                    if a.ctr as usize == a.info.nanims {
+                        a.ctr = -1;
+                        a.nexttic = bcnt + (m_random() as i32 % a.info.data1);
+                    } else {
+                        a.nexttic = bcnt + a.info.period;
+                    }
+                }
+                AnimKind::Level => {
+                    // gawd-awful hack for level anims
+                    if !(stat_count && i == 7) && next == a.info.data1 {
+                        a.ctr += 1;
+                        if a.ctr as usize == a.info.nanims {
+                            a.ctr -= 1;
+                        }
+                        a.nexttic = bcnt + a.info.period;
+                    }
+                }
+            }
+        }
+    }
+
+    /// WI_initStats
+    fn init_stats(&mut self) {
+        self.state = WiState::StatCount;
+        self.acceleratestage = false;
+        self.sp_state = 1;
+        self.cnt_kills = -1;
+        self.cnt_items = -1;
+        self.cnt_secret = -1;
+        self.cnt_time = -1;
+        self.cnt_par = -1;
+        self.cnt_pause = TICRATE;
+        self.init_animated_back();
+    }
+
+    /// WI_initShowNextLoc
+    fn init_show_next_loc(&mut self) {
+        self.state = WiState::ShowNextLoc;
+        self.acceleratestage = false;
+        self.cnt = SHOWNEXTLOCDELAY * TICRATE;
+        self.init_animated_back();
+    }
+
+    /// WI_initNoState
+    fn init_no_state(&mut self) {
+        self.state = WiState::NoState;
+        self.acceleratestage = false;
+        self.cnt = 10;
+    }
+
+    /// WI_checkForAccelerate
+    /// Check for button presses to skip delays
+    fn check_for_accelerate(
+        &mut self,
+        players: &mut [Player],
+        player_in_game: &[bool],
+    ) {
+        for (i, player) in players.iter_mut().enumerate() {
+            if !player_in_game[i] {
+                continue;
+            }
+            if player.cmd.buttons & TIC_CMD_BUTTONS.bt_attack != 0 {
+                if !player.attackdown {
+                    self.acceleratestage = true;
+                }
+                player.attackdown = true;
+            } else {
+                player.attackdown = false;
+            }
+            if player.cmd.buttons & TIC_CMD_BUTTONS.bt_use != 0 {
+                if !player.usedown {
+                    self.acceleratestage = true;
+                }
+                player.usedown = true;
+            } else {
+                player.usedown = false;
+            }
+        }
+    }
+
+    /// WI_Ticker
+    ///
+    /// Returns true when the intermission has finished and `G_WorldDone`
+    /// should be called.
+    pub(crate) fn ticker(
+        &mut self,
+        players: &mut [Player],
+        player_in_game: &[bool],
+    ) -> bool {
+        // counter for general background animation
+        self.bcnt += 1;
+
+        // TODO: if bcnt == 1, intermission music
+        self.check_for_accelerate(players, player_in_game);
+
+        match self.state {
+            WiState::StatCount => self.update_stats(),
+            WiState::ShowNextLoc => self.update_show_next_loc(),
+            WiState::NoState => return self.update_no_state(),
+        }
+        false
+    }
+
+    /// WI_updateStats
+    fn update_stats(&mut self) {
+        self.update_animated_back();
+
+        let plyr = &self.wbs.plyr[self.me];
+        let kills = (plyr.skills * 100) / self.wbs.maxkills;
+        let items = (plyr.sitems * 100) / self.wbs.maxitems;
+        let secret = (plyr.ssecret * 100) / self.wbs.maxsecret;
+        let time = plyr.stime / TICRATE;
+        let par = self.wbs.partime / TICRATE;
+
+        if self.acceleratestage && self.sp_state != 10 {
+            self.acceleratestage = false;
+            self.cnt_kills = kills;
+            self.cnt_items = items;
+            self.cnt_secret = secret;
+            self.cnt_time = time;
+            self.cnt_par = par;
+            // TODO: S_StartSound(0, sfx_barexp);
+            self.sp_state = 10;
+        }
+
+        match self.sp_state {
+            2 => {
+                self.cnt_kills += 2;
+                // TODO: if (!(bcnt&3)) S_StartSound(0, sfx_pistol);
+                if self.cnt_kills >= kills {
+                    self.cnt_kills = kills;
+                    self.sp_state += 1;
+                }
+            }
+            4 => {
+                self.cnt_items += 2;
+                if self.cnt_items >= items {
+                    self.cnt_items = items;
+                    self.sp_state += 1;
+                }
+            }
+            6 => {
+                self.cnt_secret += 2;
+                if self.cnt_secret >= secret {
+                    self.cnt_secret = secret;
+                    self.sp_state += 1;
+                }
+            }
+            8 => {
+                self.cnt_time += 3;
+                if self.cnt_time >= time {
+                    self.cnt_time = time;
+                }
+
+                self.cnt_par += 3;
+                if self.cnt_par >= par {
+                    self.cnt_par = par;
+                    if self.cnt_time >= time {
+                        self.sp_state += 1;
+                    }
+                }
+            }
+            10 => {
+                if self.acceleratestage {
+                    // TODO: S_StartSound(0, sfx_sgcock);
+                    if self.game_mode == GameMode::Commercial {
+                        self.init_no_state();
+                    } else {
+                        self.init_show_next_loc();
+                    }
+                }
+            }
+            _ => {
+                if self.sp_state & 1 != 0 {
+                    self.cnt_pause -= 1;
+                    if self.cnt_pause == 0 {
+                        self.sp_state += 1;
+                        self.cnt_pause = TICRATE;
+                    }
+                }
+            }
+        }
+    }
+
+    /// WI_updateShowNextLoc
+    fn update_show_next_loc(&mut self) {
+        self.update_animated_back();
+
+        self.cnt -= 1;
+        if self.cnt == 0 || self.acceleratestage {
+            self.init_no_state();
+        } else {
+            self.snl_pointeron = (self.cnt & 31) < 20;
+        }
+    }
+
+    /// WI_updateNoState
+    fn update_no_state(&mut self) -> bool {
+        self.update_animated_back();
+
+        self.cnt -= 1;
+        // WI_End, G_WorldDone
+        self.cnt == 0
+    }
+
+    /// WI_Drawer
+    pub(crate) fn drawer(&mut self, canvas: &mut Canvas<Surface>) {
+        match self.state {
+            WiState::StatCount => self.draw_stats(canvas),
+            WiState::ShowNextLoc => self.draw_show_next_loc(canvas),
+            WiState::NoState => {
+                // WI_drawNoState
+                self.snl_pointeron = true;
+                self.draw_show_next_loc(canvas);
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        patch: &WadPatch,
+        x: i32,
+        y: i32,
+        canvas: &mut Canvas<Surface>,
+    ) {
+        draw_patch(patch, x, y, &self.palette, canvas);
+    }
+
+    /// WI_slamBackground and WI_drawAnimatedBack
+    fn draw_background(&self, canvas: &mut Canvas<Surface>) {
+        self.draw(&self.patches.background, 0, 0, canvas);
+
+        for a in self.anims.iter() {
+            if a.ctr >= 0 {
+                self.draw(
+                    &a.patches[a.ctr as usize],
+                    a.info.loc.0,
+                    a.info.loc.1,
+                    canvas,
+                );
+            }
+        }
+    }
+
+    /// WI_drawLF
+    /// Draws "<Levelname> Finished!"
+    fn draw_lf(&self, canvas: &mut Canvas<Surface>) {
+        let mut y = WI_TITLEY;
+        if let Some(lname) = self.patches.lnames.get(self.wbs.last as usize) {
+            // draw <LevelName>
+            self.draw(lname, (320 - lname.width as i32) / 2, y, canvas);
+            y += (5 * lname.height as i32) / 4;
+        }
+        // draw "Finished!"
+        let finished = &self.patches.finished;
+        self.draw(finished, (320 - finished.width as i32) / 2, y, canvas);
+    }
+
+    /// WI_drawEL
+    /// Draws "Entering <LevelName>"
+    fn draw_el(&self, canvas: &mut Canvas<Surface>) {
+        let mut y = WI_TITLEY;
+        // draw "Entering"
+        let entering = &self.patches.entering;
+        self.draw(entering, (320 - entering.width as i32) / 2, y, canvas);
+
+        if let Some(lname) = self.patches.lnames.get(self.wbs.next as usize) {
+            // draw level
+            y += (5 * lname.height as i32) / 4;
+            self.draw(lname, (320 - lname.width as i32) / 2, y, canvas);
+        }
+    }
+
+    /// WI_drawOnLnode
+    /// Draw the first of the patches that fits on screen
+    fn draw_on_lnode(
+        &self,
+        n: usize,
+        patches: &[Option<WadPatch>],
+        canvas: &mut Canvas<Surface>,
+    ) {
+        let (x, y) = LNODES[self.wbs.epsd as usize][n];
+        for patch in patches.iter().flatten() {
+            let left = x - patch.left_offset as i32;
+            let top = y - patch.top_offset as i32;
+            let right = left + patch.width as i32;
+            let bottom = top + patch.height as i32;
+
+            if left >= 0 && right < 320 && top >= 0 && bottom < 200 {
+                self.draw(patch, x, y, canvas);
+                return;
+            }
+        }
+        println!("Could not place patch on level {}", n + 1);
+    }
+
+    /// WI_drawNum
+    /// Draws a number. If digits > 0, then use that many digits minimum,
+    /// otherwise only use as many as necessary. Returns new x position.
+    fn draw_num(
+        &self,
+        mut x: i32,
+        y: i32,
+        mut n: i32,
+        mut digits: i32,
+        canvas: &mut Canvas<Surface>,
+    ) -> i32 {
+        let fontwidth = self.patches.num[0].width as i32;
+
+        if digits < 0 {
+            if n == 0 {
+                // make variable-length zeros 1 digit long
+                digits = 1;
+            } else {
+                // figure out # of digits in #
+                digits = 0;
+                let mut temp = n;
+                while temp != 0 {
+                    temp /= 10;
+                    digits += 1;
+                }
+            }
+        }
+
+        let neg = n < 0;
+        if neg {
+            n = -n;
+        }
+
+        // if non-number, do not draw it
+        if n == 1994 {
+            return 0;
+        }
+
+        // draw the new number
+        while digits > 0 {
+            x -= fontwidth;
+            self.draw(&self.patches.num[(n % 10) as usize], x, y, canvas);
+            n /= 10;
+            digits -= 1;
+        }
+
+        // draw a minus sign if necessary
+        if neg {
+            x -= 8;
+            self.draw(&self.patches.wiminus, x, y, canvas);
+        }
+        x
+    }
+
+    /// WI_drawPercent
+    fn draw_percent(
+        &self,
+        x: i32,
+        y: i32,
+        p: i32,
+        canvas: &mut Canvas<Surface>,
+    ) {
+        if p < 0 {
+            return;
+        }
+        self.draw(&self.patches.percent, x, y, canvas);
+        self.draw_num(x, y, p, -1, canvas);
+    }
+
+    /// WI_drawTime
+    /// Display level completion time and par, or "sucks" message if overflow.
+    fn draw_time(
+        &self,
+        mut x: i32,
+        y: i32,
+        t: i32,
+        canvas: &mut Canvas<Surface>,
+    ) {
+        if t < 0 {
+            return;
+        }
+
+        if t <= 61 * 59 {
+            let mut div = 1;
+            loop {
+                let n = (t / div) % 60;
+                x = self.draw_num(x, y, n, 2, canvas)
+                    - self.patches.colon.width as i32;
+                div *= 60;
+
+                // draw
+                if div == 60 || t / div != 0 {
+                    self.draw(&self.patches.colon, x, y, canvas);
+                }
+                if t / div == 0 {
+                    break;
+                }
+            }
+        } else {
+            // "sucks"
+            let sucks = &self.patches.sucks;
+            self.draw(sucks, x - sucks.width as i32, y, canvas);
+        }
+    }
+
+    /// WI_drawStats
+    fn draw_stats(&self, canvas: &mut Canvas<Surface>) {
+        // line height
+        let lh = (3 * self.patches.num[0].height as i32) / 2;
+
+        self.draw_background(canvas);
+        self.draw_lf(canvas);
+
+        self.draw(&self.patches.kills, SP_STATSX, SP_STATSY, canvas);
+        self.draw_percent(320 - SP_STATSX, SP_STATSY, self.cnt_kills, canvas);
+
+        self.draw(&self.patches.items, SP_STATSX, SP_STATSY + lh, canvas);
+        self.draw_percent(
+            320 - SP_STATSX,
+            SP_STATSY + lh,
+            self.cnt_items,
+            canvas,
+        );
+
+        self.draw(
+            &self.patches.sp_secret,
+            SP_STATSX,
+            SP_STATSY + 2 * lh,
+            canvas,
+        );
+        self.draw_percent(
+            320 - SP_STATSX,
+            SP_STATSY + 2 * lh,
+            self.cnt_secret,
+            canvas,
+        );
+
+        self.draw(&self.patches.time, SP_TIMEX, SP_TIMEY, canvas);
+        self.draw_time(320 / 2 - SP_TIMEX, SP_TIMEY, self.cnt_time, canvas);
+
+        if self.wbs.epsd < 3 {
+            self.draw(&self.patches.par, 320 / 2 + SP_TIMEX, SP_TIMEY, canvas);
+            self.draw_time(320 - SP_TIMEX, SP_TIMEY, self.cnt_par, canvas);
+        }
+    }
+
+    /// WI_drawShowNextLoc
+    fn draw_show_next_loc(&self, canvas: &mut Canvas<Surface>) {
+        self.draw_background(canvas);
+
+        if self.game_mode != GameMode::Commercial {
+            if self.wbs.epsd > 2 {
+                self.draw_el(canvas);
+                return;
+            }
+
+            let last = if self.wbs.last == 8 {
+                self.wbs.next - 1
+            } else {
+                self.wbs.last
+            };
+
+            // draw a splat on taken cities.
+            let splat = [self.patches.splat.clone()];
+            for i in 0..=last {
+                self.draw_on_lnode(i as usize, &splat, canvas);
+            }
+
+            // splat the secret level?
+            if self.wbs.didsecret {
+                self.draw_on_lnode(8, &splat, canvas);
+            }
+
+            // draw flashing ptr
+            if self.snl_pointeron {
+                self.draw_on_lnode(
+                    self.wbs.next as usize,
+                    &self.patches.yah,
+                    canvas,
+                );
+            }
+        }
+
+        // draws which level you are entering..
+        if self.game_mode != GameMode::Commercial || self.wbs.next != 30 {
+            self.draw_el(canvas);
+        }
+    }
+}
@@ -260,11 +260,164 @@ impl WadData {
             _phantom: Default::default(),
         }
     }
+
+    pub fn palette_iter(
+        &self,
+    ) -> LumpIter<WadPalette, impl Fn(usize) -> WadPalette + '_> {
+        let info = self.find_lump_or_panic("PLAYPAL");
+        let item_size = 768;
+        let file = &self.file_data[info.file_handle];
+
+        LumpIter {
+            item_size,
+            item_count: info.lump_size / item_size,
+            lump_offset: info.lump_offset,
+            current: 0,
+            transformer: move |offset| {
+                let mut palette = WadPalette::new();
+                for (i, colour) in palette.0.iter_mut().enumerate() {
+                    colour.r = file[offset + i * 3];
+                    colour.g = file[offset + i * 3 + 1];
+                    colour.b = file[offset + i * 3 + 2];
+                }
+                palette
+            },
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Read and decode a patch picture. Panics if the patch does not exist
+    pub fn get_patch(&self, name: &str) -> WadPatch {
+        let info = self.find_lump_or_panic(name);
+        let file = &self.file_data[info.file_handle];
+        let offset = info.lump_offset;
+
+        let width = self.read_2_bytes(offset, file) as u16;
+        let mut columns = Vec::with_capacity(width as usize);
+        for c in 0..width as usize {
+            let mut col_offset =
+                offset + self.read_4_bytes(offset + 8 + c * 4, file) as usize;
+
+            let mut posts = Vec::new();
+            while file[col_offset] != 0xFF {
+                let top_delta = file[col_offset] as i32;
+                let len = file[col_offset + 1] as usize;
+                // Skip the unused byte either side of the pixels
+                let start = col_offset + 3;
+                posts.push(WadPatchPost {
+                    top_delta,
+                    pixels: file[start..start + len].to_vec(),
+                });
+                col_offset += len + 4;
+            }
+            columns.push(posts);
+        }
+
+        WadPatch {
+            name: name.to_owned(),
+            width,
+            height: self.read_2_bytes(offset + 2, file) as u16,
+            left_offset: self.read_2_bytes(offset + 4, file),
+            top_offset: self.read_2_bytes(offset + 6, file),
+            columns,
+        }
+    }
+
+    /// Read a flat. Panics if the flat does not exist
+    pub fn get_flat(&self, name: &str) -> WadFlat {
+        let info = self.find_lump_or_panic(name);
+        let file = &self.file_data[info.file_handle];
+        WadFlat {
+            name: name.to_owned(),
+            data: file[info.lump_offset..info.lump_offset + info.lump_size]
+                .to_vec(),
+        }
+    }
+
+    /// Read the names and sizes of all textures in `TEXTURE1` and, if it
+    /// exists, `TEXTURE2`. The index in to the returned list is the texture
+    /// number used by vanilla
+    pub fn get_textures(&self) -> Vec<WadTexture> {
+        let mut textures = Vec::new();
+        for lump in &["TEXTURE1", "TEXTURE2"] {
+            if !self.lump_exists(lump) {
+                continue;
+            }
+            let info = self.find_lump_or_panic(lump);
+            let file = &self.file_data[info.file_handle];
+            let count = self.read_4_bytes(info.lump_offset, file) as usize;
+            for i in 0..count {
+                let offset = info.lump_offset
+                    + self.read_4_bytes(info.lump_offset + 4 + i * 4, file)
+                        as usize;
+                textures.push(WadTexture {
+                    name:   name_from_bytes(&file[offset..offset + 8]),
+                    width:  self.read_2_bytes(offset + 12, file),
+                    height: self.read_2_bytes(offset + 14, file),
+                });
+            }
+        }
+        textures
+    }
+
+    /// Read the Boom `ANIMATED` lump if there is one
+    pub fn get_animated(&self) -> Option<Vec<WadAnimated>> {
+        if !self.lump_exists("ANIMATED") {
+            return None;
+        }
+        let info = self.find_lump_or_panic("ANIMATED");
+        let file = &self.file_data[info.file_handle];
+        let mut animated = Vec::new();
+        let mut offset = info.lump_offset;
+        while offset + 23 <= info.lump_offset + info.lump_size
+            && file[offset] != 0xFF
+        {
+            animated.push(WadAnimated {
+                is_texture: file[offset] & 1 == 1,
+                end_name:   name_from_bytes(&file[offset + 1..offset + 10]),
+                start_name: name_from_bytes(&file[offset + 10..offset + 19]),
+                speed:      self.read_4_bytes(offset + 19, file),
+            });
+            offset += 23;
+        }
+        Some(animated)
+    }
+
+    /// Read the Boom `SWITCHES` lump if there is one
+    pub fn get_switches(&self) -> Option<Vec<WadSwitch>> {
+        if !self.lump_exists("SWITCHES") {
+            return None;
+        }
+        let info = self.find_lump_or_panic("SWITCHES");
+        let file = &self.file_data[info.file_handle];
+        let mut switches = Vec::new();
+        let mut offset = info.lump_offset;
+        while offset + 20 <= info.lump_offset + info.lump_size {
+            let episode = self.read_2_bytes(offset + 18, file);
+            if episode == 0 {
+                break;
+            }
+            switches.push(WadSwitch {
+                off_name: name_from_bytes(&file[offset..offset + 9]),
+                on_name: name_from_bytes(&file[offset + 9..offset + 18]),
+                episode,
+            });
+            offset += 20;
+        }
+        Some(switches)
+    }
+}
+
+/// Names in the texture and animation lumps are NUL padded, and may have
+/// junk after the first NUL
+fn name_from_bytes(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).to_ascii_uppercase()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::lumps::WadThing;
+    use crate::lumps::{WadPalette, WadThing};
     use crate::wad::WadData;
 
     #[test]
@@ -290,4 +443,34 @@ mod tests {
         let collection: Vec<WadThing> = wad.thing_iter("E1M1").collect();
         assert_eq!(collection.len(), 138);
     }
+
+    #[test]
+    fn palette_iter() {
+        let wad = WadData::new("../doom1.wad".into());
+        let palettes: Vec<WadPalette> = wad.palette_iter().collect();
+        assert_eq!(palettes.len(), 14);
+        // Index 0 of the normal palette is black
+        assert_eq!(palettes[0].0[0].r, 0);
+        assert_eq!(palettes[0].0[0].g, 0);
+        assert_eq!(palettes[0].0[0].b, 0);
+    }
+
+    #[test]
+    fn get_patch() {
+        let wad = WadData::new("../doom1.wad".into());
+        let patch = wad.get_patch("M_DOOM");
+        assert_eq!(patch.width, 192);
+        assert_eq!(patch.columns.len(), 192);
+    }
+
+    #[test]
+    fn get_textures() {
+        let wad = WadData::new("../doom1.wad".into());
+        let textures = wad.get_textures();
+        assert_eq!(textures[0].name, "AASTINKY");
+        assert!(textures.iter().any(|t| t.name == "SW1BRCOM"));
+        // The shareware IWAD has no Boom lumps
+        assert!(wad.get_animated().is_none());
+        assert!(wad.get_switches().is_none());
+    }
 }
@@ -9,6 +9,14 @@
 //  - [X] Sector
 //  - [ ] Reject
 //  - [ ] Blockmap
+//
+// Non-map lumps
+//  - [X] Palette (PLAYPAL)
+//  - [X] Patch
+//  - [X] Flat
+//  - [X] Texture (TEXTURE1/TEXTURE2, names and sizes only)
+//  - [X] Animated (Boom ANIMATED)
+//  - [X] Switches (Boom SWITCHES)
 
 use std::str;
 
@@ -425,3 +433,132 @@ impl WadNode {
         }
     }
 }
+
+/// A single colour entry in a `WadPalette`, 3 bytes: red, green, blue
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct WadColour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+/// The PLAYPAL lump holds 14 palettes. Palette 0 is the normal palette, 1-8
+/// are the increasing red of damage/berserk, 9-12 the yellow of item pickups
+/// and 13 is the green of the radiation suit.
+///
+/// Each palette is 256 `WadColour` entries, so 768 bytes
+#[derive(Debug, Clone)]
+pub struct WadPalette(pub [WadColour; 256]);
+
+impl WadPalette {
+    pub fn new() -> WadPalette { WadPalette([WadColour::default(); 256]) }
+}
+
+impl Default for WadPalette {
+    fn default() -> Self { WadPalette::new() }
+}
+
+/// A post is a vertical run of pixels in a `WadPatch` column. A column may
+/// have many posts with transparent gaps between them
+///
+/// | Field Size | Data Type | Content                               |
+/// |------------|-----------|---------------------------------------|
+/// |  0x00      |    u8     | Row to start drawing at, 0xFF ends    |
+/// |  0x01      |    u8     | Length of the post in pixels          |
+/// |  0x02      |    u8     | Unused padding                        |
+/// |  0x03..n   |    u8     | Palette indexes for each pixel        |
+/// |  n+1       |    u8     | Unused padding                        |
+#[derive(Debug, Clone)]
+pub struct WadPatchPost {
+    pub top_delta: i32,
+    pub pixels:    Vec<u8>,
+}
+
+/// A patch is the picture format used for everything from wall texture pieces
+/// to sprites, menu graphics and the status bar
+///
+/// The header of the lump is structured as follows:
+///
+/// | Field Size | Data Type | Content                                  |
+/// |------------|-----------|------------------------------------------|
+/// |  0x00-0x01 |    u16    | Width                                    |
+/// |  0x02-0x03 |    u16    | Height                                   |
+/// |  0x04-0x05 |    i16    | Left offset                              |
+/// |  0x06-0x07 |    i16    | Top offset                               |
+/// |  0x08..n   |    u32    | Offset to each column, `width` entries   |
+#[derive(Debug, Clone)]
+pub struct WadPatch {
+    pub name:        String,
+    pub width:       u16,
+    pub height:      u16,
+    /// Pixels to the left of the origin
+    pub left_offset: i16,
+    /// Pixels below the origin
+    pub top_offset:  i16,
+    /// Each column is a list of posts
+    pub columns:     Vec<Vec<WadPatchPost>>,
+}
+
+/// A flat is the raw 64x64 picture used for floors and ceilings, and also for
+/// things like screen borders and finale backgrounds. There is no header, it
+/// is only the 4096 palette indexes in row order
+#[derive(Debug, Clone)]
+pub struct WadFlat {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+/// Only the parts of a `TEXTURE1`/`TEXTURE2` entry the game logic needs to
+/// look up textures by name. The patches making up a texture are not read
+///
+/// The lump starts with an i32 count followed by an i32 offset to each entry.
+/// The start of each entry is structured as follows:
+///
+/// | Field Size | Data Type | Content                               |
+/// |------------|-----------|---------------------------------------|
+/// |  0x00-0x07 | 8 ASCII   | Texture name                          |
+/// |  0x08-0x0b |    i32    | Masked, unused                        |
+/// |  0x0c-0x0d |    i16    | Width                                 |
+/// |  0x0e-0x0f |    i16    | Height                                |
+#[derive(Debug, Clone)]
+pub struct WadTexture {
+    pub name:   String,
+    pub width:  i16,
+    pub height: i16,
+}
+
+/// A record from the Boom `ANIMATED` lump, defining a texture or flat
+/// animation sequence. The list ends with a record with type `0xFF`
+///
+/// | Field Size | Data Type | Content                               |
+/// |------------|-----------|---------------------------------------|
+/// |  0x00      |    i8     | 0 = flat, 1 = texture                 |
+/// |  0x01-0x09 | 9 ASCII   | Last name in the sequence             |
+/// |  0x0a-0x12 | 9 ASCII   | First name in the sequence            |
+/// |  0x13-0x16 |    i32    | Tics between each frame               |
+///
+/// Each record is 23 bytes
+#[derive(Debug, Clone)]
+pub struct WadAnimated {
+    pub is_texture: bool,
+    pub end_name:   String,
+    pub start_name: String,
+    pub speed:      i32,
+}
+
+/// A record from the Boom `SWITCHES` lump, pairing the off and on textures
+/// of a switch. The list ends with a record with episode 0
+///
+/// | Field Size | Data Type | Content                               |
+/// |------------|-----------|---------------------------------------|
+/// |  0x00-0x08 | 9 ASCII   | Texture name when off                 |
+/// |  0x09-0x11 | 9 ASCII   | Texture name when on                  |
+/// |  0x12-0x13 |    i16    | Episode, 1 = shareware, 2 = registered, 3 = commercial |
+///
+/// Each record is 20 bytes
+#[derive(Debug, Clone)]
+pub struct WadSwitch {
+    pub off_name: String,
+    pub on_name:  String,
+    pub episode:  i16,
+}
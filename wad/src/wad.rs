@@ -274,6 +274,17 @@ impl WadData {
         panic!("Could not find {}", map_name);
     }
 
+    /// Find a lump by name. The search is done in reverse so that lumps in
+    /// PWADs take precedence over the IWAD
+    pub(crate) fn find_lump_or_panic(&self, name: &str) -> &LumpInfo {
+        for info in self.lump_info.iter().rev() {
+            if info.lump_name == name {
+                return info;
+            }
+        }
+        panic!("Could not find {}", name);
+    }
+
     pub fn lump_exists(&self, lump_name: &str) -> bool {
         for lump in self.lump_info.iter().rev() {
             if lump.lump_name == lump_name {
@@ -282,6 +293,53 @@ impl WadData {
         }
         false
     }
+
+    /// The names of all flats between the `F_START`/`F_END` markers, and the
+    /// `FF_START`/`FF_END` markers PWADs use. The index in to the list is the
+    /// flat number, a flat replaced by a PWAD keeps its original number
+    pub fn flat_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        let mut in_flats = false;
+        for lump in &self.lump_info {
+            match lump.lump_name.as_str() {
+                "F_START" | "FF_START" => in_flats = true,
+                "F_END" | "FF_END" => in_flats = false,
+                name => {
+                    // Skip the F1_START etc sub-markers, which have no data
+                    if in_flats
+                        && lump.lump_size != 0
+                        && !names.iter().any(|n| n == name)
+                    {
+                        names.push(name.to_owned());
+                    }
+                }
+            }
+        }
+        names
+    }
+
+    /// The names of all sprites between the `S_START`/`S_END` markers, and
+    /// the `SS_START`/`SS_END` markers PWADs use. A sprite replaced by a PWAD
+    /// is listed once, `get_patch` finds the replacement
+    pub fn sprite_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        let mut in_sprites = false;
+        for lump in &self.lump_info {
+            match lump.lump_name.as_str() {
+                "S_START" | "SS_START" => in_sprites = true,
+                "S_END" | "SS_END" => in_sprites = false,
+                name => {
+                    if in_sprites
+                        && lump.lump_size != 0
+                        && !names.iter().any(|n| n == name)
+                    {
+                        names.push(name.to_owned());
+                    }
+                }
+            }
+        }
+        names
+    }
 }
 
 #[cfg(test)]
@@ -365,6 +423,30 @@ mod tests {
         assert_eq!(things_lump.lump_name, Lumps::Vertexes.to_string());
     }
 
+    #[test]
+    fn find_playpal() {
+        let wad = WadData::new("../doom1.wad".into());
+        let lump = wad.find_lump_or_panic("PLAYPAL");
+        assert_eq!(lump.lump_size, 10752);
+    }
+
+    #[test]
+    fn flat_names() {
+        let wad = WadData::new("../doom1.wad".into());
+        let names = wad.flat_names();
+        assert!(names.iter().any(|n| n == "NUKAGE1"));
+        assert!(!names.iter().any(|n| n == "F1_START"));
+    }
+
+    #[test]
+    fn sprite_names() {
+        let wad = WadData::new("../doom1.wad".into());
+        let names = wad.sprite_names();
+        assert!(names.iter().any(|n| n == "PLAYA2A8"));
+        assert!(names.iter().any(|n| n == "PISGA0"));
+        assert!(!names.iter().any(|n| n == "S_START" || n == "FLOOR0_1"));
+    }
+
     #[test]
     #[ignore]
     fn load_sigil() {